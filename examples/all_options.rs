@@ -1,8 +1,24 @@
 use fakes_gen::date_time_format::{
     DEFAULT_DATE_FORMAT, DEFAULT_DATE_TIME_FORMAT, DEFAULT_TIME_FORMAT,
 };
+use fakes_gen::faker::access_log::AccessLogFormat;
+use fakes_gen::faker::address_style::AddressStyle;
+use fakes_gen::faker::card_brand::CardBrand;
+use fakes_gen::faker::color_format::{ColorFormat, ColorTone};
+use fakes_gen::faker::currency::Currency;
 use fakes_gen::faker::fake_options::FakeOption;
+use fakes_gen::faker::geo_area::GeoArea;
+use fakes_gen::faker::hash_format::HashFormat;
+use fakes_gen::faker::ip_range::IpRange;
+use fakes_gen::faker::kana::KanaScript;
 use fakes_gen::faker::locale::Locale;
+use fakes_gen::faker::mac_style::MacStyle;
+use fakes_gen::faker::password_policy::{PasswordClass, PasswordPolicy};
+use fakes_gen::faker::phone_kind::PhoneKind;
+use fakes_gen::faker::port_range::PortRange;
+use fakes_gen::faker::romaji::Capitalization;
+use fakes_gen::faker::url_format::{UrlFormat, UrlScheme};
+use fakes_gen::faker::user_agent::{Browser, DeviceType, Platform, UserAgentFormat};
 use fakes_gen::faker::Faker;
 use rand::rngs::ThreadRng;
 use rand::thread_rng;
@@ -27,6 +43,10 @@ fn main() {
                 )),
             ],
         ),
+        WithLocale(
+            Locale::UnitedStates,
+            Box::new(FullName(false, KanaScript::Katakana)),
+        ),
         // Fixed
         FixedString("Dummy String".to_string()),
         FixedNotString("Dummy not String".to_string()),
@@ -41,6 +61,16 @@ fn main() {
             "not-str2".to_string(),
             "not-str3".to_string(),
         ]),
+        FakeOption::select_from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/examples/data/department.txt"
+        ))
+        .unwrap(),
+        FakeOption::select_from_column(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/examples/data/product.csv"),
+            "name",
+        )
+        .unwrap(),
         // Lorem
         Word,
         Words(3, 10),
@@ -48,17 +78,21 @@ fn main() {
         Sentences(3, 10),
         Paragraph,
         Paragraphs(3, 10),
-        // Name(use furigana)
+        // Name(use furigana, script of furigana)
         // generate "name":"furigana"
-        FirstName(true),
-        FirstName(false),
-        FirstNameFurigana,
-        LastName(true),
-        LastName(false),
-        LastNameFurigana,
-        FullName(true),
-        FullName(false),
-        FullNameFurigana,
+        FirstName(true, KanaScript::Katakana),
+        FirstName(false, KanaScript::Katakana),
+        FirstNameFurigana(KanaScript::Katakana),
+        LastName(true, KanaScript::Katakana),
+        LastName(false, KanaScript::Katakana),
+        LastNameFurigana(KanaScript::Hiragana),
+        FullName(true, KanaScript::Hiragana),
+        FullName(false, KanaScript::Katakana),
+        FullNameFurigana(KanaScript::Katakana),
+        FirstNameRomaji(Capitalization::Capitalized),
+        LastNameRomaji(Capitalization::Upper),
+        FullNameRomaji(Capitalization::Lower),
+        Gender,
         // Primitive
         Integer,
         IntegerRange(-10, 10),
@@ -70,18 +104,85 @@ fn main() {
         Email,
         UserName,
         Password(8, 15),
+        PasswordWithPolicy(
+            12,
+            16,
+            PasswordPolicy {
+                classes: vec![
+                    PasswordClass::Upper,
+                    PasswordClass::Lower,
+                    PasswordClass::Digit,
+                ],
+                excluded: "0O1lI".to_string(),
+            },
+        ),
+        PasswordHash(HashFormat::Sha256),
+        PasswordHash(HashFormat::Sha256Crypt),
         CreditCard,
+        CreditCardNumber(CardBrand::Visa),
+        CreditCardNumber(CardBrand::Amex),
+        CreditCardExpiry,
+        CreditCardCvv(CardBrand::Amex),
+        CreditCardHolder,
         URL,
+        URLWith(UrlFormat {
+            scheme: UrlScheme::Https,
+            depth: 3,
+            query: 2,
+            fragment: true,
+            port: true,
+            encoded: false,
+        }),
+        URLWith(UrlFormat {
+            encoded: true,
+            ..UrlFormat::default()
+        }),
         IPv4,
         IPv6,
+        IPv4InRange(IpRange::Private, true),
+        IPv4InRange(IpRange::Cidr("10.0.0.0".parse().unwrap(), 8), false),
+        IPv6InRange(IpRange::Documentation),
+        MAC(MacStyle::Colon, false),
+        MAC(MacStyle::Hyphen, true),
+        Hostname(false),
+        Hostname(true),
+        DomainName,
+        Port(PortRange::Any),
+        Port(PortRange::Ephemeral),
+        Slug,
         RGB,
         RGBA,
+        Color(ColorFormat::CssHsl, ColorTone::Pastel),
+        Color(ColorFormat::Hsv, ColorTone::Any),
+        ColorName(true),
+        CssColorName(false),
         UserAgent,
+        UserAgentWith(UserAgentFormat {
+            browser: Browser::Safari,
+            device: DeviceType::Mobile,
+            ..UserAgentFormat::default()
+        }),
+        UserAgentWith(UserAgentFormat {
+            browser: Browser::Chrome,
+            platform: Platform::Windows,
+            version: Some((110, 120)),
+            ..UserAgentFormat::default()
+        }),
+        AccessLog(AccessLogFormat::Combined),
+        AccessLog(AccessLogFormat::Nginx),
+        AccessLog(AccessLogFormat::Custom("%h %t \"%r\" %>s %D".to_string())),
         StatusCode,
         // Company
         CompanySuffix,
         CompanyName,
         Industry,
+        CorporateNumber,
+        IndividualNumber,
+        Department,
+        JobTitle,
+        EmployeeNumber("EMP-999999".to_string()),
+        CompanyDomain,
+        EmployeeEmail,
         // Address
         Building,
         StreetName,
@@ -91,14 +192,49 @@ fn main() {
         CountryName,
         TimeZone,
         Address,
+        DetailedAddress(AddressStyle::Hyphen),
+        DetailedAddress(AddressStyle::Kanji),
+        AddressLine1(AddressStyle::Kanji),
+        AddressLine2,
         // use hyphen?
         ZipCode(true),
         ZipCode(false),
         // use hyphen?
         DomesticPhoneNumber(true),
         DomesticPhoneNumber(false),
+        PhoneNumber(PhoneKind::Landline, true),
+        PhoneNumber(PhoneKind::Mobile, false),
+        InternationalPhoneNumber(PhoneKind::TollFree),
         Latitude,
         Longitude,
+        LatitudeIn(GeoArea::State),
+        LongitudeIn(GeoArea::State),
+        Coordinates(GeoArea::Country),
+        Coordinates(GeoArea::bounding_box(30.0, 170.0, 40.0, -170.0).unwrap()),
+        Coordinates(GeoArea::circle(35.68, 139.76, 10.0).unwrap()),
+        // Finance
+        BankName,
+        BankCode,
+        BranchName,
+        BranchCode,
+        AccountType,
+        AccountNumber,
+        AccountHolder,
+        // Commerce
+        JAN,
+        EAN8,
+        UPC,
+        ISBN10,
+        ISBN13,
+        ProductName,
+        ProductCategory,
+        Sku("AAA-99999".to_string()),
+        Price(Currency::Local, None),
+        Price(Currency::Usd, Some((10, 100))),
+        CurrencyCode(Currency::Local),
+        Price(Currency::Any, None),
+        CurrencyCode(Currency::Any),
+        Quantity(1, 10),
         // Date Time
         // format-str: https://docs.rs/chrono/0.4.9/chrono/format/strftime/index.html#specifiers
         // String is format. default is "%Y-%m-%d %H:%I:%M"'s sub-format.
@@ -4,6 +4,7 @@ use fakes_gen::converter::file_convert::{
 use fakes_gen::converter::file_type::FileType;
 use fakes_gen::date_time_format::DEFAULT_DATE_TIME_FORMAT;
 use fakes_gen::faker::fake_options::FakeOption;
+use fakes_gen::faker::kana::KanaScript;
 use fakes_gen::faker::Faker;
 use rand::rngs::ThreadRng;
 use std::io;
@@ -44,9 +45,12 @@ fn main() -> io::Result<()> {
         ),
         (
             "first_name_with_furigana".to_string(),
-            FakeOption::FirstName(true),
+            FakeOption::FirstName(true, KanaScript::Katakana),
+        ),
+        (
+            "furigana".to_string(),
+            FakeOption::FirstNameFurigana(KanaScript::Katakana),
         ),
-        ("furigana".to_string(), FakeOption::FirstNameFurigana),
         (
             "date time".to_string(),
             FakeOption::DateTime(DEFAULT_DATE_TIME_FORMAT.to_string()),
@@ -4,6 +4,7 @@ use fakes_gen::converter::file_convert::{
 use fakes_gen::converter::file_type::FileType;
 use fakes_gen::date_time_format::DEFAULT_DATE_TIME_FORMAT;
 use fakes_gen::faker::fake_options::FakeOption;
+use fakes_gen::faker::kana::KanaScript;
 use fakes_gen::faker::Faker;
 use rand::rngs::ThreadRng;
 use std::io;
@@ -13,24 +14,33 @@ fn main() -> io::Result<()> {
     let mut faker: Faker<ThreadRng> = Faker::default();
     let header_options: Vec<(String, FakeOption)> = vec![
         ("room_id".to_string(), FakeOption::IntegerRange(1, 1000)),
-        ("full name".to_string(), FakeOption::FullName(false)),
-        ("last name".to_string(), FakeOption::LastName(false)),
-        ("first name".to_string(), FakeOption::FirstName(false)),
+        (
+            "full name".to_string(),
+            FakeOption::FullName(false, KanaScript::Katakana),
+        ),
+        (
+            "last name".to_string(),
+            FakeOption::LastName(false, KanaScript::Katakana),
+        ),
+        (
+            "first name".to_string(),
+            FakeOption::FirstName(false, KanaScript::Katakana),
+        ),
         (
             "full name furigana".to_string(),
-            FakeOption::FullNameFurigana,
+            FakeOption::FullNameFurigana(KanaScript::Katakana),
         ),
         (
             "last name furigana".to_string(),
-            FakeOption::LastNameFurigana,
+            FakeOption::LastNameFurigana(KanaScript::Katakana),
         ),
         (
             "first name furigana".to_string(),
-            FakeOption::FirstNameFurigana,
+            FakeOption::FirstNameFurigana(KanaScript::Katakana),
         ),
         (
             "full name with furigana".to_string(),
-            FakeOption::FullName(true),
+            FakeOption::FullName(true, KanaScript::Katakana),
         ),
         (
             "date time".to_string(),
@@ -1,7 +1,9 @@
 mod helper;
 pub mod scanner;
+mod schema;
 
 use crate::cli::scanner::Scanner;
+use crate::cli::schema::{read_schema, write_schema};
 use clap::*;
 use failure::_core::str::FromStr;
 use fakes_gen::converter::file_convert::{
@@ -9,11 +11,16 @@ use fakes_gen::converter::file_convert::{
 };
 use fakes_gen::converter::file_type::FileType;
 use fakes_gen::faker::category::Category;
+use fakes_gen::faker::each_locale::pack::LocalePack;
 use fakes_gen::faker::fake_options::FakeOption;
+use fakes_gen::faker::gender::Gender;
 use fakes_gen::faker::locale::Locale;
 use fakes_gen::faker::Faker;
 use rand::thread_rng;
+use std::fs;
 use std::io;
+use std::path::Path;
+use std::sync::Arc;
 
 pub struct FakerApp<'a, 'b> {
     app: App<'a, 'b>,
@@ -45,7 +52,7 @@ impl<'a, 'b> FakerApp<'a, 'b> {
                         .help("converter for output")
                         .default_value("csv")
                         .case_insensitive(true)
-                        .possible_values(&vec!["csv", "tsv", "json"])
+                        .possible_values(&vec!["csv", "tsv", "json", "raw"])
                         .takes_value(true),
                 )
                 .arg(
@@ -64,7 +71,29 @@ impl<'a, 'b> FakerApp<'a, 'b> {
                         .help("3-char's country code.")
                         .default_value("jpn")
                         .case_insensitive(true)
-                        .possible_values(&vec!["jpn"])
+                        .possible_values(&vec!["jpn", "usa", "kor"])
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("locale-dir")
+                        .short("d")
+                        .long("locale-dir")
+                        .help("directory of locale pack whose data is used before data of the locale.")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("locale-mix")
+                        .short("m")
+                        .long("locale-mix")
+                        .help("locales with weight for each record such as jpn:70#usa:30. This cannot be used with locale.")
+                        .conflicts_with_all(&["locale", "locale-dir"])
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("gender-ratio")
+                        .short("g")
+                        .long("gender-ratio")
+                        .help("weight of gender for person name such as male:60#female:40. If only female, generate only female.")
                         .takes_value(true),
                 )
                 .arg(
@@ -74,6 +103,20 @@ impl<'a, 'b> FakerApp<'a, 'b> {
                         .help("flag for generating as fullform such as body with header")
                         .takes_value(false),
                 )
+                .arg(
+                    Arg::with_name("emit-schema")
+                        .short("e")
+                        .long("emit-schema")
+                        .help("write schema document equivalent to options and exit")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("schema")
+                        .short("S")
+                        .long("schema")
+                        .help("schema document whose options are used before [option]s")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("option")
                         .help("options with name of column for faker")
@@ -94,20 +137,18 @@ impl<'a, 'b> FakerApp<'a, 'b> {
             Self::print_bnf();
             return Ok(());
         }
-        if !m.is_present("option") {
+        if !m.is_present("option") && !m.is_present("schema") {
             app.print_help().unwrap();
             return Ok(());
         }
 
-        let locale: Locale = match m.value_of("locale").unwrap() {
-            "jpn" => Locale::Japan,
-            _ => unreachable!(),
-        };
+        let locale: Locale = Locale::from_code(m.value_of("locale").unwrap()).unwrap();
 
         let converter: FileType = match m.value_of("converter").unwrap() {
             "csv" => FileType::CSV,
             "tsv" => FileType::TSV,
             "json" => FileType::JSON,
+            "raw" => FileType::Raw,
             _ => unreachable!(),
         };
 
@@ -123,27 +164,73 @@ impl<'a, 'b> FakerApp<'a, 'b> {
             return Ok(());
         }
 
+        let mut options: Vec<String> = Vec::new();
+        if let Some(path) = m.value_of("schema") {
+            let schema = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| read_schema(&text));
+            match schema {
+                Ok(schema_options) => options.extend(schema_options),
+                Err(e) => {
+                    eprintln!("Schema Err: {}: {}", path, e);
+                    return Ok(());
+                }
+            }
+        }
+        if let Some(values) = m.values_of("option") {
+            options.extend(values.map(|option| option.to_string()));
+        }
+
         let mut header_options: Vec<(String, FakeOption)> = Vec::new();
         let mut errors: Vec<String> = Vec::new();
 
-        if m.is_present("option") {
-            for option in m.values_of("option").unwrap() {
-                let scan_res = Scanner::new(option).scan();
-                if scan_res.is_err() {
-                    errors.push(format!("Format Err: {}", scan_res.err().unwrap()));
-                } else {
-                    header_options.push(scan_res.unwrap());
+        for option in options.iter() {
+            let scan_res = Scanner::new(option).scan();
+            if scan_res.is_err() {
+                errors.push(format!("Format Err: {}", scan_res.err().unwrap()));
+            } else {
+                header_options.push(scan_res.unwrap());
+            }
+        }
+        if !errors.is_empty() {
+            for error in errors {
+                eprintln!("{}", error);
+            }
+            return Ok(());
+        }
+
+        if m.is_present("emit-schema") {
+            return write_schema(&mut io::stdout(), &header_options);
+        }
+
+        let mut faker = if let Some(mix) = m.value_of("locale-mix") {
+            match Self::parse_locale_mix(mix) {
+                Ok(locale_mix) => Faker::with_locale_mix(thread_rng(), &locale_mix),
+                Err(e) => {
+                    eprintln!("Locale Mix Err: {}", e);
+                    return Ok(());
+                }
+            }
+        } else if let Some(dir) = m.value_of("locale-dir") {
+            match LocalePack::load(locale, Path::new(dir)) {
+                Ok(pack) => Faker::with_locale_pack(thread_rng(), Arc::new(pack)),
+                Err(e) => {
+                    eprintln!("Locale Pack Err: {}", e);
+                    return Ok(());
                 }
             }
-            if !errors.is_empty() {
-                for error in errors {
-                    eprintln!("{}", error);
+        } else {
+            Faker::new(thread_rng(), locale)
+        };
+        if let Some(ratio) = m.value_of("gender-ratio") {
+            match Self::parse_gender_ratio(ratio) {
+                Ok((male, female)) => faker.set_gender_ratio(male, female),
+                Err(e) => {
+                    eprintln!("Gender Ratio Err: {}", e);
+                    return Ok(());
                 }
-                return Ok(());
             }
         }
-
-        let mut faker = Faker::new(thread_rng(), locale);
         let mut writer = io::stdout();
         if size == 1 {
             if m.is_present("fullform") {
@@ -160,6 +247,61 @@ impl<'a, 'b> FakerApp<'a, 'b> {
         }
     }
 
+    /// parse such as "jpn:70#usa:30"
+    fn parse_locale_mix(target: &str) -> std::result::Result<Vec<(Locale, u32)>, String> {
+        let mut locale_mix: Vec<(Locale, u32)> = Vec::new();
+        for item in target.split('#') {
+            let (code, weight): (&str, &str) = match item.find(':') {
+                Some(index) => (&item[..index], &item[index + 1..]),
+                None => (item, "1"),
+            };
+            let locale: Locale = Locale::from_code(code)
+                .ok_or_else(|| format!("unknown locale \"{}\" in \"{}\"", code, item))?;
+            let weight: u32 = u32::from_str(weight)
+                .map_err(|e| format!("weight of \"{}\" is not integer: {}", item, e))?;
+            locale_mix.push((locale, weight));
+        }
+        if locale_mix.iter().all(|(_, weight)| *weight == 0) {
+            return Err("sum of weights is 0".to_string());
+        }
+        if locale_mix
+            .iter()
+            .try_fold(0u32, |sum, (_, weight)| sum.checked_add(*weight))
+            .is_none()
+        {
+            return Err(format!("sum of weights is greater than {}", u32::MAX));
+        }
+        Ok(locale_mix)
+    }
+
+    /// parse such as "male:60#female:40". omitted gender is weight 0 such as "female".
+    fn parse_gender_ratio(target: &str) -> std::result::Result<(u32, u32), String> {
+        let mut ratio: (u32, u32) = (0, 0);
+        for item in target.split('#') {
+            let (name, weight): (&str, &str) = match item.find(':') {
+                Some(index) => (&item[..index], &item[index + 1..]),
+                None => (item, "1"),
+            };
+            let gender: Gender = Gender::all_list()
+                .into_iter()
+                .find(|gender| gender.to_string().eq_ignore_ascii_case(name))
+                .ok_or_else(|| format!("unknown gender \"{}\" in \"{}\"", name, item))?;
+            let weight: u32 = u32::from_str(weight)
+                .map_err(|e| format!("weight of \"{}\" is not integer: {}", item, e))?;
+            match gender {
+                Gender::Male => ratio.0 = weight,
+                Gender::Female => ratio.1 = weight,
+            }
+        }
+        if ratio == (0, 0) {
+            return Err("sum of weights is 0".to_string());
+        }
+        if ratio.0.checked_add(ratio.1).is_none() {
+            return Err(format!("sum of weights is greater than {}", u32::MAX));
+        }
+        Ok(ratio)
+    }
+
     fn print_usable_options() {
         for category in Category::all_list() {
             println!("Category:\n {}", category.to_string());
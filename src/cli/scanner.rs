@@ -6,9 +6,27 @@ use failure::_core::str::FromStr;
 use fakes_gen::date_time_format::{
     DEFAULT_DATE_FORMAT, DEFAULT_DATE_TIME_FORMAT, DEFAULT_TIME_FORMAT,
 };
+use fakes_gen::faker::access_log::AccessLogFormat;
+use fakes_gen::faker::address_style::AddressStyle;
+use fakes_gen::faker::card_brand::CardBrand;
 use fakes_gen::faker::category::Category;
+use fakes_gen::faker::color_format::{ColorFormat, ColorTone};
+use fakes_gen::faker::currency::Currency;
 use fakes_gen::faker::fake_options::FakeOption;
+use fakes_gen::faker::geo_area::GeoArea;
+use fakes_gen::faker::hash_format::HashFormat;
+use fakes_gen::faker::ip_range::IpRange;
+use fakes_gen::faker::kana::KanaScript;
+use fakes_gen::faker::locale::Locale;
+use fakes_gen::faker::mac_style::MacStyle;
+use fakes_gen::faker::password_policy::{PasswordClass, PasswordPolicy};
+use fakes_gen::faker::phone_kind::PhoneKind;
+use fakes_gen::faker::port_range::PortRange;
+use fakes_gen::faker::romaji::Capitalization;
+use fakes_gen::faker::url_format::{UrlFormat, UrlScheme};
+use fakes_gen::faker::user_agent::{Browser, DeviceType, Platform, UserAgentFormat};
 use regex::{Captures, Regex};
+use std::net::IpAddr;
 use std::process::exit;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -19,10 +37,13 @@ pub struct Scanner {
 impl Scanner {
     // option name
     const JOIN: &'static str = "Join";
+    const WITH_LOCALE: &'static str = "Locale";
     const FIXED_STRING: &'static str = "String";
     const FIXED_NOT_STRING: &'static str = "NotString";
     const SELECT_STRING: &'static str = "String";
     const SELECT_NOT_STRING: &'static str = "NotString";
+    const SELECT_FROM_FILE: &'static str = "FromFile";
+    const SELECT_FROM_COLUMN: &'static str = "FromColumn";
     const LOREM_WORD: &'static str = "Word";
     const LOREM_SENTENCE: &'static str = "Sentence";
     const LOREM_PARAGRAPH: &'static str = "Paragraph";
@@ -32,6 +53,10 @@ impl Scanner {
     const NAME_LAST_NAME_FURIGANA: &'static str = "LastNameFurigana";
     const NAME_FULL_NAME: &'static str = "FullName";
     const NAME_FULL_NAME_FURIGANA: &'static str = "FullNameFurigana";
+    const NAME_FIRST_NAME_ROMAJI: &'static str = "FirstNameRomaji";
+    const NAME_LAST_NAME_ROMAJI: &'static str = "LastNameRomaji";
+    const NAME_FULL_NAME_ROMAJI: &'static str = "FullNameRomaji";
+    const NAME_GENDER: &'static str = "Gender";
     const PRIMITIVE_INTEGER: &'static str = "Int";
     const PRIMITIVE_FLOAT: &'static str = "Float";
     const PRIMITIVE_ASCII: &'static str = "Ascii";
@@ -39,17 +64,39 @@ impl Scanner {
     const INTERNET_EMAIL: &'static str = "Email";
     const INTERNET_USER_NAME: &'static str = "UserName";
     const INTERNET_PASSWORD: &'static str = "Password";
+    const INTERNET_PASSWORD_HASH: &'static str = "PasswordHash";
     const INTERNET_CREDIT_CARD: &'static str = "CreditCard";
+    const INTERNET_CREDIT_CARD_NUMBER: &'static str = "CreditCardNumber";
+    const INTERNET_CREDIT_CARD_EXPIRY: &'static str = "CreditCardExpiry";
+    const INTERNET_CREDIT_CARD_CVV: &'static str = "CreditCardCvv";
+    const INTERNET_CREDIT_CARD_HOLDER: &'static str = "CreditCardHolder";
     const INTERNET_URL: &'static str = "URL";
     const INTERNET_IPV4: &'static str = "IPv4";
     const INTERNET_IPV6: &'static str = "IPv6";
+    const INTERNET_MAC: &'static str = "MAC";
+    const INTERNET_HOSTNAME: &'static str = "Hostname";
+    const INTERNET_DOMAIN_NAME: &'static str = "DomainName";
+    const INTERNET_PORT: &'static str = "Port";
+    const INTERNET_SLUG: &'static str = "Slug";
     const INTERNET_RGB: &'static str = "RGB";
     const INTERNET_RGBA: &'static str = "RGBA";
+    const INTERNET_COLOR: &'static str = "Color";
+    const INTERNET_COLOR_NAME: &'static str = "ColorName";
+    const INTERNET_CSS_COLOR_NAME: &'static str = "CssColorName";
     const INTERNET_USER_AGENT: &'static str = "UserAgent";
+    const INTERNET_ACCESS_LOG: &'static str = "AccessLog";
     const INTERNET_STATUS_CODE: &'static str = "StatusCode";
     const COMPANY_SUFFIX: &'static str = "Suffix";
     const COMPANY_NAME: &'static str = "Name";
     const COMPANY_INDUSTRY: &'static str = "Industry";
+    const COMPANY_CORPORATE_NUMBER: &'static str = "CorporateNumber";
+    const COMPANY_INDIVIDUAL_NUMBER: &'static str = "IndividualNumber";
+    const COMPANY_DEPARTMENT: &'static str = "Department";
+    const COMPANY_JOB_TITLE: &'static str = "JobTitle";
+    const COMPANY_EMPLOYEE_NUMBER: &'static str = "EmployeeNumber";
+    const COMPANY_DOMAIN: &'static str = "Domain";
+    const COMPANY_EMPLOYEE_EMAIL: &'static str = "EmployeeEmail";
+    const DEFAULT_EMPLOYEE_NUMBER_PATTERN: &'static str = "999999";
     const ADDRESS_BUILDING: &'static str = "Building";
     const ADDRESS_STREET_NAME: &'static str = "Street";
     const ADDRESS_CITY_NAME: &'static str = "City";
@@ -58,10 +105,36 @@ impl Scanner {
     const ADDRESS_COUNTRY_NAME: &'static str = "CountryName";
     const ADDRESS_TIMEZONE: &'static str = "TimeZone";
     const ADDRESS_ADDRESS: &'static str = "Address";
+    const ADDRESS_DETAILED_ADDRESS: &'static str = "DetailedAddress";
+    const ADDRESS_ADDRESS_LINE1: &'static str = "AddressLine1";
+    const ADDRESS_ADDRESS_LINE2: &'static str = "AddressLine2";
     const ADDRESS_ZIP_CODE: &'static str = "ZipCode";
     const ADDRESS_DOMESTIC_PHONE_NUMBER: &'static str = "Phone";
+    const ADDRESS_PHONE_NUMBER: &'static str = "PhoneNumber";
+    const ADDRESS_INTERNATIONAL_PHONE_NUMBER: &'static str = "InternationalPhoneNumber";
     const ADDRESS_LATITUDE: &'static str = "Latitude";
     const ADDRESS_LONGITUDE: &'static str = "Longitude";
+    const ADDRESS_COORDINATES: &'static str = "Coordinates";
+    const FINANCE_BANK_NAME: &'static str = "BankName";
+    const FINANCE_BANK_CODE: &'static str = "BankCode";
+    const FINANCE_BRANCH_NAME: &'static str = "BranchName";
+    const FINANCE_BRANCH_CODE: &'static str = "BranchCode";
+    const FINANCE_ACCOUNT_TYPE: &'static str = "AccountType";
+    const FINANCE_ACCOUNT_NUMBER: &'static str = "AccountNumber";
+    const FINANCE_ACCOUNT_HOLDER: &'static str = "AccountHolder";
+    const COMMERCE_JAN: &'static str = "JAN";
+    const COMMERCE_EAN8: &'static str = "EAN8";
+    const COMMERCE_UPC: &'static str = "UPC";
+    const COMMERCE_ISBN10: &'static str = "ISBN10";
+    const COMMERCE_ISBN13: &'static str = "ISBN13";
+    const COMMERCE_PRODUCT_NAME: &'static str = "ProductName";
+    const COMMERCE_PRODUCT_CATEGORY: &'static str = "ProductCategory";
+    const COMMERCE_SKU: &'static str = "Sku";
+    const COMMERCE_PRICE: &'static str = "Price";
+    const COMMERCE_CURRENCY_CODE: &'static str = "CurrencyCode";
+    const COMMERCE_QUANTITY: &'static str = "Quantity";
+    const DEFAULT_SKU_PATTERN: &'static str = "AAA-99999";
+    const DEFAULT_QUANTITY: (usize, usize) = (1, 10);
     const DATE_TIME_TIME: &'static str = "Time";
     const DATE_TIME_DATE: &'static str = "Date";
     const DATE_TIME_DATE_TIME: &'static str = "DateTime";
@@ -79,6 +152,8 @@ impl Scanner {
             Internet => Self::readable_internet_options(),
             Company => Self::readable_company_options(),
             Address => Self::readable_address_options(),
+            Finance => Self::readable_finance_options(),
+            Commerce => Self::readable_commerce_options(),
             DateTime => Self::readable_datetime_options(),
             FileSystem => Self::readable_filesystem_options(),
         }
@@ -87,6 +162,7 @@ impl Scanner {
     pub fn readable_with_options() -> Vec<String> {
         let mut stack: Vec<String> = Vec::new();
         stack.push(Self::WITH_JOIN_OPTION_FORMAT.to_string());
+        stack.push(Self::WITH_LOCALE_OPTION_FORMAT.to_string());
         return stack;
     }
 
@@ -117,6 +193,16 @@ impl Scanner {
             Self::SELECT_NOT_STRING,
             Self::STRING_LIST_VAR,
         ));
+        stack.push(Self::option_format(
+            Category::Select,
+            Self::SELECT_FROM_FILE,
+            Self::FILE_PATH_VAR,
+        ));
+        stack.push(Self::option_format(
+            Category::Select,
+            Self::SELECT_FROM_COLUMN,
+            &format!("{}#{}", Self::FILE_PATH_VAR, Self::STRING_VAR),
+        ));
         return stack;
     }
 
@@ -142,29 +228,54 @@ impl Scanner {
 
     pub fn readable_name_options() -> Vec<String> {
         let mut stack: Vec<String> = Vec::new();
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Name,
             Self::NAME_FIRST_NAME,
+            &format!("{}(#{})?", Self::BOOL_VAR, Self::KANA_SCRIPT_VAR),
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Name,
             Self::NAME_FIRST_NAME_FURIGANA,
+            Self::KANA_SCRIPT_VAR,
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Name,
             Self::NAME_LAST_NAME,
+            &format!("{}(#{})?", Self::BOOL_VAR, Self::KANA_SCRIPT_VAR),
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Name,
             Self::NAME_LAST_NAME_FURIGANA,
+            Self::KANA_SCRIPT_VAR,
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Name,
             Self::NAME_FULL_NAME,
+            &format!("{}(#{})?", Self::BOOL_VAR, Self::KANA_SCRIPT_VAR),
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Name,
             Self::NAME_FULL_NAME_FURIGANA,
+            Self::KANA_SCRIPT_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Name,
+            Self::NAME_FIRST_NAME_ROMAJI,
+            Self::CAPITALIZATION_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Name,
+            Self::NAME_LAST_NAME_ROMAJI,
+            Self::CAPITALIZATION_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Name,
+            Self::NAME_FULL_NAME_ROMAJI,
+            Self::CAPITALIZATION_VAR,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Name,
+            Self::NAME_GENDER,
         ));
         return stack;
     }
@@ -206,23 +317,85 @@ impl Scanner {
         stack.push(Self::option_format(
             Category::Internet,
             Self::INTERNET_PASSWORD,
-            Self::UNSIGNED_INTEGER_RANGE_VAR,
+            &format!(
+                "{}(#{}(#{})?)?",
+                Self::UNSIGNED_INTEGER_RANGE_VAR,
+                Self::PASSWORD_CLASSES_VAR,
+                Self::STRING_VAR
+            ),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_PASSWORD_HASH,
+            Self::HASH_FORMAT_VAR,
         ));
         stack.push(Self::option_format_has_no_arg(
             Category::Internet,
             Self::INTERNET_CREDIT_CARD,
         ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_CREDIT_CARD_NUMBER,
+            Self::CARD_BRAND_VAR,
+        ));
         stack.push(Self::option_format_has_no_arg(
             Category::Internet,
-            Self::INTERNET_URL,
+            Self::INTERNET_CREDIT_CARD_EXPIRY,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_CREDIT_CARD_CVV,
+            Self::CARD_BRAND_VAR,
         ));
         stack.push(Self::option_format_has_no_arg(
+            Category::Internet,
+            Self::INTERNET_CREDIT_CARD_HOLDER,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_URL,
+            &format!(
+                "{}(#{}(#{}(#{}(#{}(#{})?)?)?)?)?",
+                Self::URL_SCHEME_VAR,
+                Self::UNSIGNED_INTEGER_VAR,
+                Self::UNSIGNED_INTEGER_VAR,
+                Self::BOOL_VAR,
+                Self::BOOL_VAR,
+                Self::BOOL_VAR
+            ),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Internet,
             Self::INTERNET_IPV4,
+            &format!("{}(#{})?", Self::IP_RANGE_VAR, Self::BOOL_VAR),
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Internet,
             Self::INTERNET_IPV6,
+            Self::IP_RANGE_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_MAC,
+            &format!("{}(#{})?", Self::MAC_STYLE_VAR, Self::BOOL_VAR),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_HOSTNAME,
+            Self::BOOL_VAR,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Internet,
+            Self::INTERNET_DOMAIN_NAME,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_PORT,
+            Self::PORT_RANGE_VAR,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Internet,
+            Self::INTERNET_SLUG,
         ));
         stack.push(Self::option_format_has_no_arg(
             Category::Internet,
@@ -232,9 +405,36 @@ impl Scanner {
             Category::Internet,
             Self::INTERNET_RGBA,
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_COLOR,
+            &format!("{}(#{})?", Self::COLOR_FORMAT_VAR, Self::COLOR_TONE_VAR),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_COLOR_NAME,
+            Self::BOOL_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_CSS_COLOR_NAME,
+            Self::BOOL_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Internet,
             Self::INTERNET_USER_AGENT,
+            &format!(
+                "{}(#{}(#{}(#{})?)?)?",
+                Self::BROWSER_VAR,
+                Self::DEVICE_TYPE_VAR,
+                Self::PLATFORM_VAR,
+                Self::UNSIGNED_INTEGER_RANGE_VAR
+            ),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Internet,
+            Self::INTERNET_ACCESS_LOG,
+            Self::ACCESS_LOG_FORMAT_VAR,
         ));
         stack.push(Self::option_format_has_no_arg(
             Category::Internet,
@@ -257,6 +457,35 @@ impl Scanner {
             Category::Company,
             Self::COMPANY_INDUSTRY,
         ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Company,
+            Self::COMPANY_CORPORATE_NUMBER,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Company,
+            Self::COMPANY_INDIVIDUAL_NUMBER,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Company,
+            Self::COMPANY_DEPARTMENT,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Company,
+            Self::COMPANY_JOB_TITLE,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Company,
+            Self::COMPANY_EMPLOYEE_NUMBER,
+            Self::STRING_VAR,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Company,
+            Self::COMPANY_DOMAIN,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Company,
+            Self::COMPANY_EMPLOYEE_EMAIL,
+        ));
         return stack;
     }
 
@@ -294,6 +523,20 @@ impl Scanner {
             Category::Address,
             Self::ADDRESS_ADDRESS,
         ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Address,
+            Self::ADDRESS_DETAILED_ADDRESS,
+            Self::ADDRESS_STYLE_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Address,
+            Self::ADDRESS_ADDRESS_LINE1,
+            Self::ADDRESS_STYLE_VAR,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Address,
+            Self::ADDRESS_ADDRESS_LINE2,
+        ));
         stack.push(Self::option_format_has_no_necessary_arg(
             Category::Address,
             Self::ADDRESS_ZIP_CODE,
@@ -304,17 +547,124 @@ impl Scanner {
             Self::ADDRESS_DOMESTIC_PHONE_NUMBER,
             Self::BOOL_VAR,
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Address,
+            Self::ADDRESS_PHONE_NUMBER,
+            &format!("{}(#{})?", Self::PHONE_KIND_VAR, Self::BOOL_VAR),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Address,
+            Self::ADDRESS_INTERNATIONAL_PHONE_NUMBER,
+            Self::PHONE_KIND_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Address,
             Self::ADDRESS_LATITUDE,
+            Self::GEO_AREA_VAR,
         ));
-        stack.push(Self::option_format_has_no_arg(
+        stack.push(Self::option_format_has_no_necessary_arg(
             Category::Address,
             Self::ADDRESS_LONGITUDE,
+            Self::GEO_AREA_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Address,
+            Self::ADDRESS_COORDINATES,
+            Self::GEO_AREA_VAR,
         ));
         return stack;
     }
 
+    pub fn readable_finance_options() -> Vec<String> {
+        let mut stack: Vec<String> = Vec::new();
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_BANK_NAME,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_BANK_CODE,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_BRANCH_NAME,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_BRANCH_CODE,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_ACCOUNT_TYPE,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_ACCOUNT_NUMBER,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Finance,
+            Self::FINANCE_ACCOUNT_HOLDER,
+        ));
+        stack
+    }
+
+    pub fn readable_commerce_options() -> Vec<String> {
+        let mut stack: Vec<String> = Vec::new();
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_JAN,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_EAN8,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_UPC,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_ISBN10,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_ISBN13,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_PRODUCT_NAME,
+        ));
+        stack.push(Self::option_format_has_no_arg(
+            Category::Commerce,
+            Self::COMMERCE_PRODUCT_CATEGORY,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Commerce,
+            Self::COMMERCE_SKU,
+            Self::STRING_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Commerce,
+            Self::COMMERCE_PRICE,
+            &format!(
+                "{}(#{})?",
+                Self::CURRENCY_VAR,
+                Self::UNSIGNED_INTEGER_RANGE_VAR
+            ),
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Commerce,
+            Self::COMMERCE_CURRENCY_CODE,
+            Self::CURRENCY_VAR,
+        ));
+        stack.push(Self::option_format_has_no_necessary_arg(
+            Category::Commerce,
+            Self::COMMERCE_QUANTITY,
+            Self::UNSIGNED_INTEGER_RANGE_VAR,
+        ));
+        stack
+    }
+
     pub fn readable_datetime_options() -> Vec<String> {
         let mut stack: Vec<String> = Vec::new();
         stack.push(Self::option_format(
@@ -354,6 +704,8 @@ impl Scanner {
     const SPECIAL_OPTION_VAR: &'static str = "<special_option>";
     const WITH_JOIN_OPTION_VAR: &'static str = "<with_join_option>";
     const JOIN_SEPARATOR_VAR: &'static str = "<join_separator>";
+    const WITH_LOCALE_OPTION_VAR: &'static str = "<with_locale_option>";
+    const LOCALE_VAR: &'static str = "<locale>";
     const REPEATABLE_OPTION_VAR: &'static str = "<repeatable_option>";
     const CATEGORY_VAR: &'static str = "<category>";
     const OPTION_NAME_VAR: &'static str = "<option_name>";
@@ -366,16 +718,40 @@ impl Scanner {
     const UNSIGNED_INTEGER_VAR: &'static str = "<unsigned_integer>";
     const BOOL_VAR: &'static str = "<bool>";
     const FORMAT_STRING_VAR: &'static str = "<format_string>";
+    const FILE_PATH_VAR: &'static str = "<file_path>";
+    const CAPITALIZATION_VAR: &'static str = "<capitalization>";
+    const KANA_SCRIPT_VAR: &'static str = "<kana_script>";
+    const ADDRESS_STYLE_VAR: &'static str = "<address_style>";
+    const PHONE_KIND_VAR: &'static str = "<phone_kind>";
+    const CARD_BRAND_VAR: &'static str = "<card_brand>";
+    const MAC_STYLE_VAR: &'static str = "<mac_style>";
+    const IP_RANGE_VAR: &'static str = "<ip_range>";
+    const URL_SCHEME_VAR: &'static str = "<url_scheme>";
+    const PORT_RANGE_VAR: &'static str = "<port_range>";
+    const PASSWORD_CLASSES_VAR: &'static str = "<password_classes>";
+    const HASH_FORMAT_VAR: &'static str = "<hash_format>";
+    const COLOR_FORMAT_VAR: &'static str = "<color_format>";
+    const COLOR_TONE_VAR: &'static str = "<color_tone>";
+    const BROWSER_VAR: &'static str = "<browser>";
+    const DEVICE_TYPE_VAR: &'static str = "<device_type>";
+    const PLATFORM_VAR: &'static str = "<platform>";
+    const ACCESS_LOG_FORMAT_VAR: &'static str = "<access_log_format>";
+    const CURRENCY_VAR: &'static str = "<currency>";
+    const GEO_AREA_VAR: &'static str = "<geo_area>";
+    const FLOAT_VAR: &'static str = "<float>";
     const OPTION_WITHOUT_COLUMN_NAME_VAR: &'static str = "<option_without_column_name>";
 
     // value
     const OPTION_FORMAT: &'static str = "<normal_option>|<special_option>";
     const NORMAL_OPTION_FORMAT: &'static str =
         "<category>\\.<option_name>\\(<column_name>(#<sub_option>)?\\)";
-    const SPECIAL_OPTION_FORMAT: &'static str = "<with_join_option>";
+    const SPECIAL_OPTION_FORMAT: &'static str = "<with_join_option>|<with_locale_option>";
     const WITH_JOIN_OPTION_FORMAT: &'static str =
         "With\\.Join\\(<column_name>#<join_separator>(#<repeatable_option>)*\\)";
     const JOIN_SEPARATOR_FORMAT: &'static str = "[^#]*";
+    const WITH_LOCALE_OPTION_FORMAT: &'static str =
+        "With\\.Locale\\(<column_name>#<locale>#<option_without_column_name>\\)";
+    const LOCALE_FORMAT: &'static str = "(jpn)|(usa)|(kor)";
     const REPEATABLE_OPTION_FORMAT: &'static str =
         "<unsigned_integer>?#<option_without_column_name>";
     const OPTION_WITHOUT_COLUMN_NAME_FORMAT: &'static str =
@@ -383,13 +759,35 @@ impl Scanner {
     const CATEGORY_FORMAT: &'static str = "[A-Z][0-9a-zA-Z]*";
     const OPTION_NAME_FORMAT: &'static str = "[A-Z][0-9a-zA-Z]*";
     const SUB_OPTION_FORMAT: &'static str =
-        "<string>|<string_list>|<unsigned_integer_range>|<signed_integer_range>|<boolean>|<format_string>";
+        "<string>|<string_list>|<unsigned_integer_range>|<signed_integer_range>|<boolean>|<format_string>|<file_path>|<capitalization>|<kana_script>|<address_style>|<phone_kind>|<card_brand>|<mac_style>|<port_range>|<ip_range>|<url_scheme>|<password_classes>|<hash_format>|<color_format>|<color_tone>|<browser>|<device_type>|<platform>|<access_log_format>|<currency>|<geo_area>";
     const STRING_FORMAT: &'static str = "((\".*\")|[.[^\\ ]]*)";
     const STRING_LIST_FORMAT: &'static str = "\\[<string>(#<string>)*\\]";
     const UNSIGNED_INTEGER_RANGE_FORMAT: &'static str = "<unsigned_integer>#<unsigned_integer>";
     const SIGNED_INTEGER_RANGE_FORMAT: &'static str = "-?<unsigned_integer>#-?<unsigned_integer>";
     const UNSIGNED_INTEGER_FORMAT: &'static str = "[0-9][1-9]*";
     const BOOL_FORMAT: &'static str = "(true)|(false)";
+    const CAPITALIZATION_FORMAT: &'static str = "(capitalized)|(upper)|(lower)";
+    const KANA_SCRIPT_FORMAT: &'static str = "(katakana)|(hiragana)";
+    const ADDRESS_STYLE_FORMAT: &'static str = "(hyphen)|(kanji)";
+    const PHONE_KIND_FORMAT: &'static str = "(landline)|(mobile)|(ip)|(toll_free)";
+    const CARD_BRAND_FORMAT: &'static str = "(visa)|(mastercard)|(jcb)|(amex)";
+    const MAC_STYLE_FORMAT: &'static str = "(colon)|(hyphen)|(dot)|(plain)";
+    const PORT_RANGE_FORMAT: &'static str = "(any)|(well_known)|(registered)|(ephemeral)";
+    const IP_RANGE_FORMAT: &'static str =
+        "(private)|(documentation)|(loopback)|(link_local)|(<ip_address>/<prefix_length>)";
+    const URL_SCHEME_FORMAT: &'static str = "(http)|(https)";
+    const PASSWORD_CLASSES_FORMAT: &'static str = "[ulds]+";
+    const HASH_FORMAT_FORMAT: &'static str = "(sha256)|(sha256_crypt)";
+    const COLOR_FORMAT_FORMAT: &'static str = "(hex)|(css_rgb)|(css_hsl)|(hsl)|(hsv)";
+    const COLOR_TONE_FORMAT: &'static str = "(any)|(pastel)|(vivid)|(muted)|(dark)";
+    const BROWSER_FORMAT: &'static str = "(any)|(chrome)|(firefox)|(safari)|(edge)";
+    const DEVICE_TYPE_FORMAT: &'static str = "(any)|(desktop)|(mobile)|(bot)";
+    const PLATFORM_FORMAT: &'static str = "(any)|(windows)|(mac_os)|(linux)|(android)|(ios)";
+    const ACCESS_LOG_FORMAT_FORMAT: &'static str = "(common)|(combined)|(nginx)|<string>";
+    const CURRENCY_FORMAT: &'static str = "(local)|(any)|(jpy)|(usd)|(eur)|(gbp)|(krw)|(cny)";
+    const GEO_AREA_FORMAT: &'static str =
+        "(globe)|(country)|(state)|(box#<float>#<float>#<float>#<float>)|(circle#<float>#<float>#<float>)";
+    const FLOAT_FORMAT: &'static str = "-?[0-9]+(\\.[0-9]+)?";
 
     // format pair
     const OPTION: (&'static str, &'static str) = (Scanner::OPTION_VAR, Scanner::OPTION_FORMAT);
@@ -403,6 +801,11 @@ impl Scanner {
     );
     const JOIN_SEPARATOR: (&'static str, &'static str) =
         (Scanner::JOIN_SEPARATOR_VAR, Scanner::JOIN_SEPARATOR_FORMAT);
+    const WITH_LOCALE_OPTION: (&'static str, &'static str) = (
+        Scanner::WITH_LOCALE_OPTION_VAR,
+        Scanner::WITH_LOCALE_OPTION_FORMAT,
+    );
+    const LOCALE: (&'static str, &'static str) = (Scanner::LOCALE_VAR, Scanner::LOCALE_FORMAT);
     const REPEAT_OPTION: (&'static str, &'static str) = (
         Scanner::REPEATABLE_OPTION_VAR,
         Scanner::REPEATABLE_OPTION_FORMAT,
@@ -437,6 +840,49 @@ impl Scanner {
     const BOOL: (&'static str, &'static str) = (Scanner::BOOL_VAR, Scanner::BOOL_FORMAT);
     const FORMAT_STRING: (&'static str, &'static str) =
         (Scanner::FORMAT_STRING_VAR, Scanner::STRING_VAR);
+    const FILE_PATH: (&'static str, &'static str) = (Scanner::FILE_PATH_VAR, Scanner::STRING_VAR);
+    const CAPITALIZATION: (&'static str, &'static str) =
+        (Scanner::CAPITALIZATION_VAR, Scanner::CAPITALIZATION_FORMAT);
+    const KANA_SCRIPT: (&'static str, &'static str) =
+        (Scanner::KANA_SCRIPT_VAR, Scanner::KANA_SCRIPT_FORMAT);
+    const ADDRESS_STYLE: (&'static str, &'static str) =
+        (Scanner::ADDRESS_STYLE_VAR, Scanner::ADDRESS_STYLE_FORMAT);
+    const PHONE_KIND: (&'static str, &'static str) =
+        (Scanner::PHONE_KIND_VAR, Scanner::PHONE_KIND_FORMAT);
+    const CARD_BRAND: (&'static str, &'static str) =
+        (Scanner::CARD_BRAND_VAR, Scanner::CARD_BRAND_FORMAT);
+    const MAC_STYLE: (&'static str, &'static str) =
+        (Scanner::MAC_STYLE_VAR, Scanner::MAC_STYLE_FORMAT);
+    const PORT_RANGE: (&'static str, &'static str) =
+        (Scanner::PORT_RANGE_VAR, Scanner::PORT_RANGE_FORMAT);
+    const IP_RANGE: (&'static str, &'static str) =
+        (Scanner::IP_RANGE_VAR, Scanner::IP_RANGE_FORMAT);
+    const URL_SCHEME: (&'static str, &'static str) =
+        (Scanner::URL_SCHEME_VAR, Scanner::URL_SCHEME_FORMAT);
+    const PASSWORD_CLASSES: (&'static str, &'static str) = (
+        Scanner::PASSWORD_CLASSES_VAR,
+        Scanner::PASSWORD_CLASSES_FORMAT,
+    );
+    const HASH_FORMAT: (&'static str, &'static str) =
+        (Scanner::HASH_FORMAT_VAR, Scanner::HASH_FORMAT_FORMAT);
+    const COLOR_FORMAT: (&'static str, &'static str) =
+        (Scanner::COLOR_FORMAT_VAR, Scanner::COLOR_FORMAT_FORMAT);
+    const COLOR_TONE: (&'static str, &'static str) =
+        (Scanner::COLOR_TONE_VAR, Scanner::COLOR_TONE_FORMAT);
+    const BROWSER: (&'static str, &'static str) = (Scanner::BROWSER_VAR, Scanner::BROWSER_FORMAT);
+    const DEVICE_TYPE: (&'static str, &'static str) =
+        (Scanner::DEVICE_TYPE_VAR, Scanner::DEVICE_TYPE_FORMAT);
+    const PLATFORM: (&'static str, &'static str) =
+        (Scanner::PLATFORM_VAR, Scanner::PLATFORM_FORMAT);
+    const ACCESS_LOG_FORMAT: (&'static str, &'static str) = (
+        Scanner::ACCESS_LOG_FORMAT_VAR,
+        Scanner::ACCESS_LOG_FORMAT_FORMAT,
+    );
+    const CURRENCY: (&'static str, &'static str) =
+        (Scanner::CURRENCY_VAR, Scanner::CURRENCY_FORMAT);
+    const GEO_AREA: (&'static str, &'static str) =
+        (Scanner::GEO_AREA_VAR, Scanner::GEO_AREA_FORMAT);
+    const FLOAT: (&'static str, &'static str) = (Scanner::FLOAT_VAR, Scanner::FLOAT_FORMAT);
 
     fn all_format_pair() -> Vec<(&'static str, &'static str)> {
         [
@@ -445,6 +891,8 @@ impl Scanner {
             Self::SPECIAL_OPTION,
             Self::WITH_JOIN_OPTION,
             Self::JOIN_SEPARATOR,
+            Self::WITH_LOCALE_OPTION,
+            Self::LOCALE,
             Self::REPEAT_OPTION,
             Self::OPTION_WITHOUT_COLUMN_NAME,
             Self::CATEGORY,
@@ -458,6 +906,27 @@ impl Scanner {
             Self::UNSIGNED_INT,
             Self::BOOL,
             Self::FORMAT_STRING,
+            Self::FILE_PATH,
+            Self::CAPITALIZATION,
+            Self::KANA_SCRIPT,
+            Self::ADDRESS_STYLE,
+            Self::PHONE_KIND,
+            Self::CARD_BRAND,
+            Self::MAC_STYLE,
+            Self::PORT_RANGE,
+            Self::IP_RANGE,
+            Self::URL_SCHEME,
+            Self::PASSWORD_CLASSES,
+            Self::HASH_FORMAT,
+            Self::COLOR_FORMAT,
+            Self::COLOR_TONE,
+            Self::BROWSER,
+            Self::DEVICE_TYPE,
+            Self::PLATFORM,
+            Self::ACCESS_LOG_FORMAT,
+            Self::CURRENCY,
+            Self::GEO_AREA,
+            Self::FLOAT,
         ]
         .to_vec()
     }
@@ -577,61 +1046,466 @@ impl Scanner {
         }
     }
 
-    // ---
-    // combination parser
-    // ---
-    fn parse_category(&self, target: &str) -> Result<Category, ScannerError> {
-        if target == &Category::With.to_string() {
-            return Ok(Category::With);
-        }
-        if target == &Category::Fixed.to_string() {
-            return Ok(Category::Fixed);
+    fn parse_unsigned_integer(subs: &[String]) -> Result<usize, ScannerError> {
+        let s = Self::parse_string(subs)?;
+        usize::from_str(&s).map_err(|_| ScannerError::UnknownIntegerListFormat(subs.to_vec()))
+    }
+
+    fn parse_capitalization(subs: &[String]) -> Result<Capitalization, ScannerError> {
+        if subs.is_empty() {
+            return Ok(Capitalization::default());
         }
-        if target == &Category::Select.to_string() {
-            return Ok(Category::Select);
+        let s = Self::parse_string(subs)?;
+        Capitalization::all_list()
+            .into_iter()
+            .find(|c| c.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownCapitalizationFormat(subs.to_vec()))
+    }
+
+    fn parse_kana_script(subs: &[String]) -> Result<KanaScript, ScannerError> {
+        if subs.is_empty() {
+            return Ok(KanaScript::default());
         }
-        if target == &Category::Lorem.to_string() {
-            return Ok(Category::Lorem);
+        let s = Self::parse_string(subs)?;
+        KanaScript::all_list()
+            .into_iter()
+            .find(|script| script.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownKanaScriptFormat(subs.to_vec()))
+    }
+
+    fn parse_address_style(subs: &[String]) -> Result<AddressStyle, ScannerError> {
+        if subs.is_empty() {
+            return Ok(AddressStyle::default());
         }
-        if target == &Category::Name.to_string() {
-            return Ok(Category::Name);
+        let s = Self::parse_string(subs)?;
+        AddressStyle::all_list()
+            .into_iter()
+            .find(|style| style.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownAddressStyleFormat(subs.to_vec()))
+    }
+
+    fn parse_phone_kind(subs: &[String]) -> Result<PhoneKind, ScannerError> {
+        if subs.is_empty() {
+            return Ok(PhoneKind::default());
         }
-        if target == &Category::Primitive.to_string() {
-            return Ok(Category::Primitive);
+        let s = Self::parse_string(subs)?;
+        PhoneKind::all_list()
+            .into_iter()
+            .find(|kind| kind.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownPhoneKindFormat(subs.to_vec()))
+    }
+
+    fn parse_card_brand(subs: &[String]) -> Result<CardBrand, ScannerError> {
+        if subs.is_empty() {
+            return Ok(CardBrand::default());
         }
-        if target == &Category::Internet.to_string() {
-            return Ok(Category::Internet);
+        let s = Self::parse_string(subs)?;
+        CardBrand::all_list()
+            .into_iter()
+            .find(|brand| brand.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownCardBrandFormat(subs.to_vec()))
+    }
+
+    fn parse_mac_style(subs: &[String]) -> Result<MacStyle, ScannerError> {
+        if subs.is_empty() {
+            return Ok(MacStyle::default());
         }
-        if target == &Category::Company.to_string() {
-            return Ok(Category::Company);
+        let s = Self::parse_string(subs)?;
+        MacStyle::all_list()
+            .into_iter()
+            .find(|style| style.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownMacStyleFormat(subs.to_vec()))
+    }
+
+    fn parse_port_range(subs: &[String]) -> Result<PortRange, ScannerError> {
+        if subs.is_empty() {
+            return Ok(PortRange::default());
         }
-        if target == &Category::Address.to_string() {
-            return Ok(Category::Address);
+        let s = Self::parse_string(subs)?;
+        PortRange::all_list()
+            .into_iter()
+            .find(|range| range.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownPortRangeFormat(subs.to_vec()))
+    }
+
+    fn parse_url_scheme(subs: &[String]) -> Result<UrlScheme, ScannerError> {
+        if subs.is_empty() {
+            return Ok(UrlScheme::default());
         }
-        if target == &Category::DateTime.to_string() {
-            return Ok(Category::DateTime);
+        let s = Self::parse_string(subs)?;
+        UrlScheme::all_list()
+            .into_iter()
+            .find(|scheme| scheme.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownUrlSchemeFormat(subs.to_vec()))
+    }
+
+    fn parse_password_classes(subs: &[String]) -> Result<Vec<PasswordClass>, ScannerError> {
+        let s = Self::parse_string(subs)?;
+        let mut classes: Vec<PasswordClass> = Vec::new();
+        for letter in s.chars() {
+            let class: PasswordClass = PasswordClass::all_list()
+                .into_iter()
+                .find(|class| class.letter() == letter)
+                .ok_or_else(|| ScannerError::UnknownPasswordClassesFormat(subs.to_vec()))?;
+            if !classes.contains(&class) {
+                classes.push(class);
+            }
         }
-        if target == &Category::FileSystem.to_string() {
-            return Ok(Category::FileSystem);
+        if classes.is_empty() {
+            return Err(ScannerError::UnknownPasswordClassesFormat(subs.to_vec()));
         }
-        return Err(ScannerError::UnknownCategory(target.to_string()));
+        Ok(classes)
     }
 
-    fn parse_with(
-        &self,
-        option_name: &str,
-        sub_option_str: Option<&str>,
-    ) -> Result<FakeOption, ScannerError> {
-        if option_name == Self::JOIN {
-            if sub_option_str.is_none() {
-                return Ok(FakeOption::Join("".to_string(), vec![]));
-            }
-            let split_regex: Regex = Regex::new(
-                r"^(?P<Separator>[^#]*?)(?P<OptionItemsStr>(?:(?:#(?:[0-9]+))?#(?:[A-Z][[:alnum:]]*?)\.(?:[A-Z][[:alnum:]]*?)\((?:.*?)?\))*)$"
-            ).unwrap();
+    fn parse_hash_format(subs: &[String]) -> Result<HashFormat, ScannerError> {
+        if subs.is_empty() {
+            return Ok(HashFormat::default());
+        }
+        let s = Self::parse_string(subs)?;
+        HashFormat::all_list()
+            .into_iter()
+            .find(|format| format.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownHashFormatFormat(subs.to_vec()))
+    }
 
-            let split_capture = split_regex.captures(sub_option_str.unwrap());
-            if split_capture.is_none() {
+    fn parse_color_format(subs: &[String]) -> Result<ColorFormat, ScannerError> {
+        if subs.is_empty() {
+            return Ok(ColorFormat::default());
+        }
+        let s = Self::parse_string(subs)?;
+        ColorFormat::all_list()
+            .into_iter()
+            .find(|format| format.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownColorFormatFormat(subs.to_vec()))
+    }
+
+    fn parse_color_tone(subs: &[String]) -> Result<ColorTone, ScannerError> {
+        if subs.is_empty() {
+            return Ok(ColorTone::default());
+        }
+        let s = Self::parse_string(subs)?;
+        ColorTone::all_list()
+            .into_iter()
+            .find(|tone| tone.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownColorToneFormat(subs.to_vec()))
+    }
+
+    // such as [] or [<color_format>] or [<color_format>, <color_tone>]
+    fn parse_color(subs: &[String]) -> Result<(ColorFormat, ColorTone), ScannerError> {
+        match subs.len() {
+            0 => Ok((ColorFormat::default(), ColorTone::default())),
+            1 => Ok((Self::parse_color_format(subs)?, ColorTone::default())),
+            2 => Ok((
+                Self::parse_color_format(&subs[..1])?,
+                Self::parse_color_tone(&subs[1..])?,
+            )),
+            _ => Err(ScannerError::UnknownCharacters(subs[2..].to_vec())),
+        }
+    }
+
+    fn parse_browser(subs: &[String]) -> Result<Browser, ScannerError> {
+        if subs.is_empty() {
+            return Ok(Browser::default());
+        }
+        let s = Self::parse_string(subs)?;
+        Browser::all_list()
+            .into_iter()
+            .find(|browser| browser.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownBrowserFormat(subs.to_vec()))
+    }
+
+    fn parse_device_type(subs: &[String]) -> Result<DeviceType, ScannerError> {
+        if subs.is_empty() {
+            return Ok(DeviceType::default());
+        }
+        let s = Self::parse_string(subs)?;
+        DeviceType::all_list()
+            .into_iter()
+            .find(|device| device.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownDeviceTypeFormat(subs.to_vec()))
+    }
+
+    fn parse_platform(subs: &[String]) -> Result<Platform, ScannerError> {
+        if subs.is_empty() {
+            return Ok(Platform::default());
+        }
+        let s = Self::parse_string(subs)?;
+        Platform::all_list()
+            .into_iter()
+            .find(|platform| platform.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownPlatformFormat(subs.to_vec()))
+    }
+
+    // name of the format or format string such as "%h %t \"%r\" %>s"
+    fn parse_access_log_format(subs: &[String]) -> Result<AccessLogFormat, ScannerError> {
+        if subs.is_empty() {
+            return Ok(AccessLogFormat::default());
+        }
+        let s = Self::parse_string(subs)?;
+        if s.is_empty() {
+            return Err(ScannerError::UnknownAccessLogFormatFormat(subs.to_vec()));
+        }
+        Ok(AccessLogFormat::all_list()
+            .into_iter()
+            .find(|format| format.to_string() == s)
+            .unwrap_or(AccessLogFormat::Custom(s)))
+    }
+
+    fn parse_currency(subs: &[String]) -> Result<Currency, ScannerError> {
+        if subs.is_empty() {
+            return Ok(Currency::default());
+        }
+        let s = Self::parse_string(subs)?;
+        Currency::all_list()
+            .into_iter()
+            .find(|currency| currency.to_string() == s)
+            .ok_or_else(|| ScannerError::UnknownCurrencyFormat(subs.to_vec()))
+    }
+
+    // such as [<name>] or [box, <south>, <west>, <north>, <east>] or [circle, <latitude>, <longitude>, <radius>]
+    fn parse_geo_area(subs: &[String]) -> Result<GeoArea, ScannerError> {
+        if subs.is_empty() {
+            return Ok(GeoArea::default());
+        }
+        let values: Vec<f64> = subs[1..]
+            .iter()
+            .map(|s| f64::from_str(s).ok().filter(|v| v.is_finite()))
+            .collect::<Option<Vec<f64>>>()
+            .ok_or_else(|| ScannerError::UnknownGeoAreaFormat(subs.to_vec()))?;
+        let area: Option<GeoArea> = match (subs[0].as_str(), values.as_slice()) {
+            ("globe", []) => Some(GeoArea::Globe),
+            ("country", []) => Some(GeoArea::Country),
+            ("state", []) => Some(GeoArea::State),
+            ("box", [south, west, north, east]) => {
+                GeoArea::bounding_box(*south, *west, *north, *east)
+            }
+            ("circle", [latitude, longitude, radius]) => {
+                GeoArea::circle(*latitude, *longitude, *radius)
+            }
+            _ => None,
+        };
+        area.ok_or_else(|| ScannerError::UnknownGeoAreaFormat(subs.to_vec()))
+    }
+
+    // such as [<currency>] or [<currency>, <min>, <max>]
+    fn parse_price(subs: &[String]) -> Result<FakeOption, ScannerError> {
+        if subs.len() > 3 {
+            return Err(ScannerError::UnknownCharacters(subs[3..].to_vec()));
+        }
+        let currency: Currency = Self::parse_currency(&subs[..subs.len().min(1)])?;
+        if subs.len() > 1 {
+            let range = Self::parse_int_range::<usize>(&subs[1..])?;
+            if currency.minor_range(range.0, range.1).is_none() {
+                return Err(ScannerError::UnusablePriceRange(subs[1..].to_vec()));
+            }
+            return Ok(FakeOption::Price(currency, Some(range)));
+        }
+        Ok(FakeOption::Price(currency, None))
+    }
+
+    // such as [<browser>, <device_type>, <platform>, <min>, <max>] whose tail can be omitted
+    fn parse_user_agent(subs: &[String]) -> Result<UserAgentFormat, ScannerError> {
+        if subs.len() > 5 {
+            return Err(ScannerError::UnknownCharacters(subs[5..].to_vec()));
+        }
+        let mut format: UserAgentFormat = UserAgentFormat::default();
+        for (i, sub) in subs.iter().take(3).enumerate() {
+            let sub: &[String] = std::slice::from_ref(sub);
+            match i {
+                0 => format.browser = Self::parse_browser(sub)?,
+                1 => format.device = Self::parse_device_type(sub)?,
+                _ => format.platform = Self::parse_platform(sub)?,
+            }
+        }
+        if subs.len() > 3 {
+            format.version = Some(Self::parse_int_range::<usize>(&subs[3..])?);
+        }
+        if !format.is_usable() {
+            return Err(ScannerError::UnusableUserAgentFormat(subs.to_vec()));
+        }
+        Ok(format)
+    }
+
+    // such as [<min>, <max>] or [<min>, <max>, <password_classes>] or [<min>, <max>, <password_classes>, <excluded>]
+    fn parse_password(subs: &[String]) -> Result<FakeOption, ScannerError> {
+        if subs.len() > 4 {
+            return Err(ScannerError::UnknownCharacters(subs[4..].to_vec()));
+        }
+        let (from, to) = Self::parse_int_range::<usize>(&subs[..subs.len().min(2)])?;
+        if subs.len() == 2 {
+            return Ok(FakeOption::Password(from, to));
+        }
+        let policy: PasswordPolicy = PasswordPolicy {
+            classes: Self::parse_password_classes(&subs[2..3])?,
+            excluded: Self::parse_string(&subs[3..])?,
+        };
+        if !policy.is_usable(to) {
+            return Err(ScannerError::UnusablePasswordPolicy(subs.to_vec()));
+        }
+        Ok(FakeOption::PasswordWithPolicy(from, to, policy))
+    }
+
+    // such as [<url_scheme>, <depth>, <query>, <fragment>, <port>, <encoded>] whose tail can be omitted
+    fn parse_url(subs: &[String]) -> Result<UrlFormat, ScannerError> {
+        if subs.len() > 6 {
+            return Err(ScannerError::UnknownCharacters(subs[6..].to_vec()));
+        }
+        let mut format: UrlFormat = UrlFormat::default();
+        for (i, sub) in subs.iter().enumerate() {
+            let sub: &[String] = std::slice::from_ref(sub);
+            match i {
+                0 => format.scheme = Self::parse_url_scheme(sub)?,
+                1 => format.depth = Self::parse_unsigned_integer(sub)?,
+                2 => format.query = Self::parse_unsigned_integer(sub)?,
+                3 => format.fragment = Self::parse_bool(sub)?,
+                4 => format.port = Self::parse_bool(sub)?,
+                _ => format.encoded = Self::parse_bool(sub)?,
+            }
+        }
+        if !format.is_usable() {
+            return Err(ScannerError::UnusableUrlFormat(subs.to_vec()));
+        }
+        Ok(format)
+    }
+
+    // named range or CIDR block of the IP version such as 10.0.0.0/8
+    fn parse_ip_range(subs: &[String], v6: bool) -> Result<IpRange, ScannerError> {
+        if subs.is_empty() {
+            return Ok(IpRange::default());
+        }
+        let s = Self::parse_string(subs)?;
+        if let Some(range) = IpRange::all_list()
+            .into_iter()
+            .find(|range| range.to_string() == s)
+        {
+            return Ok(range);
+        }
+        let (network, prefix) = s
+            .split_once('/')
+            .ok_or_else(|| ScannerError::UnknownIpRangeFormat(subs.to_vec()))?;
+        let network = IpAddr::from_str(network)
+            .map_err(|_| ScannerError::UnknownIpRangeFormat(subs.to_vec()))?;
+        let prefix =
+            u8::from_str(prefix).map_err(|_| ScannerError::UnknownIpRangeFormat(subs.to_vec()))?;
+        let max_prefix: u8 = if network.is_ipv6() { 128 } else { 32 };
+        if network.is_ipv6() != v6 || prefix > max_prefix {
+            return Err(ScannerError::UnknownIpRangeFormat(subs.to_vec()));
+        }
+        Ok(IpRange::Cidr(network, prefix))
+    }
+
+    // such as [<ip_range>] or [<ip_range>, <bool>]
+    fn parse_ipv4_range(subs: &[String]) -> Result<(IpRange, bool), ScannerError> {
+        match subs.len() {
+            0 | 1 => Ok((Self::parse_ip_range(subs, false)?, false)),
+            2 => Ok((
+                Self::parse_ip_range(&subs[..1], false)?,
+                Self::parse_bool(&subs[1..])?,
+            )),
+            _ => Err(ScannerError::UnknownCharacters(subs[2..].to_vec())),
+        }
+    }
+
+    // such as [] or [<mac_style>] or [<mac_style>, <bool>]
+    fn parse_mac(subs: &[String]) -> Result<(MacStyle, bool), ScannerError> {
+        match subs.len() {
+            0 => Ok((MacStyle::default(), false)),
+            1 => Ok((Self::parse_mac_style(subs)?, false)),
+            2 => Ok((
+                Self::parse_mac_style(&subs[..1])?,
+                Self::parse_bool(&subs[1..])?,
+            )),
+            _ => Err(ScannerError::UnknownCharacters(subs[2..].to_vec())),
+        }
+    }
+
+    // such as [] or [<phone_kind>] or [<phone_kind>, <bool>]
+    fn parse_phone(subs: &[String]) -> Result<(PhoneKind, bool), ScannerError> {
+        match subs.len() {
+            0 => Ok((PhoneKind::default(), false)),
+            1 => Ok((Self::parse_phone_kind(subs)?, false)),
+            2 => Ok((
+                Self::parse_phone_kind(&subs[..1])?,
+                Self::parse_bool(&subs[1..])?,
+            )),
+            _ => Err(ScannerError::UnknownCharacters(subs[2..].to_vec())),
+        }
+    }
+
+    // such as [] or [<bool>] or [<bool>, <kana_script>]
+    fn parse_furigana(subs: &[String]) -> Result<(bool, KanaScript), ScannerError> {
+        match subs.len() {
+            0 => Ok((false, KanaScript::default())),
+            1 => Ok((Self::parse_bool(subs)?, KanaScript::default())),
+            2 => Ok((
+                Self::parse_bool(&subs[..1])?,
+                Self::parse_kana_script(&subs[1..])?,
+            )),
+            _ => Err(ScannerError::UnknownCharacters(subs[2..].to_vec())),
+        }
+    }
+
+    // ---
+    // combination parser
+    // ---
+    fn parse_category(&self, target: &str) -> Result<Category, ScannerError> {
+        if target == &Category::With.to_string() {
+            return Ok(Category::With);
+        }
+        if target == &Category::Fixed.to_string() {
+            return Ok(Category::Fixed);
+        }
+        if target == &Category::Select.to_string() {
+            return Ok(Category::Select);
+        }
+        if target == &Category::Lorem.to_string() {
+            return Ok(Category::Lorem);
+        }
+        if target == &Category::Name.to_string() {
+            return Ok(Category::Name);
+        }
+        if target == &Category::Primitive.to_string() {
+            return Ok(Category::Primitive);
+        }
+        if target == &Category::Internet.to_string() {
+            return Ok(Category::Internet);
+        }
+        if target == &Category::Company.to_string() {
+            return Ok(Category::Company);
+        }
+        if target == &Category::Address.to_string() {
+            return Ok(Category::Address);
+        }
+        if target == &Category::Finance.to_string() {
+            return Ok(Category::Finance);
+        }
+        if target == &Category::Commerce.to_string() {
+            return Ok(Category::Commerce);
+        }
+        if target == &Category::DateTime.to_string() {
+            return Ok(Category::DateTime);
+        }
+        if target == &Category::FileSystem.to_string() {
+            return Ok(Category::FileSystem);
+        }
+        return Err(ScannerError::UnknownCategory(target.to_string()));
+    }
+
+    fn parse_with(
+        &self,
+        option_name: &str,
+        sub_option_str: Option<&str>,
+    ) -> Result<FakeOption, ScannerError> {
+        if option_name == Self::JOIN {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::Join("".to_string(), vec![]));
+            }
+            let split_regex: Regex = Regex::new(
+                r"^(?P<Separator>[^#]*?)(?P<OptionItemsStr>(?:(?:#(?:[0-9]+))?#(?:[A-Z][[:alnum:]]*?)\.(?:[A-Z][[:alnum:]]*?)\((?:.*?)?\))*)$"
+            ).unwrap();
+
+            let split_capture = split_regex.captures(sub_option_str.unwrap());
+            if split_capture.is_none() {
                 return Err(ScannerError::UnknownOptionFormat(self.input.to_string()));
             }
             let capture: Captures = split_capture.unwrap();
@@ -683,6 +1557,23 @@ impl Scanner {
             }
             return Ok(FakeOption::Join(separator.to_string(), fake_option_items));
         }
+        if option_name == Self::WITH_LOCALE {
+            let option_regex = Regex::new(
+                r"^(?P<Locale>[^#]*)#(?P<Category>[A-Z][[:alnum:]]*?)\.(?P<OptionName>[A-Z][[:alnum:]]*?)\((?P<SubOption>.+)?\)$"
+            ).unwrap();
+            let capture: Captures = option_regex
+                .captures(sub_option_str.unwrap_or_default())
+                .ok_or(ScannerError::UnknownOptionFormat(self.input.to_string()))?;
+            let locale_code: &str = capture.name("Locale").unwrap().as_str();
+            let locale: Locale = Locale::from_code(locale_code)
+                .ok_or(ScannerError::UnknownLocale(locale_code.to_string()))?;
+            let option = self.get_fake_option(
+                capture.name("Category").unwrap().as_str(),
+                capture.name("OptionName").unwrap().as_str(),
+                capture.name("SubOption").map(|s| s.as_str()),
+            )?;
+            return Ok(FakeOption::WithLocale(locale, Box::new(option)));
+        }
         return Err(ScannerError::UnknownOption(
             option_name.to_string(),
             Category::With,
@@ -725,6 +1616,23 @@ impl Scanner {
                 &Self::split(sub_option_str),
             )?));
         }
+        if option_name == Self::SELECT_FROM_FILE {
+            let path: String = Self::parse_string(&Self::split(sub_option_str))?;
+            return FakeOption::select_from_file(&path)
+                .map_err(|e| ScannerError::UnreadableFile(path, e.to_string()));
+        }
+        if option_name == Self::SELECT_FROM_COLUMN {
+            let subs: Vec<String> = Self::split(sub_option_str);
+            if subs.len() != 2 {
+                return Err(ScannerError::WrongSubOptionCount(
+                    format!("{}.{}", Category::Select, Self::SELECT_FROM_COLUMN),
+                    2,
+                    subs,
+                ));
+            }
+            return FakeOption::select_from_column(&subs[0], &subs[1])
+                .map_err(|e| ScannerError::UnreadableFile(subs[0].to_string(), e.to_string()));
+        }
         return Err(ScannerError::UnknownOption(
             option_name.to_string(),
             Category::Select,
@@ -772,28 +1680,50 @@ impl Scanner {
         sub_option_str: Option<&str>,
     ) -> Result<FakeOption, ScannerError> {
         if option_name == Self::NAME_FIRST_NAME {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::FirstName(false));
+            let (furigana, script) = Self::parse_furigana(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::FirstName(furigana, script));
         }
         if option_name == Self::NAME_FIRST_NAME_FURIGANA {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::FirstNameFurigana);
+            return Ok(FakeOption::FirstNameFurigana(Self::parse_kana_script(
+                &Self::split(sub_option_str),
+            )?));
         }
         if option_name == Self::NAME_LAST_NAME {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::LastName(false));
+            let (furigana, script) = Self::parse_furigana(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::LastName(furigana, script));
         }
         if option_name == Self::NAME_LAST_NAME_FURIGANA {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::LastNameFurigana);
+            return Ok(FakeOption::LastNameFurigana(Self::parse_kana_script(
+                &Self::split(sub_option_str),
+            )?));
         }
         if option_name == Self::NAME_FULL_NAME {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::FullName(false));
+            let (furigana, script) = Self::parse_furigana(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::FullName(furigana, script));
         }
         if option_name == Self::NAME_FULL_NAME_FURIGANA {
+            return Ok(FakeOption::FullNameFurigana(Self::parse_kana_script(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::NAME_FIRST_NAME_ROMAJI {
+            return Ok(FakeOption::FirstNameRomaji(Self::parse_capitalization(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::NAME_LAST_NAME_ROMAJI {
+            return Ok(FakeOption::LastNameRomaji(Self::parse_capitalization(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::NAME_FULL_NAME_ROMAJI {
+            return Ok(FakeOption::FullNameRomaji(Self::parse_capitalization(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::NAME_GENDER {
             Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::FullNameFurigana);
+            return Ok(FakeOption::Gender);
         }
         return Err(ScannerError::UnknownOption(
             option_name.to_string(),
@@ -850,24 +1780,83 @@ impl Scanner {
             return Ok(FakeOption::UserName);
         }
         if option_name == Self::INTERNET_PASSWORD {
-            let ints = Self::parse_int_range::<usize>(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::Password(ints.0, ints.1));
+            return Self::parse_password(&Self::split(sub_option_str));
+        }
+        if option_name == Self::INTERNET_PASSWORD_HASH {
+            return Ok(FakeOption::PasswordHash(Self::parse_hash_format(
+                &Self::split(sub_option_str),
+            )?));
         }
         if option_name == Self::INTERNET_CREDIT_CARD {
             Self::parse_none(&Self::split(sub_option_str))?;
             return Ok(FakeOption::CreditCard);
         }
-        if option_name == Self::INTERNET_URL {
+        if option_name == Self::INTERNET_CREDIT_CARD_NUMBER {
+            return Ok(FakeOption::CreditCardNumber(Self::parse_card_brand(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::INTERNET_CREDIT_CARD_EXPIRY {
             Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::URL);
+            return Ok(FakeOption::CreditCardExpiry);
         }
-        if option_name == Self::INTERNET_IPV4 {
+        if option_name == Self::INTERNET_CREDIT_CARD_CVV {
+            return Ok(FakeOption::CreditCardCvv(Self::parse_card_brand(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::INTERNET_CREDIT_CARD_HOLDER {
             Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::IPv4);
+            return Ok(FakeOption::CreditCardHolder);
+        }
+        if option_name == Self::INTERNET_URL {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::URL);
+            }
+            return Ok(FakeOption::URLWith(Self::parse_url(&Self::split(
+                sub_option_str,
+            ))?));
+        }
+        if option_name == Self::INTERNET_IPV4 {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::IPv4);
+            }
+            let (range, exclude) = Self::parse_ipv4_range(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::IPv4InRange(range, exclude));
         }
         if option_name == Self::INTERNET_IPV6 {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::IPv6);
+            }
+            return Ok(FakeOption::IPv6InRange(Self::parse_ip_range(
+                &Self::split(sub_option_str),
+                true,
+            )?));
+        }
+        if option_name == Self::INTERNET_MAC {
+            let (style, local) = Self::parse_mac(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::MAC(style, local));
+        }
+        if option_name == Self::INTERNET_HOSTNAME {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::Hostname(false));
+            }
+            return Ok(FakeOption::Hostname(Self::parse_bool(&Self::split(
+                sub_option_str,
+            ))?));
+        }
+        if option_name == Self::INTERNET_DOMAIN_NAME {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::DomainName);
+        }
+        if option_name == Self::INTERNET_PORT {
+            return Ok(FakeOption::Port(Self::parse_port_range(&Self::split(
+                sub_option_str,
+            ))?));
+        }
+        if option_name == Self::INTERNET_SLUG {
             Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::IPv6);
+            return Ok(FakeOption::Slug);
         }
         if option_name == Self::INTERNET_RGB {
             Self::parse_none(&Self::split(sub_option_str))?;
@@ -877,13 +1866,42 @@ impl Scanner {
             Self::parse_none(&Self::split(sub_option_str))?;
             return Ok(FakeOption::RGBA);
         }
+        if option_name == Self::INTERNET_COLOR {
+            let (format, tone) = Self::parse_color(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::Color(format, tone));
+        }
+        if option_name == Self::INTERNET_COLOR_NAME {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::ColorName(false));
+            }
+            return Ok(FakeOption::ColorName(Self::parse_bool(&Self::split(
+                sub_option_str,
+            ))?));
+        }
+        if option_name == Self::INTERNET_CSS_COLOR_NAME {
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::CssColorName(false));
+            }
+            return Ok(FakeOption::CssColorName(Self::parse_bool(&Self::split(
+                sub_option_str,
+            ))?));
+        }
         if option_name == Self::INTERNET_USER_AGENT {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::UserAgent);
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::UserAgent);
+            }
+            return Ok(FakeOption::UserAgentWith(Self::parse_user_agent(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::INTERNET_ACCESS_LOG {
+            return Ok(FakeOption::AccessLog(Self::parse_access_log_format(
+                &Self::split(sub_option_str),
+            )?));
         }
         if option_name == Self::INTERNET_STATUS_CODE {
             Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::UserName);
+            return Ok(FakeOption::StatusCode);
         }
         return Err(ScannerError::UnknownOption(
             option_name.to_string(),
@@ -908,6 +1926,39 @@ impl Scanner {
             Self::parse_none(&Self::split(sub_option_str))?;
             return Ok(FakeOption::Industry);
         }
+        if option_name == Self::COMPANY_CORPORATE_NUMBER {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::CorporateNumber);
+        }
+        if option_name == Self::COMPANY_INDIVIDUAL_NUMBER {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::IndividualNumber);
+        }
+        if option_name == Self::COMPANY_DEPARTMENT {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::Department);
+        }
+        if option_name == Self::COMPANY_JOB_TITLE {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::JobTitle);
+        }
+        if option_name == Self::COMPANY_EMPLOYEE_NUMBER {
+            let pattern: String = Self::parse_string(&Self::split(sub_option_str))?;
+            if pattern.is_empty() {
+                return Ok(FakeOption::EmployeeNumber(
+                    Self::DEFAULT_EMPLOYEE_NUMBER_PATTERN.to_string(),
+                ));
+            }
+            return Ok(FakeOption::EmployeeNumber(pattern));
+        }
+        if option_name == Self::COMPANY_DOMAIN {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::CompanyDomain);
+        }
+        if option_name == Self::COMPANY_EMPLOYEE_EMAIL {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::EmployeeEmail);
+        }
         return Err(ScannerError::UnknownOption(
             option_name.to_string(),
             Category::Company,
@@ -951,6 +2002,20 @@ impl Scanner {
             Self::parse_none(&Self::split(sub_option_str))?;
             return Ok(FakeOption::Address);
         }
+        if option_name == Self::ADDRESS_DETAILED_ADDRESS {
+            return Ok(FakeOption::DetailedAddress(Self::parse_address_style(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::ADDRESS_ADDRESS_LINE1 {
+            return Ok(FakeOption::AddressLine1(Self::parse_address_style(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::ADDRESS_ADDRESS_LINE2 {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::AddressLine2);
+        }
         if option_name == Self::ADDRESS_ZIP_CODE {
             if sub_option_str.is_none() {
                 return Ok(FakeOption::ZipCode(false));
@@ -967,20 +2032,143 @@ impl Scanner {
                 &Self::split(sub_option_str),
             )?));
         }
+        if option_name == Self::ADDRESS_PHONE_NUMBER {
+            let (kind, hyphen) = Self::parse_phone(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::PhoneNumber(kind, hyphen));
+        }
+        if option_name == Self::ADDRESS_INTERNATIONAL_PHONE_NUMBER {
+            return Ok(FakeOption::InternationalPhoneNumber(
+                Self::parse_phone_kind(&Self::split(sub_option_str))?,
+            ));
+        }
         if option_name == Self::ADDRESS_LATITUDE {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::Latitude);
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::Latitude);
+            }
+            return Ok(FakeOption::LatitudeIn(Self::parse_geo_area(&Self::split(
+                sub_option_str,
+            ))?));
         }
         if option_name == Self::ADDRESS_LONGITUDE {
-            Self::parse_none(&Self::split(sub_option_str))?;
-            return Ok(FakeOption::Longitude);
-        }
+            if sub_option_str.is_none() {
+                return Ok(FakeOption::Longitude);
+            }
+            return Ok(FakeOption::LongitudeIn(Self::parse_geo_area(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::ADDRESS_COORDINATES {
+            return Ok(FakeOption::Coordinates(Self::parse_geo_area(
+                &Self::split(sub_option_str),
+            )?));
+        }
         return Err(ScannerError::UnknownOption(
             option_name.to_string(),
             Category::Address,
         ));
     }
 
+    fn parse_finance(
+        &self,
+        option_name: &str,
+        sub_option_str: Option<&str>,
+    ) -> Result<FakeOption, ScannerError> {
+        if option_name == Self::FINANCE_BANK_NAME {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::BankName);
+        }
+        if option_name == Self::FINANCE_BANK_CODE {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::BankCode);
+        }
+        if option_name == Self::FINANCE_BRANCH_NAME {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::BranchName);
+        }
+        if option_name == Self::FINANCE_BRANCH_CODE {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::BranchCode);
+        }
+        if option_name == Self::FINANCE_ACCOUNT_TYPE {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::AccountType);
+        }
+        if option_name == Self::FINANCE_ACCOUNT_NUMBER {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::AccountNumber);
+        }
+        if option_name == Self::FINANCE_ACCOUNT_HOLDER {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::AccountHolder);
+        }
+        Err(ScannerError::UnknownOption(
+            option_name.to_string(),
+            Category::Finance,
+        ))
+    }
+
+    fn parse_commerce(
+        &self,
+        option_name: &str,
+        sub_option_str: Option<&str>,
+    ) -> Result<FakeOption, ScannerError> {
+        if option_name == Self::COMMERCE_JAN {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::JAN);
+        }
+        if option_name == Self::COMMERCE_EAN8 {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::EAN8);
+        }
+        if option_name == Self::COMMERCE_UPC {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::UPC);
+        }
+        if option_name == Self::COMMERCE_ISBN10 {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::ISBN10);
+        }
+        if option_name == Self::COMMERCE_ISBN13 {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::ISBN13);
+        }
+        if option_name == Self::COMMERCE_PRODUCT_NAME {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::ProductName);
+        }
+        if option_name == Self::COMMERCE_PRODUCT_CATEGORY {
+            Self::parse_none(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::ProductCategory);
+        }
+        if option_name == Self::COMMERCE_SKU {
+            let pattern: String = Self::parse_string(&Self::split(sub_option_str))?;
+            if pattern.is_empty() {
+                return Ok(FakeOption::Sku(Self::DEFAULT_SKU_PATTERN.to_string()));
+            }
+            return Ok(FakeOption::Sku(pattern));
+        }
+        if option_name == Self::COMMERCE_PRICE {
+            return Self::parse_price(&Self::split(sub_option_str));
+        }
+        if option_name == Self::COMMERCE_CURRENCY_CODE {
+            return Ok(FakeOption::CurrencyCode(Self::parse_currency(
+                &Self::split(sub_option_str),
+            )?));
+        }
+        if option_name == Self::COMMERCE_QUANTITY {
+            if sub_option_str.is_none() {
+                let (from, to) = Self::DEFAULT_QUANTITY;
+                return Ok(FakeOption::Quantity(from, to));
+            }
+            let (from, to) = Self::parse_int_range::<usize>(&Self::split(sub_option_str))?;
+            return Ok(FakeOption::Quantity(from, to));
+        }
+        Err(ScannerError::UnknownOption(
+            option_name.to_string(),
+            Category::Commerce,
+        ))
+    }
+
     fn parse_datetime(
         &self,
         option_name: &str,
@@ -1055,6 +2243,8 @@ impl Scanner {
             Category::Internet => self.parse_internet(option_name, sub_option_str)?,
             Category::Company => self.parse_company(option_name, sub_option_str)?,
             Category::Address => self.parse_address(option_name, sub_option_str)?,
+            Category::Finance => self.parse_finance(option_name, sub_option_str)?,
+            Category::Commerce => self.parse_commerce(option_name, sub_option_str)?,
             Category::DateTime => self.parse_datetime(option_name, sub_option_str)?,
             Category::FileSystem => self.parse_filesystem(option_name, sub_option_str)?,
         };
@@ -1078,6 +2268,466 @@ impl Scanner {
 
         return Ok((column_name.to_string(), option));
     }
+
+    // ---
+    // printer
+    // ---
+    /// inverse of scan: format option as the string which scan can parse.
+    pub fn option_string(column_name: &str, option: &FakeOption) -> String {
+        let (option_name, subs): (&str, Vec<String>) = Self::option_parts(option);
+        let mut args: Vec<String> = vec![column_name.to_string()];
+        args.extend(subs);
+        format!("{}.{}({})", option.category(), option_name, args.join("#"))
+    }
+
+    fn option_string_without_column_name(option: &FakeOption) -> String {
+        let (option_name, subs): (&str, Vec<String>) = Self::option_parts(option);
+        format!("{}.{}({})", option.category(), option_name, subs.join("#"))
+    }
+
+    fn option_parts(option: &FakeOption) -> (&'static str, Vec<String>) {
+        use FakeOption::*;
+        match option {
+            Join(separator, data) => {
+                let mut subs: Vec<String> = vec![separator.to_string()];
+                let items: Vec<String> = data
+                    .iter()
+                    .map(|d| Self::option_string_without_column_name(d))
+                    .collect();
+                let mut index: usize = 0;
+                while index < items.len() {
+                    let count: usize = items[index..]
+                        .iter()
+                        .take_while(|item| *item == &items[index])
+                        .count();
+                    if count > 1 {
+                        subs.push(count.to_string());
+                    }
+                    subs.push(items[index].to_string());
+                    index += count;
+                }
+                (Self::JOIN, subs)
+            }
+            WithLocale(locale, option) => (
+                Self::WITH_LOCALE,
+                vec![
+                    locale.code().to_string(),
+                    Self::option_string_without_column_name(option),
+                ],
+            ),
+            FixedString(s) => (Self::FIXED_STRING, vec![s.to_string()]),
+            FixedNotString(s) => (Self::FIXED_NOT_STRING, vec![s.to_string()]),
+            SelectString(list) => (Self::SELECT_STRING, list.to_vec()),
+            SelectNotString(list) => (Self::SELECT_NOT_STRING, list.to_vec()),
+            SelectFromFile(path, _) => (Self::SELECT_FROM_FILE, vec![path.to_string()]),
+            SelectFromColumn(path, column, _) => (
+                Self::SELECT_FROM_COLUMN,
+                vec![path.to_string(), column.to_string()],
+            ),
+            Word => (Self::LOREM_WORD, vec![]),
+            Words(from, to) => (Self::LOREM_WORD, vec![from.to_string(), to.to_string()]),
+            Sentence => (Self::LOREM_SENTENCE, vec![]),
+            Sentences(from, to) => (Self::LOREM_SENTENCE, vec![from.to_string(), to.to_string()]),
+            Paragraph => (Self::LOREM_PARAGRAPH, vec![]),
+            Paragraphs(from, to) => (
+                Self::LOREM_PARAGRAPH,
+                vec![from.to_string(), to.to_string()],
+            ),
+            FirstName(furigana, script) => (
+                Self::NAME_FIRST_NAME,
+                Self::furigana_parts(*furigana, *script),
+            ),
+            FirstNameFurigana(script) => (
+                Self::NAME_FIRST_NAME_FURIGANA,
+                Self::kana_script_parts(*script),
+            ),
+            LastName(furigana, script) => (
+                Self::NAME_LAST_NAME,
+                Self::furigana_parts(*furigana, *script),
+            ),
+            LastNameFurigana(script) => (
+                Self::NAME_LAST_NAME_FURIGANA,
+                Self::kana_script_parts(*script),
+            ),
+            FullName(furigana, script) => (
+                Self::NAME_FULL_NAME,
+                Self::furigana_parts(*furigana, *script),
+            ),
+            FullNameFurigana(script) => (
+                Self::NAME_FULL_NAME_FURIGANA,
+                Self::kana_script_parts(*script),
+            ),
+            FirstNameRomaji(capitalization) => (
+                Self::NAME_FIRST_NAME_ROMAJI,
+                Self::capitalization_parts(*capitalization),
+            ),
+            LastNameRomaji(capitalization) => (
+                Self::NAME_LAST_NAME_ROMAJI,
+                Self::capitalization_parts(*capitalization),
+            ),
+            FullNameRomaji(capitalization) => (
+                Self::NAME_FULL_NAME_ROMAJI,
+                Self::capitalization_parts(*capitalization),
+            ),
+            Gender => (Self::NAME_GENDER, vec![]),
+            Integer => (Self::PRIMITIVE_INTEGER, vec![]),
+            IntegerRange(from, to) => (
+                Self::PRIMITIVE_INTEGER,
+                vec![from.to_string(), to.to_string()],
+            ),
+            Float => (Self::PRIMITIVE_FLOAT, vec![]),
+            FloatRange(from, to) => (
+                Self::PRIMITIVE_FLOAT,
+                vec![from.to_string(), to.to_string()],
+            ),
+            Ascii(from, to) => (
+                Self::PRIMITIVE_ASCII,
+                vec![from.to_string(), to.to_string()],
+            ),
+            Boolean => (Self::PRIMITIVE_BOOL, vec![]),
+            Email => (Self::INTERNET_EMAIL, vec![]),
+            UserName => (Self::INTERNET_USER_NAME, vec![]),
+            Password(from, to) => (
+                Self::INTERNET_PASSWORD,
+                vec![from.to_string(), to.to_string()],
+            ),
+            PasswordWithPolicy(from, to, policy) => {
+                let mut parts: Vec<String> =
+                    vec![from.to_string(), to.to_string(), policy.letters()];
+                if !policy.excluded.is_empty() {
+                    parts.push(policy.excluded.to_string());
+                }
+                (Self::INTERNET_PASSWORD, parts)
+            }
+            PasswordHash(format) => (
+                Self::INTERNET_PASSWORD_HASH,
+                Self::hash_format_parts(*format),
+            ),
+            CreditCard => (Self::INTERNET_CREDIT_CARD, vec![]),
+            CreditCardNumber(brand) => (
+                Self::INTERNET_CREDIT_CARD_NUMBER,
+                Self::card_brand_parts(*brand),
+            ),
+            CreditCardExpiry => (Self::INTERNET_CREDIT_CARD_EXPIRY, vec![]),
+            CreditCardCvv(brand) => (
+                Self::INTERNET_CREDIT_CARD_CVV,
+                Self::card_brand_parts(*brand),
+            ),
+            CreditCardHolder => (Self::INTERNET_CREDIT_CARD_HOLDER, vec![]),
+            URL => (Self::INTERNET_URL, vec![]),
+            URLWith(format) => (Self::INTERNET_URL, Self::url_parts(*format)),
+            IPv4 => (Self::INTERNET_IPV4, vec![]),
+            IPv6 => (Self::INTERNET_IPV6, vec![]),
+            IPv4InRange(range, exclude) => {
+                let mut parts: Vec<String> = vec![range.to_string()];
+                parts.extend(Self::bool_parts(*exclude));
+                (Self::INTERNET_IPV4, parts)
+            }
+            IPv6InRange(range) => (Self::INTERNET_IPV6, vec![range.to_string()]),
+            MAC(style, local) => (Self::INTERNET_MAC, Self::mac_parts(*style, *local)),
+            Hostname(with_domain) => (Self::INTERNET_HOSTNAME, Self::bool_parts(*with_domain)),
+            DomainName => (Self::INTERNET_DOMAIN_NAME, vec![]),
+            Port(range) => (Self::INTERNET_PORT, Self::port_range_parts(*range)),
+            Slug => (Self::INTERNET_SLUG, vec![]),
+            RGB => (Self::INTERNET_RGB, vec![]),
+            RGBA => (Self::INTERNET_RGBA, vec![]),
+            Color(format, tone) => (Self::INTERNET_COLOR, Self::color_parts(*format, *tone)),
+            ColorName(with_hex) => (Self::INTERNET_COLOR_NAME, Self::bool_parts(*with_hex)),
+            CssColorName(with_hex) => (Self::INTERNET_CSS_COLOR_NAME, Self::bool_parts(*with_hex)),
+            UserAgent => (Self::INTERNET_USER_AGENT, vec![]),
+            UserAgentWith(format) => (Self::INTERNET_USER_AGENT, Self::user_agent_parts(*format)),
+            AccessLog(format) => (
+                Self::INTERNET_ACCESS_LOG,
+                Self::access_log_format_parts(format),
+            ),
+            StatusCode => (Self::INTERNET_STATUS_CODE, vec![]),
+            CompanySuffix => (Self::COMPANY_SUFFIX, vec![]),
+            CompanyName => (Self::COMPANY_NAME, vec![]),
+            Industry => (Self::COMPANY_INDUSTRY, vec![]),
+            CorporateNumber => (Self::COMPANY_CORPORATE_NUMBER, vec![]),
+            IndividualNumber => (Self::COMPANY_INDIVIDUAL_NUMBER, vec![]),
+            Department => (Self::COMPANY_DEPARTMENT, vec![]),
+            JobTitle => (Self::COMPANY_JOB_TITLE, vec![]),
+            EmployeeNumber(pattern) => (
+                Self::COMPANY_EMPLOYEE_NUMBER,
+                Self::employee_number_parts(pattern),
+            ),
+            CompanyDomain => (Self::COMPANY_DOMAIN, vec![]),
+            EmployeeEmail => (Self::COMPANY_EMPLOYEE_EMAIL, vec![]),
+            Building => (Self::ADDRESS_BUILDING, vec![]),
+            StreetName => (Self::ADDRESS_STREET_NAME, vec![]),
+            CityName => (Self::ADDRESS_CITY_NAME, vec![]),
+            StateName => (Self::ADDRESS_STATE_NAME, vec![]),
+            CountryCode => (Self::ADDRESS_COUNTRY_CODE, vec![]),
+            CountryName => (Self::ADDRESS_COUNTRY_NAME, vec![]),
+            TimeZone => (Self::ADDRESS_TIMEZONE, vec![]),
+            Address => (Self::ADDRESS_ADDRESS, vec![]),
+            DetailedAddress(style) => (
+                Self::ADDRESS_DETAILED_ADDRESS,
+                Self::address_style_parts(*style),
+            ),
+            AddressLine1(style) => (
+                Self::ADDRESS_ADDRESS_LINE1,
+                Self::address_style_parts(*style),
+            ),
+            AddressLine2 => (Self::ADDRESS_ADDRESS_LINE2, vec![]),
+            ZipCode(hyphen) => (Self::ADDRESS_ZIP_CODE, vec![hyphen.to_string()]),
+            DomesticPhoneNumber(hyphen) => (
+                Self::ADDRESS_DOMESTIC_PHONE_NUMBER,
+                vec![hyphen.to_string()],
+            ),
+            PhoneNumber(kind, hyphen) => (
+                Self::ADDRESS_PHONE_NUMBER,
+                Self::phone_parts(*kind, *hyphen),
+            ),
+            InternationalPhoneNumber(kind) => (
+                Self::ADDRESS_INTERNATIONAL_PHONE_NUMBER,
+                Self::phone_kind_parts(*kind),
+            ),
+            Latitude => (Self::ADDRESS_LATITUDE, vec![]),
+            Longitude => (Self::ADDRESS_LONGITUDE, vec![]),
+            LatitudeIn(area) => (Self::ADDRESS_LATITUDE, Self::geo_area_parts(*area)),
+            LongitudeIn(area) => (Self::ADDRESS_LONGITUDE, Self::geo_area_parts(*area)),
+            Coordinates(area) => (Self::ADDRESS_COORDINATES, Self::geo_area_parts(*area)),
+            BankName => (Self::FINANCE_BANK_NAME, vec![]),
+            BankCode => (Self::FINANCE_BANK_CODE, vec![]),
+            BranchName => (Self::FINANCE_BRANCH_NAME, vec![]),
+            BranchCode => (Self::FINANCE_BRANCH_CODE, vec![]),
+            AccountType => (Self::FINANCE_ACCOUNT_TYPE, vec![]),
+            AccountNumber => (Self::FINANCE_ACCOUNT_NUMBER, vec![]),
+            AccountHolder => (Self::FINANCE_ACCOUNT_HOLDER, vec![]),
+            JAN => (Self::COMMERCE_JAN, vec![]),
+            EAN8 => (Self::COMMERCE_EAN8, vec![]),
+            UPC => (Self::COMMERCE_UPC, vec![]),
+            ISBN10 => (Self::COMMERCE_ISBN10, vec![]),
+            ISBN13 => (Self::COMMERCE_ISBN13, vec![]),
+            ProductName => (Self::COMMERCE_PRODUCT_NAME, vec![]),
+            ProductCategory => (Self::COMMERCE_PRODUCT_CATEGORY, vec![]),
+            Sku(pattern) => (Self::COMMERCE_SKU, Self::sku_parts(pattern)),
+            Price(currency, range) => (Self::COMMERCE_PRICE, Self::price_parts(*currency, *range)),
+            CurrencyCode(currency) => (
+                Self::COMMERCE_CURRENCY_CODE,
+                Self::currency_parts(*currency),
+            ),
+            Quantity(from, to) => (Self::COMMERCE_QUANTITY, Self::quantity_parts(*from, *to)),
+            Time(format) => (Self::DATE_TIME_TIME, vec![format.to_string()]),
+            Date(format) => (Self::DATE_TIME_DATE, vec![format.to_string()]),
+            DateTime(format) => (Self::DATE_TIME_DATE_TIME, vec![format.to_string()]),
+            FileName => (Self::FILE_SYSTEM_FILE_NAME, vec![]),
+            Extension => (Self::FILE_SYSTEM_EXTENSION, vec![]),
+        }
+    }
+
+    // omit default value false
+    fn bool_parts(b: bool) -> Vec<String> {
+        if b {
+            vec![b.to_string()]
+        } else {
+            vec![]
+        }
+    }
+
+    // omit default value
+    fn kana_script_parts(script: KanaScript) -> Vec<String> {
+        if script == KanaScript::default() {
+            vec![]
+        } else {
+            vec![script.to_string()]
+        }
+    }
+
+    // omit default value of script and furigana
+    fn furigana_parts(furigana: bool, script: KanaScript) -> Vec<String> {
+        if script == KanaScript::default() {
+            Self::bool_parts(furigana)
+        } else {
+            vec![furigana.to_string(), script.to_string()]
+        }
+    }
+
+    // omit default value
+    fn capitalization_parts(capitalization: Capitalization) -> Vec<String> {
+        if capitalization == Capitalization::default() {
+            vec![]
+        } else {
+            vec![capitalization.to_string()]
+        }
+    }
+
+    // omit default value
+    fn address_style_parts(style: AddressStyle) -> Vec<String> {
+        if style == AddressStyle::default() {
+            vec![]
+        } else {
+            vec![style.to_string()]
+        }
+    }
+
+    // omit default value
+    fn card_brand_parts(brand: CardBrand) -> Vec<String> {
+        if brand == CardBrand::default() {
+            vec![]
+        } else {
+            vec![brand.to_string()]
+        }
+    }
+
+    // omit default value of style and local
+    fn mac_parts(style: MacStyle, local: bool) -> Vec<String> {
+        if local {
+            vec![style.to_string(), local.to_string()]
+        } else if style == MacStyle::default() {
+            vec![]
+        } else {
+            vec![style.to_string()]
+        }
+    }
+
+    // omit default value
+    fn port_range_parts(range: PortRange) -> Vec<String> {
+        if range == PortRange::default() {
+            vec![]
+        } else {
+            vec![range.to_string()]
+        }
+    }
+
+    // omit default value
+    fn employee_number_parts(pattern: &str) -> Vec<String> {
+        if pattern == Self::DEFAULT_EMPLOYEE_NUMBER_PATTERN {
+            vec![]
+        } else {
+            vec![pattern.to_string()]
+        }
+    }
+
+    // omit globe which is default
+    fn geo_area_parts(area: GeoArea) -> Vec<String> {
+        if area == GeoArea::default() {
+            return vec![];
+        }
+        let mut parts: Vec<String> = vec![area.name().to_string()];
+        parts.extend(area.values().iter().map(|v| v.to_string()));
+        parts
+    }
+
+    fn sku_parts(pattern: &str) -> Vec<String> {
+        if pattern == Self::DEFAULT_SKU_PATTERN {
+            vec![]
+        } else {
+            vec![pattern.to_string()]
+        }
+    }
+
+    fn currency_parts(currency: Currency) -> Vec<String> {
+        if currency == Currency::default() {
+            vec![]
+        } else {
+            vec![currency.to_string()]
+        }
+    }
+
+    // omit default currency when range is None
+    fn price_parts(currency: Currency, range: Option<(usize, usize)>) -> Vec<String> {
+        match range {
+            Some((from, to)) => vec![currency.to_string(), from.to_string(), to.to_string()],
+            None => Self::currency_parts(currency),
+        }
+    }
+
+    fn quantity_parts(from: usize, to: usize) -> Vec<String> {
+        if (from, to) == Self::DEFAULT_QUANTITY {
+            vec![]
+        } else {
+            vec![from.to_string(), to.to_string()]
+        }
+    }
+
+    fn access_log_format_parts(format: &AccessLogFormat) -> Vec<String> {
+        if *format == AccessLogFormat::default() {
+            vec![]
+        } else {
+            vec![format.to_string()]
+        }
+    }
+
+    // omit default values at the tail except browser
+    fn user_agent_parts(format: UserAgentFormat) -> Vec<String> {
+        let mut parts: Vec<String> = vec![
+            format.browser.to_string(),
+            format.device.to_string(),
+            format.platform.to_string(),
+        ];
+        if let Some((from, to)) = format.version {
+            parts.extend(vec![from.to_string(), to.to_string()]);
+        } else if format.platform == Platform::default() {
+            parts.pop();
+            if format.device == DeviceType::default() {
+                parts.pop();
+            }
+        }
+        parts
+    }
+
+    // omit default value of format and tone
+    fn color_parts(format: ColorFormat, tone: ColorTone) -> Vec<String> {
+        if tone != ColorTone::default() {
+            vec![format.to_string(), tone.to_string()]
+        } else if format == ColorFormat::default() {
+            vec![]
+        } else {
+            vec![format.to_string()]
+        }
+    }
+
+    // omit default value
+    fn hash_format_parts(format: HashFormat) -> Vec<String> {
+        if format == HashFormat::default() {
+            vec![]
+        } else {
+            vec![format.to_string()]
+        }
+    }
+
+    // omit default values at the tail except scheme
+    fn url_parts(format: UrlFormat) -> Vec<String> {
+        let to_parts = |format: UrlFormat| -> Vec<String> {
+            vec![
+                format.scheme.to_string(),
+                format.depth.to_string(),
+                format.query.to_string(),
+                format.fragment.to_string(),
+                format.port.to_string(),
+                format.encoded.to_string(),
+            ]
+        };
+        let parts: Vec<String> = to_parts(format);
+        let defaults: Vec<String> = to_parts(UrlFormat::default());
+        let length: usize = parts
+            .iter()
+            .zip(defaults.iter())
+            .rposition(|(part, default)| part != default)
+            .map_or(1, |i| i + 1);
+        parts[..length].to_vec()
+    }
+
+    // omit default value of kind and hyphen
+    fn phone_parts(kind: PhoneKind, hyphen: bool) -> Vec<String> {
+        if hyphen {
+            vec![kind.to_string(), hyphen.to_string()]
+        } else {
+            Self::phone_kind_parts(kind)
+        }
+    }
+
+    // omit default value
+    fn phone_kind_parts(kind: PhoneKind) -> Vec<String> {
+        if kind == PhoneKind::default() {
+            vec![]
+        } else {
+            vec![kind.to_string()]
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Fail)]
@@ -1086,13 +2736,39 @@ pub enum ScannerError {
     UnknownOptionFormat(String),
     UnknownOption(String, Category),
     UnknownCharacters(Vec<String>),
+    // option name, count of sub options needed and the given sub options
+    WrongSubOptionCount(String, usize, Vec<String>),
     UnknownStringFormat(Vec<String>),
     UnknownBooleanFormat(Vec<String>),
-    #[allow(dead_code)]
+    UnknownCapitalizationFormat(Vec<String>),
+    UnknownKanaScriptFormat(Vec<String>),
+    UnknownAddressStyleFormat(Vec<String>),
+    UnknownPhoneKindFormat(Vec<String>),
+    UnknownCardBrandFormat(Vec<String>),
+    UnknownMacStyleFormat(Vec<String>),
+    UnknownPortRangeFormat(Vec<String>),
+    UnknownIpRangeFormat(Vec<String>),
+    UnknownUrlSchemeFormat(Vec<String>),
+    UnknownPasswordClassesFormat(Vec<String>),
+    UnknownHashFormatFormat(Vec<String>),
+    UnknownColorFormatFormat(Vec<String>),
+    UnknownColorToneFormat(Vec<String>),
+    UnknownBrowserFormat(Vec<String>),
+    UnknownDeviceTypeFormat(Vec<String>),
+    UnknownPlatformFormat(Vec<String>),
+    UnusableUserAgentFormat(Vec<String>),
+    UnknownAccessLogFormatFormat(Vec<String>),
+    UnknownCurrencyFormat(Vec<String>),
+    UnusablePriceRange(Vec<String>),
+    UnusablePasswordPolicy(Vec<String>),
+    UnusableUrlFormat(Vec<String>),
+    UnknownGeoAreaFormat(Vec<String>),
     UnknownStringListFormat(Vec<String>),
     UnknownIntegerListFormat(Vec<String>),
     RangeErr(String, String),
     UnknownJoinItemFormat(String),
+    UnreadableFile(String, String),
+    UnknownLocale(String),
 }
 
 impl ScannerError {
@@ -1149,9 +2825,10 @@ impl Display for ScannerError {
                 writeln!(f, "Unknown Option is \"{}\"", s)?;
                 writeln!(
                     f,
-                    "Usable Option's format is ({}) | ({})",
+                    "Usable Option's format is ({}) | ({}) | ({})",
                     Scanner::NORMAL_OPTION_FORMAT,
-                    Scanner::WITH_JOIN_OPTION_FORMAT
+                    Scanner::WITH_JOIN_OPTION_FORMAT,
+                    Scanner::WITH_LOCALE_OPTION_FORMAT
                 )?;
                 write!(
                     f,
@@ -1165,6 +2842,17 @@ impl Display for ScannerError {
                 write!(f, "Unknown characters \"{}\"", s_list.join("#"))?;
                 Ok(())
             }
+            WrongSubOptionCount(name, count, s_list) => {
+                write!(
+                    f,
+                    "{} needs {} sub options, but {} sub options {} are given",
+                    name,
+                    count,
+                    s_list.len(),
+                    vec_to_str(s_list)
+                )?;
+                Ok(())
+            }
             UnknownStringFormat(s_list) => {
                 Self::write_unknown_format_of_vec(f, s_list)?;
                 Self::write_messages(f, "Usable String format", &[Scanner::STRING])?;
@@ -1175,6 +2863,155 @@ impl Display for ScannerError {
                 Self::write_messages(f, "Usable Boolean format", &[Scanner::BOOL])?;
                 Ok(())
             }
+            UnknownCapitalizationFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(
+                    f,
+                    "Usable Capitalization format",
+                    &[Scanner::CAPITALIZATION],
+                )?;
+                Ok(())
+            }
+            UnknownKanaScriptFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Kana script format", &[Scanner::KANA_SCRIPT])?;
+                Ok(())
+            }
+            UnknownAddressStyleFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Address style format", &[Scanner::ADDRESS_STYLE])?;
+                Ok(())
+            }
+            UnknownPhoneKindFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Phone kind format", &[Scanner::PHONE_KIND])?;
+                Ok(())
+            }
+            UnknownCardBrandFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Card brand format", &[Scanner::CARD_BRAND])?;
+                Ok(())
+            }
+            UnknownMacStyleFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable MAC style format", &[Scanner::MAC_STYLE])?;
+                Ok(())
+            }
+            UnknownPortRangeFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Port range format", &[Scanner::PORT_RANGE])?;
+                Ok(())
+            }
+            UnknownIpRangeFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable IP range format", &[Scanner::IP_RANGE])?;
+                Ok(())
+            }
+            UnknownUrlSchemeFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable URL scheme format", &[Scanner::URL_SCHEME])?;
+                Ok(())
+            }
+            UnknownPasswordClassesFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(
+                    f,
+                    "Usable Password classes format",
+                    &[Scanner::PASSWORD_CLASSES],
+                )?;
+                Ok(())
+            }
+            UnknownHashFormatFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Hash format", &[Scanner::HASH_FORMAT])?;
+                Ok(())
+            }
+            UnknownColorFormatFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Color format", &[Scanner::COLOR_FORMAT])?;
+                Ok(())
+            }
+            UnknownColorToneFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Color tone format", &[Scanner::COLOR_TONE])?;
+                Ok(())
+            }
+            UnknownBrowserFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Browser format", &[Scanner::BROWSER])?;
+                Ok(())
+            }
+            UnknownDeviceTypeFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Device type format", &[Scanner::DEVICE_TYPE])?;
+                Ok(())
+            }
+            UnknownPlatformFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Platform format", &[Scanner::PLATFORM])?;
+                Ok(())
+            }
+            UnknownAccessLogFormatFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(
+                    f,
+                    "Usable Access log format",
+                    &[Scanner::ACCESS_LOG_FORMAT, Scanner::STRING],
+                )?;
+                Ok(())
+            }
+            UnknownCurrencyFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                Self::write_messages(f, "Usable Currency format", &[Scanner::CURRENCY])?;
+                Ok(())
+            }
+            UnusablePriceRange(s_list) => {
+                write!(
+                    f,
+                    "Unusable price range {}. price in the smallest unit such as cent must be at most {}.",
+                    vec_to_str(s_list),
+                    u64::MAX
+                )?;
+                Ok(())
+            }
+            UnusablePasswordPolicy(s_list) => {
+                write!(
+                    f,
+                    "Unusable password policy {}. each class needs a character which is not excluded, and maximum length must be at least the count of classes.",
+                    vec_to_str(s_list)
+                )?;
+                Ok(())
+            }
+            UnusableUrlFormat(s_list) => {
+                write!(
+                    f,
+                    "Unusable URL format {}. depth and count of query parameters must be at most {}.",
+                    vec_to_str(s_list),
+                    UrlFormat::MAX_COUNT
+                )?;
+                Ok(())
+            }
+            UnknownGeoAreaFormat(s_list) => {
+                Self::write_unknown_format_of_vec(f, s_list)?;
+                writeln!(
+                    f,
+                    "latitude is in -90..=90, longitude is in -180..=180, south is not north of north and radius in km is in 0.001..=20015.087"
+                )?;
+                Self::write_messages(
+                    f,
+                    "Usable Geo area format",
+                    &[Scanner::GEO_AREA, Scanner::FLOAT],
+                )?;
+                Ok(())
+            }
+            UnusableUserAgentFormat(s_list) => {
+                write!(
+                    f,
+                    "Unusable combination of user agent {}. Safari runs on mac_os and ios, Edge does not run on linux, and bot has no browser and platform.",
+                    vec_to_str(s_list)
+                )?;
+                Ok(())
+            }
             UnknownStringListFormat(s_list) => {
                 Self::write_unknown_format_of_vec(f, s_list)?;
                 Self::write_messages(
@@ -1214,6 +3051,115 @@ impl Display for ScannerError {
                 )?;
                 Ok(())
             }
+            UnreadableFile(path, message) => {
+                write!(f, "Unreadable file \"{}\": {}", path, message)?;
+                Ok(())
+            }
+            UnknownLocale(s) => {
+                writeln!(f, "Unknown locale \"{}\"", s)?;
+                Self::write_messages(f, "Usable locale format", &[Scanner::LOCALE])?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // options except Select.FromFile and Select.FromColumn which read a file
+    const OPTIONS: &[&str] = &[
+        "With.Join(_#-#2#Lorem.Word()#Primitive.Int(1#9))",
+        "With.Locale(_#usa#Name.FullName(true))",
+        "Fixed.String(_#hoge)",
+        "Fixed.NotString(_#1)",
+        "Select.String(_#a#b#c)",
+        "Select.NotString(_#1#2)",
+        "Lorem.Word(_)",
+        "Lorem.Word(_#2#4)",
+        "Lorem.Sentence(_#1#2)",
+        "Lorem.Paragraph(_)",
+        "Name.FirstName(_)",
+        "Name.FirstName(_#true)",
+        "Name.LastName(_#true#hiragana)",
+        "Name.FullName(_#false)",
+        "Name.FirstNameFurigana(_#hiragana)",
+        "Name.FullNameFurigana(_)",
+        "Name.FullNameRomaji(_#upper)",
+        "Name.LastNameRomaji(_)",
+        "Name.Gender(_)",
+        "Primitive.Int(_)",
+        "Primitive.Int(_#-5#5)",
+        "Primitive.Float(_#-1#1)",
+        "Primitive.Ascii(_#1#8)",
+        "Primitive.Bool(_)",
+        "Internet.Email(_)",
+        "Internet.Password(_#8#12)",
+        "Internet.Password(_#8#12#ud)",
+        "Internet.Password(_#12#16#ulds#0O1lI)",
+        "Internet.PasswordHash(_)",
+        "Internet.PasswordHash(_#sha256_crypt)",
+        "Internet.CreditCardNumber(_#jcb)",
+        "Internet.CreditCardCvv(_#amex)",
+        "Internet.CreditCardExpiry(_)",
+        "Internet.URL(_)",
+        "Internet.URL(_#https#3#2#true#true#true)",
+        "Internet.IPv4(_#10.0.0.0/8#true)",
+        "Internet.IPv6(_#2001:db8::/32)",
+        "Internet.MAC(_#hyphen#true)",
+        "Internet.Hostname(_#true)",
+        "Internet.Port(_#ephemeral)",
+        "Internet.Color(_#css_hsl#pastel)",
+        "Internet.ColorName(_#true)",
+        "Internet.UserAgent(_#firefox#desktop#linux#100#120)",
+        "Internet.AccessLog(_#nginx)",
+        "Internet.StatusCode(_)",
+        "Company.Name(_)",
+        "Company.CorporateNumber(_)",
+        "Company.IndividualNumber(_)",
+        "Company.EmployeeNumber(_#EMP-999999)",
+        "Company.EmployeeEmail(_)",
+        "Address.State(_)",
+        "Address.DetailedAddress(_#kanji)",
+        "Address.ZipCode(_#false)",
+        "Address.PhoneNumber(_#mobile#true)",
+        "Address.InternationalPhoneNumber(_#toll_free)",
+        "Address.Latitude(_)",
+        "Address.Latitude(_#state)",
+        "Address.Longitude(_#box#30#129#46#146)",
+        "Address.Coordinates(_#circle#35.68#139.76#10)",
+        "Finance.BankName(_)",
+        "Finance.AccountHolder(_)",
+        "Commerce.JAN(_)",
+        "Commerce.ISBN10(_)",
+        "Commerce.Sku(_#AAA-9999)",
+        "Commerce.Price(_)",
+        "Commerce.Price(_#usd#10#100)",
+        "Commerce.CurrencyCode(_#any)",
+        "Commerce.Quantity(_#1#5)",
+        "DateTime.Date(_#%Y-%m-%d)",
+        "DateTime.DateTime(_#%Y/%m/%d %H:%M:%S)",
+        "FileSystem.FileName(_)",
+    ];
+
+    #[test]
+    fn option_string_is_scanned_as_the_same_option() {
+        for target in OPTIONS {
+            let (column_name, option) = Scanner::new(target)
+                .scan()
+                .unwrap_or_else(|e| panic!("{}: {}", target, e));
+            let printed: String = Scanner::option_string(&column_name, &option);
+            let rescanned = Scanner::new(&printed)
+                .scan()
+                .unwrap_or_else(|e| panic!("{} printed as {}: {}", target, printed, e));
+            assert_eq!(rescanned, (column_name, option), "{}", target);
+            assert_eq!(
+                Scanner::option_string(&rescanned.0, &rescanned.1),
+                printed,
+                "{}",
+                target
+            );
         }
     }
 }
@@ -0,0 +1,244 @@
+use crate::cli::scanner::Scanner;
+use fakes_gen::faker::fake_options::FakeOption;
+use std::io;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// write schema document for options as json such as
+/// {
+///   "options": [
+///     "Name.FullName(name)"
+///   ]
+/// }
+/// each item is formatted as <option>, so you can use it as [option] of fakes-gen.
+pub fn write_schema<W: io::Write>(
+    w: &mut W,
+    header_options: &[(String, FakeOption)],
+) -> io::Result<()> {
+    let one_indent: &str = "  ";
+    write!(w, "{{")?;
+    write!(w, "\n{}\"options\": [", one_indent)?;
+    let items: Vec<String> = header_options
+        .iter()
+        .map(|(h, opt)| {
+            format!(
+                "{}{}\"{}\"",
+                one_indent,
+                one_indent,
+                escape(&Scanner::option_string(h, opt))
+            )
+        })
+        .collect();
+    if let Some((head, tails)) = items.split_first() {
+        write!(w, "\n{}", head)?;
+        for tail in tails {
+            write!(w, ",\n{}", tail)?;
+        }
+        write!(w, "\n{}", one_indent)?;
+    }
+    write!(w, "]")?;
+    writeln!(w, "\n}}")?;
+    w.flush()
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped: String = String::new();
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// read <option>s of the schema document written by write_schema.
+/// the document is json object whose "options" is array of strings, and other keys are error.
+pub fn read_schema(text: &str) -> Result<Vec<String>, String> {
+    let mut chars: Peekable<Chars> = text.trim_start_matches('\u{feff}').chars().peekable();
+    let mut options: Option<Vec<String>> = None;
+    expect(&mut chars, '{')?;
+    if !next_is(&mut chars, '}') {
+        loop {
+            let key: String = read_string(&mut chars)?;
+            expect(&mut chars, ':')?;
+            match key.as_str() {
+                "options" if options.is_none() => options = Some(read_strings(&mut chars)?),
+                "options" => return Err("duplicated key \"options\"".to_string()),
+                _ => return Err(format!("unknown key \"{}\"", key)),
+            }
+            if !next_is(&mut chars, ',') {
+                expect(&mut chars, '}')?;
+                break;
+            }
+        }
+    }
+    skip_whitespace(&mut chars);
+    if let Some(c) = chars.next() {
+        return Err(format!("unexpected '{}' after the object", c));
+    }
+    options.ok_or_else(|| "no key \"options\"".to_string())
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+// consume the character if it is next
+fn next_is(chars: &mut Peekable<Chars>, expected: char) -> bool {
+    skip_whitespace(chars);
+    if chars.peek() == Some(&expected) {
+        chars.next();
+        true
+    } else {
+        false
+    }
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(format!("expected '{}' but found '{}'", expected, c)),
+        None => Err(format!("expected '{}' but found end of document", expected)),
+    }
+}
+
+// such as ["a", "b"]
+fn read_strings(chars: &mut Peekable<Chars>) -> Result<Vec<String>, String> {
+    let mut strings: Vec<String> = Vec::new();
+    expect(chars, '[')?;
+    if next_is(chars, ']') {
+        return Ok(strings);
+    }
+    loop {
+        strings.push(read_string(chars)?);
+        if !next_is(chars, ',') {
+            expect(chars, ']')?;
+            return Ok(strings);
+        }
+    }
+}
+
+fn read_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    expect(chars, '"')?;
+    let mut s: String = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => match chars.next() {
+                Some('"') => s.push('"'),
+                Some('\\') => s.push('\\'),
+                Some('/') => s.push('/'),
+                Some('b') => s.push('\u{8}'),
+                Some('f') => s.push('\u{c}'),
+                Some('n') => s.push('\n'),
+                Some('r') => s.push('\r'),
+                Some('t') => s.push('\t'),
+                Some('u') => s.push(read_unicode_escape(chars)?),
+                Some(c) => return Err(format!("unknown escape '\\{}'", c)),
+                None => return Err("unterminated string".to_string()),
+            },
+            Some(c) if (c as u32) < 0x20 => {
+                return Err(format!("control character {:?} in string", c))
+            }
+            Some(c) => s.push(c),
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+}
+
+// character of \uXXXX after "\u". surrogate pair is \uXXXX\uXXXX.
+fn read_unicode_escape(chars: &mut Peekable<Chars>) -> Result<char, String> {
+    let high: u32 = read_hex4(chars)?;
+    let code: u32 = if (0xd800..0xdc00).contains(&high) {
+        if chars.next() != Some('\\') || chars.next() != Some('u') {
+            return Err(format!("lone surrogate \\u{:04x}", high));
+        }
+        let low: u32 = read_hex4(chars)?;
+        if !(0xdc00..0xe000).contains(&low) {
+            return Err(format!(
+                "invalid surrogate pair \\u{:04x}\\u{:04x}",
+                high, low
+            ));
+        }
+        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+    } else {
+        high
+    };
+    std::char::from_u32(code).ok_or_else(|| format!("invalid character \\u{:04x}", code))
+}
+
+fn read_hex4(chars: &mut Peekable<Chars>) -> Result<u32, String> {
+    let hex: String = chars.take(4).collect();
+    if hex.chars().count() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("invalid \\u escape \"{}\"", hex));
+    }
+    u32::from_str_radix(&hex, 16).map_err(|_| format!("invalid \\u escape \"{}\"", hex))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // options whose strings have characters to be escaped in json
+    const OPTIONS: &[&str] = &[
+        "Name.FullName(name#true)",
+        "DateTime.Date(birthday#%m/%d)",
+        "Fixed.String(quote#\"a\\b\")",
+        "Select.String(kana#[あ#ア#\u{1f600}])",
+        "Fixed.String(control#a\tb)",
+        "With.Join(join#-#2#Primitive.Int()#Address.State())",
+    ];
+
+    fn scan_all(options: &[String]) -> Vec<(String, FakeOption)> {
+        options
+            .iter()
+            .map(|option| Scanner::new(option).scan().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn read_schema_of_emitted_schema_is_the_same_options() {
+        let header_options: Vec<(String, FakeOption)> = scan_all(
+            &OPTIONS
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>(),
+        );
+        let mut document: Vec<u8> = Vec::new();
+        write_schema(&mut document, &header_options).unwrap();
+        let options: Vec<String> = read_schema(&String::from_utf8(document).unwrap()).unwrap();
+        assert_eq!(scan_all(&options), header_options);
+    }
+
+    #[test]
+    fn read_schema_with_json_escapes_and_whitespace() {
+        let text: &str = "\u{feff} {\"options\" :[ \"Fixed.String(a#\\u3042\\ud83d\\ude00\\/)\" ,\n\"Name.FirstName(b)\"]}\n";
+        assert_eq!(
+            read_schema(text),
+            Ok(vec![
+                "Fixed.String(a#あ\u{1f600}/)".to_string(),
+                "Name.FirstName(b)".to_string()
+            ])
+        );
+        assert_eq!(read_schema("{\"options\": []}"), Ok(vec![]));
+    }
+
+    #[test]
+    fn read_schema_rejects_other_documents() {
+        assert!(read_schema("").is_err());
+        assert!(read_schema("{}").is_err());
+        assert!(read_schema("{\"options\": [\"a\",]}").is_err());
+        assert!(read_schema("{\"options\": [1]}").is_err());
+        assert!(read_schema("{\"columns\": []}").is_err());
+        assert!(read_schema("{\"options\": [], \"options\": []}").is_err());
+        assert!(read_schema("{\"options\": []} []").is_err());
+        assert!(read_schema("{\"options\": [\"\\ud83d\"]}").is_err());
+    }
+}
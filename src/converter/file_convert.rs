@@ -24,6 +24,10 @@ pub fn to_record<W: io::Write, R: Rng>(
             let converter = JsonConverter::new(header_options);
             converter.to_record(w, &faker.gen_record(converter.options()))
         }
+        FileType::Raw => {
+            let converter = RawConverter::new(header_options);
+            converter.to_record(w, &faker.gen_record(converter.options()))
+        }
     }
 }
 
@@ -47,6 +51,10 @@ pub fn to_record_with_header<W: io::Write, R: Rng>(
             let converter = JsonConverter::new(header_options);
             converter.to_record_with_header(w, &faker.gen_record(converter.options()))
         }
+        FileType::Raw => {
+            let converter = RawConverter::new(header_options);
+            converter.to_record_with_header(w, &faker.gen_record(converter.options()))
+        }
     }
 }
 
@@ -71,6 +79,10 @@ pub fn to_data_set<W: io::Write, R: Rng>(
             let converter = JsonConverter::new(header_options);
             converter.to_data_set(w, &faker.gen_data_set(count, converter.options()))
         }
+        FileType::Raw => {
+            let converter = RawConverter::new(header_options);
+            converter.to_data_set(w, &faker.gen_data_set(count, converter.options()))
+        }
     }
 }
 
@@ -95,6 +107,10 @@ pub fn to_full_form<W: io::Write, R: Rng>(
             let converter = JsonConverter::new(header_options);
             converter.to_full_form(w, &faker.gen_data_set(count, converter.options()))
         }
+        FileType::Raw => {
+            let converter = RawConverter::new(header_options);
+            converter.to_full_form(w, &faker.gen_data_set(count, converter.options()))
+        }
     }
 }
 
@@ -386,3 +402,62 @@ impl Converter for JsonConverter {
         w.flush()
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct RawConverter {
+    header: Vec<String>,
+    options: Vec<FakeOption>,
+}
+
+impl Converter for RawConverter {
+    fn new(header_options: &[(String, FakeOption)]) -> Self {
+        let (header, options): (Vec<String>, Vec<FakeOption>) = split_options(header_options);
+        RawConverter { header, options }
+    }
+
+    fn header(&self) -> &Vec<String> {
+        &self.header
+    }
+
+    fn options(&self) -> &Vec<FakeOption> {
+        &self.options
+    }
+
+    /// write a record with flush
+    fn to_header<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{}", self.header().join(" "))?;
+        w.flush()
+    }
+
+    /// write a record with flush
+    fn to_record<W: io::Write>(&self, w: &mut W, record: &[String]) -> io::Result<()> {
+        write!(w, "{}", record.join(" "))?;
+        w.flush()
+    }
+
+    fn to_record_with_header<W: io::Write>(&self, w: &mut W, record: &[String]) -> io::Result<()> {
+        self.to_header(w)?;
+        writeln!(w)?;
+        self.to_record(w, record)
+    }
+
+    fn to_data_set<W: io::Write>(&self, w: &mut W, data_set: &[Vec<String>]) -> io::Result<()> {
+        if let Some((fst, snd)) = data_set.split_first() {
+            self.to_record(w, fst)?;
+            for record in snd {
+                writeln!(w)?;
+                self.to_record(w, record)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn to_full_form<W: io::Write>(&self, w: &mut W, data_set: &[Vec<String>]) -> io::Result<()> {
+        self.to_header(w)?;
+        for record in data_set {
+            writeln!(w)?;
+            self.to_record(w, record)?;
+        }
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@ pub enum FileType {
     CSV,
     TSV,
     JSON,
+    // values as is joined by space such as lines of log
+    Raw,
 }
 
 impl Default for FileType {
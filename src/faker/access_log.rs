@@ -0,0 +1,267 @@
+use chrono::{DateTime, Local};
+
+/// layout of a line of access log of web server.
+/// Custom is format string with directives of Apache LogFormat such as %h and %>s, or variables of nginx such as $remote_addr.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub enum AccessLogFormat {
+    // Common Log Format of Apache
+    Common,
+    // Combined Log Format of Apache
+    #[default]
+    Combined,
+    // "main" format of nginx
+    Nginx,
+    Custom(String),
+}
+
+impl AccessLogFormat {
+    /// all formats except Custom
+    pub fn all_list() -> Vec<Self> {
+        use AccessLogFormat::*;
+        vec![Common, Combined, Nginx]
+    }
+
+    /// format string of the layout
+    pub fn layout(&self) -> &str {
+        use AccessLogFormat::*;
+        match self {
+            Common => "%h %l %u %t \"%r\" %>s %b",
+            Combined => "%h %l %u %t \"%r\" %>s %b \"%{Referer}i\" \"%{User-Agent}i\"",
+            Nginx => "$remote_addr - $remote_user [$time_local] \"$request\" $status $body_bytes_sent \"$http_referer\" \"$http_user_agent\" \"$http_x_forwarded_for\"",
+            Custom(layout) => layout,
+        }
+    }
+}
+
+impl std::fmt::Display for AccessLogFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use AccessLogFormat::*;
+        let s: String = match self {
+            Common => "common",
+            Combined => "combined",
+            Nginx => "nginx",
+            Custom(layout) => layout,
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// a request in access log. None of user, referer and forwarded_for is written as "-".
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AccessLogEntry {
+    pub remote_addr: String,
+    pub user: Option<String>,
+    pub time: DateTime<Local>,
+    pub method: String,
+    pub path: String,
+    // such as "?page=2" or empty
+    pub query: String,
+    pub protocol: String,
+    pub status: u16,
+    pub size: usize,
+    pub referer: Option<String>,
+    pub user_agent: String,
+    pub forwarded_for: Option<String>,
+    // time taken to serve the request in microseconds
+    pub duration: u64,
+}
+
+// Apache directives such as %h and %{Referer}i sorted from longest
+const APACHE_DIRECTIVE: &[&str] = &[
+    "%{X-Forwarded-For}i",
+    "%{User-Agent}i",
+    "%{Referer}i",
+    "%>s",
+    "%%",
+    "%a",
+    "%b",
+    "%B",
+    "%D",
+    "%h",
+    "%H",
+    "%l",
+    "%m",
+    "%q",
+    "%r",
+    "%s",
+    "%t",
+    "%T",
+    "%u",
+    "%U",
+];
+
+// nginx variables sorted from longest
+const NGINX_VARIABLE: &[&str] = &[
+    "$http_x_forwarded_for",
+    "$body_bytes_sent",
+    "$http_user_agent",
+    "$server_protocol",
+    "$request_method",
+    "$http_referer",
+    "$time_iso8601",
+    "$request_time",
+    "$request_uri",
+    "$remote_addr",
+    "$remote_user",
+    "$time_local",
+    "$bytes_sent",
+    "$request",
+    "$status",
+    "$msec",
+];
+
+impl AccessLogEntry {
+    /// line of the layout. unknown directive and variable are written as is.
+    pub fn format(&self, layout: &str) -> String {
+        let mut line: String = String::new();
+        let mut rest: &str = layout;
+        while let Some(c) = rest.chars().next() {
+            let token: Option<&str> = match c {
+                '%' => APACHE_DIRECTIVE
+                    .iter()
+                    .find(|d| rest.starts_with(*d))
+                    .cloned(),
+                '$' => NGINX_VARIABLE
+                    .iter()
+                    .find(|v| rest.starts_with(*v))
+                    .cloned(),
+                _ => None,
+            };
+            match token {
+                Some(token) => {
+                    line.push_str(&self.value(token));
+                    rest = &rest[token.len()..];
+                }
+                None => {
+                    line.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        line
+    }
+
+    fn value(&self, token: &str) -> String {
+        let or_hyphen =
+            |value: &Option<String>| -> String { value.clone().unwrap_or_else(|| "-".to_string()) };
+        let request: String = format!(
+            "{} {}{} {}",
+            self.method, self.path, self.query, self.protocol
+        );
+        match token {
+            "%h" | "%a" | "$remote_addr" => self.remote_addr.to_string(),
+            "%l" => "-".to_string(),
+            "%u" | "$remote_user" => or_hyphen(&self.user),
+            "%t" => format!("[{}]", self.time.format("%d/%b/%Y:%H:%M:%S %z")),
+            "$time_local" => self.time.format("%d/%b/%Y:%H:%M:%S %z").to_string(),
+            "$time_iso8601" => self.time.format("%Y-%m-%dT%H:%M:%S%:z").to_string(),
+            "$msec" => format!(
+                "{}.{:03}",
+                self.time.timestamp(),
+                self.time.timestamp_subsec_millis()
+            ),
+            "%r" | "$request" => request,
+            "%m" | "$request_method" => self.method.to_string(),
+            "%U" => self.path.to_string(),
+            "%q" => self.query.to_string(),
+            "$request_uri" => format!("{}{}", self.path, self.query),
+            "%H" | "$server_protocol" => self.protocol.to_string(),
+            "%>s" | "%s" | "$status" => self.status.to_string(),
+            "%b" if self.size == 0 => "-".to_string(),
+            "%b" | "%B" | "$body_bytes_sent" => self.size.to_string(),
+            // headers of response are about 200 bytes
+            "$bytes_sent" => (self.size + 200).to_string(),
+            "%{Referer}i" | "$http_referer" => or_hyphen(&self.referer),
+            "%{User-Agent}i" | "$http_user_agent" => self.user_agent.to_string(),
+            "%{X-Forwarded-For}i" | "$http_x_forwarded_for" => or_hyphen(&self.forwarded_for),
+            "%D" => self.duration.to_string(),
+            "%T" => (self.duration / 1_000_000).to_string(),
+            "$request_time" => format!("{:.3}", self.duration as f64 / 1_000_000.0),
+            "%%" => "%".to_string(),
+            _ => token.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    // example of Combined Log Format in Apache documentation
+    fn entry() -> AccessLogEntry {
+        AccessLogEntry {
+            remote_addr: "127.0.0.1".to_string(),
+            user: Some("frank".to_string()),
+            time: Local.with_ymd_and_hms(2000, 10, 10, 13, 55, 36).unwrap(),
+            method: "GET".to_string(),
+            path: "/apache_pb.gif".to_string(),
+            query: "".to_string(),
+            protocol: "HTTP/1.0".to_string(),
+            status: 200,
+            size: 2326,
+            referer: Some("http://www.example.com/start.html".to_string()),
+            user_agent: "Mozilla/4.08 [en] (Win98; I ;Nav)".to_string(),
+            forwarded_for: None,
+            duration: 1_234_567,
+        }
+    }
+
+    // offset of the local time zone such as -0700
+    fn offset(entry: &AccessLogEntry) -> String {
+        entry.time.format("%z").to_string()
+    }
+
+    #[test]
+    fn format_common_and_combined() {
+        let entry: AccessLogEntry = entry();
+        assert_eq!(
+            entry.format(AccessLogFormat::Common.layout()),
+            format!(
+                "127.0.0.1 - frank [10/Oct/2000:13:55:36 {}] \"GET /apache_pb.gif HTTP/1.0\" 200 2326",
+                offset(&entry)
+            )
+        );
+        assert_eq!(
+            entry.format(AccessLogFormat::Combined.layout()),
+            format!(
+                "127.0.0.1 - frank [10/Oct/2000:13:55:36 {}] \"GET /apache_pb.gif HTTP/1.0\" 200 2326 \"http://www.example.com/start.html\" \"Mozilla/4.08 [en] (Win98; I ;Nav)\"",
+                offset(&entry)
+            )
+        );
+    }
+
+    #[test]
+    fn format_nginx_with_hyphen_of_none() {
+        let entry: AccessLogEntry = AccessLogEntry {
+            user: None,
+            referer: None,
+            ..entry()
+        };
+        assert_eq!(
+            entry.format(AccessLogFormat::Nginx.layout()),
+            format!(
+                "127.0.0.1 - - [10/Oct/2000:13:55:36 {}] \"GET /apache_pb.gif HTTP/1.0\" 200 2326 \"-\" \"Mozilla/4.08 [en] (Win98; I ;Nav)\" \"-\"",
+                offset(&entry)
+            )
+        );
+    }
+
+    #[test]
+    fn format_custom_layout() {
+        let entry: AccessLogEntry = AccessLogEntry {
+            query: "?page=2".to_string(),
+            size: 0,
+            ..entry()
+        };
+        assert_eq!(
+            entry.format("%m %U%q %b %B %D %T 100%% %z"),
+            "GET /apache_pb.gif?page=2 - 0 1234567 1 100% %z"
+        );
+        assert_eq!(
+            entry.format("$request_uri $request_time $bytes_sent $unknown"),
+            "/apache_pb.gif?page=2 1.235 200 $unknown"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+/// style of block number in address such as "1-2-3" and "1丁目2番3号"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum AddressStyle {
+    #[default]
+    Hyphen,
+    Kanji,
+}
+
+impl AddressStyle {
+    pub fn all_list() -> Vec<Self> {
+        use AddressStyle::*;
+        vec![Hyphen, Kanji]
+    }
+}
+
+impl std::fmt::Display for AddressStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use AddressStyle::*;
+        let s: String = match self {
+            Hyphen => "hyphen",
+            Kanji => "kanji",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
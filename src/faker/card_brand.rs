@@ -0,0 +1,121 @@
+/// brand of credit card such as Visa and JCB
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum CardBrand {
+    #[default]
+    Visa,
+    Mastercard,
+    Jcb,
+    Amex,
+}
+
+impl CardBrand {
+    pub fn all_list() -> Vec<Self> {
+        use CardBrand::*;
+        vec![Visa, Mastercard, Jcb, Amex]
+    }
+
+    /// length of card number with check digit
+    pub fn length(&self) -> usize {
+        use CardBrand::*;
+        match self {
+            Visa | Mastercard | Jcb => 16,
+            Amex => 15,
+        }
+    }
+
+    /// length of security code
+    pub fn cvv_length(&self) -> usize {
+        use CardBrand::*;
+        match self {
+            Visa | Mastercard | Jcb => 3,
+            Amex => 4,
+        }
+    }
+
+    /// prefixes of test card numbers published for payment sandboxes such as 411111 of 4111111111111111.
+    pub fn test_prefixes(&self) -> &'static [&'static str] {
+        use CardBrand::*;
+        match self {
+            Visa => &["411111", "424242", "401288", "400005"],
+            Mastercard => &["555555", "510510", "520082", "222300"],
+            Jcb => &["353011", "356600", "356611"],
+            Amex => &["378282", "371449", "378734"],
+        }
+    }
+}
+
+impl std::fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use CardBrand::*;
+        let s: String = match self {
+            Visa => "visa",
+            Mastercard => "mastercard",
+            Jcb => "jcb",
+            Amex => "amex",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helper::{luhn_check_digit, to_digits};
+
+    // test card numbers published for payment sandboxes such as Stripe and PayPal
+    fn test_numbers(brand: CardBrand) -> &'static [&'static str] {
+        use CardBrand::*;
+        match brand {
+            Visa => &[
+                "4111111111111111",
+                "4242424242424242",
+                "4012888888881881",
+                "4000056655665556",
+            ],
+            Mastercard => &[
+                "5555555555554444",
+                "5105105105105100",
+                "5200828282828210",
+                "2223003122003222",
+            ],
+            Jcb => &["3530111333300000", "3566002020360505", "3566111111111113"],
+            Amex => &["378282246310005", "371449635398431", "378734493671000"],
+        }
+    }
+
+    #[test]
+    fn test_numbers_have_test_prefix_length_and_luhn_check_digit_of_the_brand() {
+        for brand in CardBrand::all_list() {
+            for number in test_numbers(brand) {
+                assert_eq!(number.len(), brand.length(), "{}", number);
+                assert!(
+                    brand.test_prefixes().iter().any(|p| number.starts_with(p)),
+                    "{}",
+                    number
+                );
+                let digits: Vec<u8> = to_digits(number);
+                let (check_digit, base): (&u8, &[u8]) = digits.split_last().unwrap();
+                assert_eq!(luhn_check_digit(base), *check_digit, "{}", number);
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefixes_are_of_the_brand() {
+        for brand in CardBrand::all_list() {
+            for prefix in brand.test_prefixes() {
+                let head: u32 = prefix[..4].parse().unwrap();
+                let is_brand: bool = match brand {
+                    CardBrand::Visa => head / 1000 == 4,
+                    CardBrand::Mastercard => {
+                        (5100..=5599).contains(&head) || (2221..=2720).contains(&head)
+                    }
+                    CardBrand::Jcb => (3528..=3589).contains(&head),
+                    CardBrand::Amex => head / 100 == 34 || head / 100 == 37,
+                };
+                assert!(is_brand, "{}", prefix);
+            }
+        }
+    }
+}
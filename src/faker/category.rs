@@ -9,6 +9,8 @@ pub enum Category {
     Internet,
     Company,
     Address,
+    Finance,
+    Commerce,
     DateTime,
     FileSystem,
 }
@@ -17,8 +19,8 @@ impl Category {
     pub fn all_list() -> Vec<Self> {
         use Category::*;
         vec![
-            With, Fixed, Select, Lorem, Name, Primitive, Internet, Company, Address, DateTime,
-            FileSystem,
+            With, Fixed, Select, Lorem, Name, Primitive, Internet, Company, Address, Finance,
+            Commerce, DateTime, FileSystem,
         ]
     }
 }
@@ -36,6 +38,8 @@ impl std::fmt::Display for Category {
             Internet => "Internet",
             Company => "Company",
             Address => "Address",
+            Finance => "Finance",
+            Commerce => "Commerce",
             DateTime => "DateTime",
             FileSystem => "FileSystem",
         }
@@ -0,0 +1,112 @@
+use crate::helper::hsl_to_rgb;
+
+/// format of color such as "#12480F", "rgb(18, 72, 15)" and "hsl(115, 66%, 17%)"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum ColorFormat {
+    #[default]
+    Hex,
+    CssRgb,
+    CssHsl,
+    // tuple of hue, saturation and lightness such as "115,66,17"
+    Hsl,
+    // tuple of hue, saturation and value such as "115,79,28"
+    Hsv,
+}
+
+impl ColorFormat {
+    pub fn all_list() -> Vec<Self> {
+        use ColorFormat::*;
+        vec![Hex, CssRgb, CssHsl, Hsl, Hsv]
+    }
+
+    /// color of hue in degree, saturation and lightness in percent in the format
+    pub fn format(&self, hue: u16, saturation: u8, lightness: u8) -> String {
+        use ColorFormat::*;
+        let (r, g, b): (u8, u8, u8) = hsl_to_rgb(hue, saturation, lightness);
+        match self {
+            Hex => format!("#{:>02X}{:>02X}{:>02X}", r, g, b),
+            CssRgb => format!("rgb({}, {}, {})", r, g, b),
+            CssHsl => format!("hsl({}, {}%, {}%)", hue, saturation, lightness),
+            Hsl => format!("{},{},{}", hue, saturation, lightness),
+            Hsv => {
+                let s: f64 = saturation as f64 / 100.0;
+                let l: f64 = lightness as f64 / 100.0;
+                let v: f64 = l + s * l.min(1.0 - l);
+                let s_v: f64 = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+                format!("{},{},{}", hue, (s_v * 100.0).round(), (v * 100.0).round())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ColorFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use ColorFormat::*;
+        let s: String = match self {
+            Hex => "hex",
+            CssRgb => "css_rgb",
+            CssHsl => "css_hsl",
+            Hsl => "hsl",
+            Hsv => "hsv",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// tone of color as ranges of saturation and lightness of HSL
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum ColorTone {
+    #[default]
+    Any,
+    Pastel,
+    Vivid,
+    Muted,
+    Dark,
+}
+
+impl ColorTone {
+    pub fn all_list() -> Vec<Self> {
+        use ColorTone::*;
+        vec![Any, Pastel, Vivid, Muted, Dark]
+    }
+
+    /// minimum and maximum of saturation in percent
+    pub fn saturation(&self) -> (u8, u8) {
+        use ColorTone::*;
+        match self {
+            Any => (0, 100),
+            Pastel => (40, 90),
+            Vivid => (80, 100),
+            Muted => (10, 30),
+            Dark => (20, 100),
+        }
+    }
+
+    /// minimum and maximum of lightness in percent
+    pub fn lightness(&self) -> (u8, u8) {
+        use ColorTone::*;
+        match self {
+            Any => (0, 100),
+            Pastel => (75, 90),
+            Vivid => (40, 60),
+            Muted => (40, 70),
+            Dark => (10, 30),
+        }
+    }
+}
+
+impl std::fmt::Display for ColorTone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use ColorTone::*;
+        let s: String = match self {
+            Any => "any",
+            Pastel => "pastel",
+            Vivid => "vivid",
+            Muted => "muted",
+            Dark => "dark",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
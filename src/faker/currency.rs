@@ -0,0 +1,99 @@
+/// currency of price such as JPY and USD.
+/// Local is the currency of the locale, and Any is a currency selected for each record.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum Currency {
+    #[default]
+    Local,
+    Any,
+    Jpy,
+    Usd,
+    Eur,
+    Gbp,
+    Krw,
+    Cny,
+}
+
+impl Currency {
+    pub fn all_list() -> Vec<Self> {
+        use Currency::*;
+        vec![Local, Any, Jpy, Usd, Eur, Gbp, Krw, Cny]
+    }
+
+    /// currencies which have the code
+    pub fn code_list() -> Vec<Self> {
+        use Currency::*;
+        vec![Jpy, Usd, Eur, Gbp, Krw, Cny]
+    }
+
+    /// code of ISO 4217. Local and Any have no code.
+    pub fn code(&self) -> &'static str {
+        use Currency::*;
+        match self {
+            Local | Any => "",
+            Jpy => "JPY",
+            Usd => "USD",
+            Eur => "EUR",
+            Gbp => "GBP",
+            Krw => "KRW",
+            Cny => "CNY",
+        }
+    }
+
+    /// digits after the decimal point such as 0 of JPY and 2 of USD
+    pub fn decimals(&self) -> usize {
+        use Currency::*;
+        match self {
+            Jpy | Krw => 0,
+            _ => 2,
+        }
+    }
+
+    /// price is a multiple of this in the smallest unit such as 10 yen and 100 won
+    pub fn step(&self) -> u64 {
+        use Currency::*;
+        match self {
+            Jpy => 10,
+            Krw => 100,
+            _ => 1,
+        }
+    }
+
+    /// range in the major unit such as dollar to range in the smallest unit such as cent.
+    /// None if it is too large.
+    pub fn minor_range(&self, from: usize, to: usize) -> Option<(u64, u64)> {
+        let scale: u64 = 10u64.pow(self.decimals() as u32);
+        Some((
+            (from as u64).checked_mul(scale)?,
+            (to as u64).checked_mul(scale)?,
+        ))
+    }
+
+    /// default range of price in the major unit
+    pub fn price_band(&self) -> (usize, usize) {
+        use Currency::*;
+        match self {
+            Jpy => (100, 50000),
+            Krw => (1000, 500000),
+            Cny => (5, 3000),
+            _ => (1, 500),
+        }
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use Currency::*;
+        let s: String = match self {
+            Local => "local",
+            Any => "any",
+            Jpy => "jpy",
+            Usd => "usd",
+            Eur => "eur",
+            Gbp => "gbp",
+            Krw => "krw",
+            Cny => "cny",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
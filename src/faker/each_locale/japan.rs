@@ -1,11 +1,66 @@
+use crate::faker::address_style::AddressStyle;
+use crate::faker::currency::Currency;
 use crate::faker::each_locale::Data;
+use crate::faker::gender::Gender;
+use crate::faker::phone_kind::PhoneKind;
 
-use crate::helper::gen_range;
+use crate::helper::{digits_to_string, gen_digits, gen_range};
 
 use rand::Rng;
 
 pub struct JapanData {}
 
+// area code of the prefectural capital for each prefecture
+const AREA_CODE: &[(&str, &str)] = &[
+    ("北海道", "011"),
+    ("青森県", "017"),
+    ("岩手県", "019"),
+    ("宮城県", "022"),
+    ("秋田県", "018"),
+    ("山形県", "023"),
+    ("福島県", "024"),
+    ("茨城県", "029"),
+    ("栃木県", "028"),
+    ("群馬県", "027"),
+    ("埼玉県", "048"),
+    ("千葉県", "043"),
+    ("東京都", "03"),
+    ("神奈川県", "045"),
+    ("新潟県", "025"),
+    ("富山県", "076"),
+    ("石川県", "076"),
+    ("福井県", "0776"),
+    ("山梨県", "055"),
+    ("長野県", "026"),
+    ("岐阜県", "058"),
+    ("静岡県", "054"),
+    ("愛知県", "052"),
+    ("三重県", "059"),
+    ("滋賀県", "077"),
+    ("京都府", "075"),
+    ("大阪府", "06"),
+    ("兵庫県", "078"),
+    ("奈良県", "0742"),
+    ("和歌山県", "073"),
+    ("鳥取県", "0857"),
+    ("島根県", "0852"),
+    ("岡山県", "086"),
+    ("広島県", "082"),
+    ("山口県", "083"),
+    ("徳島県", "088"),
+    ("香川県", "087"),
+    ("愛媛県", "089"),
+    ("高知県", "088"),
+    ("福岡県", "092"),
+    ("佐賀県", "0952"),
+    ("長崎県", "095"),
+    ("熊本県", "096"),
+    ("大分県", "097"),
+    ("宮崎県", "0985"),
+    ("鹿児島県", "099"),
+    ("沖縄県", "098"),
+];
+
 impl Data for JapanData {
     // Lorem
     const WORD: &'static [&'static str] = &[
@@ -567,86 +622,88 @@ impl Data for JapanData {
     ];
 
     // Name
-    const FIRST_NAME: &'static [&'static str] = &[
+    const MALE_FIRST_NAME: &'static [&'static str] = &[
         "宏之:ヒロユキ",
         "俊樹:トシキ",
-        "時子:トキコ",
-        "咲月:サツキ",
-        "晴子:ハルコ",
         "優起:ユウキ",
-        "彩奈:アヤナ",
         "幸一:コウイチ",
         "守友:モリトモ",
         "福太郎:フクタロウ",
-        "真緒:マオ",
-        "実紅:ミク",
-        "愛梨:アイリ",
         "清三:セイゾウ",
-        "琴音:コトネ",
-        "七海:ナナミ",
-        "晴奈:ハルナ",
         "史郎:シロウ",
-        "優華:ユウカ",
-        "令子:レイコ",
         "啓介:ケイスケ",
         "正吾:ショウゴ",
-        "春陽:ハルヒ",
         "孝之:タカユキ",
-        "樹里:ジュリ",
         "亘:ワタル",
-        "顕子:アキコ",
-        "陽南:ヒナ",
-        "早紀:サキ",
         "巧:タクミ",
-        "景子:ケイコ",
         "謙治:ケンジ",
         "喜晴 :ヨシハル",
-        "笑:エミ",
-        "遥菜:ハルナ",
-        "瑞紀:ミズキ",
         "陽友:ヒユウ",
         "悠宇人:ユウト",
         "静男:シズオ",
         "重一:シゲカズ",
         "昭司:ショウジ",
         "秋風:シュウ",
-        "佳代:カヨ",
         "泰彦:ヤスヒコ",
-        "陽菜:ハルナ",
-        "愛里:アイリ",
         "達徳:タツノリ",
         "喜三郎:キサブロウ",
-        "沙菜:サナ",
         "誠之助:セイノスケ",
-        "花子:ハナコ",
         "寿:ヒサシ",
         "久道:ヒサミチ",
         "彰英 :アキヒデ",
-        "美玖:ミク",
-        "美緒:ミオ",
-        "遙香:ハルカ",
         "泰男:ヤスオ",
         "竜一:リュウイチ",
         "正徳:マサノリ",
+        "成輝:ナルキ",
+        "章夫:アキオ",
+        "泰貴:タイキ",
+        "邦男:クニオ",
+        "瑞晃:ミズキ",
+        "正道:マサミチ",
+    ];
+    const FEMALE_FIRST_NAME: &'static [&'static str] = &[
+        "時子:トキコ",
+        "咲月:サツキ",
+        "晴子:ハルコ",
+        "彩奈:アヤナ",
+        "真緒:マオ",
+        "実紅:ミク",
+        "愛梨:アイリ",
+        "琴音:コトネ",
+        "七海:ナナミ",
+        "晴奈:ハルナ",
+        "優華:ユウカ",
+        "令子:レイコ",
+        "春陽:ハルヒ",
+        "樹里:ジュリ",
+        "顕子:アキコ",
+        "陽南:ヒナ",
+        "早紀:サキ",
+        "景子:ケイコ",
+        "笑:エミ",
+        "遥菜:ハルナ",
+        "瑞紀:ミズキ",
+        "佳代:カヨ",
+        "陽菜:ハルナ",
+        "愛里:アイリ",
+        "沙菜:サナ",
+        "花子:ハナコ",
+        "美玖:ミク",
+        "美緒:ミオ",
+        "遙香:ハルカ",
         "文子:アヤコ",
         "愛:アイ",
         "桃華:モモカ",
         "香帆:カホ",
         "茉莉子:マリコ",
-        "成輝:ナルキ",
         "桂子:ケイコ",
-        "章夫:アキオ",
-        "泰貴:タイキ",
         "冨士子:フジコ",
         "香菜:カナ",
         "桃奈 :モナ",
         "敏子:トシコ",
-        "邦男:クニオ",
-        "瑞晃:ミズキ",
         "杏奈:アンナ",
         "茂子:シゲコ",
         "香苗:カナエ",
-        "正道:マサミチ",
         "百花:モモカ",
     ];
     const LAST_NAME: &'static [&'static str] = &[
@@ -734,6 +791,57 @@ impl Data for JapanData {
     fn build_name(last_name: &str, first_name: &str) -> String {
         [last_name, first_name].join(" ")
     }
+    fn gender_label(gender: Gender) -> &'static str {
+        match gender {
+            Gender::Male => "男性",
+            Gender::Female => "女性",
+        }
+    }
+
+    // Internet
+    // traditional colors of Japan
+    const COLOR_NAME: &'static [&'static str] = &[
+        "桜色:#FEF4F4",
+        "鴇色:#F4B3C2",
+        "薄紅:#F0908D",
+        "牡丹色:#E7609E",
+        "紅色:#D7003A",
+        "茜色:#B7282E",
+        "臙脂:#B94047",
+        "朱色:#EB6101",
+        "柿色:#ED6D3D",
+        "橙色:#EE7800",
+        "山吹色:#F8B500",
+        "黄土色:#C39143",
+        "小豆色:#96514D",
+        "栗色:#762F07",
+        "焦茶:#6F4B3E",
+        "若草色:#C3D825",
+        "萌黄:#AACF53",
+        "鶯色:#928C36",
+        "抹茶色:#C5C56A",
+        "若竹色:#68BE8D",
+        "常磐色:#007B43",
+        "浅葱色:#00A3AF",
+        "水色:#BCE2E8",
+        "空色:#A0D8EF",
+        "瑠璃色:#1E50A2",
+        "群青色:#4C6CB3",
+        "藍色:#165E83",
+        "紺色:#223A70",
+        "藤色:#BBBCDE",
+        "桔梗色:#5654A2",
+        "菫色:#7058A3",
+        "江戸紫:#745399",
+        "生成り色:#FBFAF5",
+        "胡粉色:#FFFFFC",
+        "象牙色:#F8F4E6",
+        "利休鼠:#888E7E",
+        "鼠色:#949495",
+        "鈍色:#727171",
+        "墨:#595857",
+        "漆黒:#0D0015",
+    ];
 
     // Company
     const COMPANY_SUFFIX: &'static [&'static str] = &[
@@ -747,6 +855,7 @@ impl Data for JapanData {
         "投資事業有限責任組合",
         "有限責任事業組合",
     ];
+    // reading is for domain of the company
     const COMPANY_NAME: &'static [&'static str] = &[
         "アシスト",
         "ライズ",
@@ -766,14 +875,14 @@ impl Data for JapanData {
         "プログレス",
         "サクセス",
         "アルファ",
-        "大和",
+        "大和:ヤマト",
         "フェニックス",
         "クリエイト",
         "エムテック",
         "クローバー",
         "プラスワン",
         "ひまわり",
-        "三和",
+        "三和:サンワ",
         "さくら",
         "アーク",
         "アクシス",
@@ -789,7 +898,7 @@ impl Data for JapanData {
         "エース",
         "コスモス",
         "ヤマト",
-        "和",
+        "和:ワ",
         "マックス",
         "コスモ",
         "アトラス",
@@ -797,38 +906,38 @@ impl Data for JapanData {
         "オアシス",
         "グローバル",
         "エイト",
-        "雅",
+        "雅:ミヤビ",
         "ワークス",
-        "共栄",
-        "鈴木",
+        "共栄:キョウエイ",
+        "鈴木:スズキ",
         "ステップ",
         "タナカ",
         "ライフサポート",
         "ファイン",
-        "大地",
+        "大地:ダイチ",
         "サンコー",
         "ライフ",
         "プライム",
         "プラス",
         "ネクサス",
         "エクセル",
-        "三幸",
+        "三幸:サンコウ",
         "ウィズ",
         "フリーダム",
         "クラフト",
         "ヨシダ",
-        "太陽",
-        "和光",
+        "太陽:タイヨウ",
+        "和光:ワコウ",
         "ワタナベ",
         "フェイス",
         "スリーエス",
-        "未来",
-        "田中",
+        "未来:ミライ",
+        "田中:タナカ",
         "プラネット",
         "アスク",
-        "高橋",
+        "高橋:タカハシ",
         "エイム",
-        "三和",
+        "三和:サンワ",
         "クレスト",
         "タカハシ",
         "エステック",
@@ -836,13 +945,13 @@ impl Data for JapanData {
         "ウィル",
         "タクト",
         "タック",
-        "佐藤",
+        "佐藤:サトウ",
         "インフィニティ",
         "シンエイ",
         "サンワ",
         "ワールド",
         "フィールド",
-        "三栄",
+        "三栄:サンエイ",
         "リード",
         "トライ",
     ];
@@ -941,6 +1050,41 @@ impl Data for JapanData {
         "補助的金融業等",
         "保険業",
     ];
+    const DEPARTMENT: &'static [&'static str] = &[
+        "総務部",
+        "人事部",
+        "経理部",
+        "財務部",
+        "法務部",
+        "広報部",
+        "経営企画部",
+        "営業部",
+        "海外営業部",
+        "マーケティング部",
+        "カスタマーサポート部",
+        "開発部",
+        "研究開発部",
+        "情報システム部",
+        "品質保証部",
+        "製造部",
+        "生産管理部",
+        "購買部",
+        "物流部",
+        "内部監査室",
+    ];
+    const JOB_TITLE: &'static [&'static str] = &[
+        "代表取締役社長",
+        "取締役",
+        "執行役員",
+        "本部長",
+        "部長",
+        "次長",
+        "課長",
+        "課長代理",
+        "係長",
+        "主任",
+        "一般社員",
+    ];
 
     // Address
     const STREET_NAME: &'static [&'static str] = &[
@@ -1093,6 +1237,55 @@ impl Data for JapanData {
         "鹿児島県",
         "沖縄県",
     ];
+    const STATE_AREA: &'static [(&'static str, f64, f64, f64)] = &[
+        ("北海道", 43.45, 142.65, 70.0),
+        ("青森県", 40.60, 140.60, 20.0),
+        ("岩手県", 39.60, 141.35, 35.0),
+        ("宮城県", 38.55, 140.85, 20.0),
+        ("秋田県", 39.70, 140.35, 25.0),
+        ("山形県", 38.45, 140.10, 25.0),
+        ("福島県", 37.40, 140.30, 35.0),
+        ("茨城県", 36.35, 140.25, 20.0),
+        ("栃木県", 36.65, 139.80, 25.0),
+        ("群馬県", 36.50, 138.95, 25.0),
+        ("埼玉県", 36.00, 139.45, 20.0),
+        ("千葉県", 35.55, 140.20, 20.0),
+        ("東京都", 35.70, 139.45, 12.0),
+        ("神奈川県", 35.45, 139.38, 10.0),
+        ("新潟県", 37.45, 138.90, 25.0),
+        ("富山県", 36.60, 137.20, 12.0),
+        ("石川県", 36.40, 136.60, 10.0),
+        ("福井県", 35.95, 136.35, 15.0),
+        ("山梨県", 35.60, 138.60, 20.0),
+        ("長野県", 36.10, 138.05, 40.0),
+        ("岐阜県", 35.80, 137.00, 35.0),
+        ("静岡県", 35.15, 138.30, 15.0),
+        ("愛知県", 35.10, 137.15, 15.0),
+        ("三重県", 34.65, 136.30, 15.0),
+        ("滋賀県", 34.95, 136.20, 12.0),
+        ("京都府", 35.10, 135.55, 20.0),
+        ("大阪府", 34.62, 135.58, 9.0),
+        ("兵庫県", 35.05, 134.85, 25.0),
+        ("奈良県", 34.35, 135.85, 25.0),
+        ("和歌山県", 33.95, 135.45, 20.0),
+        ("鳥取県", 35.35, 133.85, 12.0),
+        ("島根県", 35.00, 132.55, 15.0),
+        ("岡山県", 34.95, 133.80, 25.0),
+        ("広島県", 34.60, 132.75, 25.0),
+        ("山口県", 34.20, 131.55, 20.0),
+        ("徳島県", 33.90, 134.20, 20.0),
+        ("香川県", 34.22, 133.95, 8.0),
+        ("愛媛県", 33.65, 132.90, 20.0),
+        ("高知県", 33.65, 133.40, 12.0),
+        ("福岡県", 33.55, 130.65, 20.0),
+        ("佐賀県", 33.30, 130.15, 12.0),
+        ("長崎県", 32.85, 130.02, 5.0),
+        ("熊本県", 32.65, 130.90, 25.0),
+        ("大分県", 33.15, 131.35, 20.0),
+        ("宮崎県", 32.20, 131.20, 25.0),
+        ("鹿児島県", 31.75, 130.45, 12.0),
+        ("沖縄県", 26.35, 127.80, 3.0),
+    ];
     const COUNTRY_NAME: &'static [&'static str] = &[
         "アルバ",
         "アフガニスタン",
@@ -1422,6 +1615,83 @@ impl Data for JapanData {
         format!("{}{}{}", state, city, street)
     }
 
+    const TOWN_NAME: &'static [&'static str] = &[
+        "青柳",
+        "室",
+        "戸坂桜上町",
+        "塚無岱",
+        "優徳町",
+        "皆山町",
+        "朝日町",
+        "森西町",
+        "廻田町",
+        "富沢",
+        "福岡町",
+        "志免",
+        "本町",
+        "栄町",
+        "中央",
+        "緑町",
+        "旭町",
+        "若葉",
+        "桜台",
+        "東町",
+        "幸町",
+        "宮前",
+        "日吉町",
+        "錦町",
+        "寿町",
+        "花園",
+        "新町",
+        "大手町",
+        "高砂",
+        "柳町",
+    ];
+
+    // chome, banchi and go
+    fn gen_block_number<R: Rng>(rng: &mut R) -> Vec<u16> {
+        vec![
+            gen_range(rng, 1..=9),
+            gen_range(rng, 1..=30),
+            gen_range(rng, 1..=20),
+        ]
+    }
+
+    // such as 1-2-3 or 1丁目2番3号
+    fn format_block_number(numbers: &[u16], style: AddressStyle) -> String {
+        match style {
+            AddressStyle::Hyphen => numbers
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<String>>()
+                .join("-"),
+            AddressStyle::Kanji => numbers
+                .iter()
+                .zip(["丁目", "番", "号"].iter())
+                .map(|(n, unit)| format!("{}{}", n, unit))
+                .collect(),
+        }
+    }
+
+    fn build_street(town: &str, block_number: &str) -> String {
+        format!("{}{}", town, block_number)
+    }
+
+    // room number such as 101号室
+    fn gen_room_number<R: Rng>(rng: &mut R) -> String {
+        let floor: u8 = gen_range(rng, 1..=12);
+        let room: u8 = gen_range(rng, 1..=10);
+        format!("{}{:>02}号室", floor, room)
+    }
+
+    fn build_address_line2(building: &str, room_number: &str) -> String {
+        format!("{} {}", building, room_number)
+    }
+
+    fn build_detailed_address(street: &str, line2: &str, city: &str, state: &str) -> String {
+        format!("{}{}{} {}", state, city, street, line2)
+    }
+
     fn gen_zip_code<R: Rng>(rng: &mut R, hyphen: bool) -> String {
         let a: u16 = gen_range(rng, 0..=999);
         let b: u16 = gen_range(rng, 0..=9999);
@@ -1432,6 +1702,49 @@ impl Data for JapanData {
         };
     }
 
+    const CALLING_CODE: &'static str = "81";
+
+    // number of ten digits such as 03-1234-5678, 090-1234-5678, 050-1234-5678 and 0120-123-456.
+    // If the state is unknown, area code of landline is of a random prefecture.
+    fn gen_phone_number<R: Rng>(rng: &mut R, kind: PhoneKind, state: &str, hyphen: bool) -> String {
+        let parts: Vec<String> = match kind {
+            PhoneKind::Landline => {
+                let area_code: &str = match AREA_CODE.iter().find(|(s, _)| *s == state) {
+                    Some((_, code)) => code,
+                    None => AREA_CODE[gen_range(rng, 0..AREA_CODE.len())].1,
+                };
+                // area code and local exchange code are six digits
+                let digits: u32 = 6 - area_code.len() as u32;
+                let exchange: u32 = gen_range(rng, 2 * 10u32.pow(digits - 1)..10u32.pow(digits));
+                let subscriber: u16 = gen_range(rng, 0..=9999);
+                vec![
+                    area_code.to_string(),
+                    exchange.to_string(),
+                    format!("{:>04}", subscriber),
+                ]
+            }
+            PhoneKind::Mobile | PhoneKind::IpPhone => {
+                let prefix: &str = match kind {
+                    PhoneKind::Mobile => ["070", "080", "090"][gen_range(rng, 0..3)],
+                    _ => "050",
+                };
+                let a: u16 = gen_range(rng, 1000..=9999);
+                let b: u16 = gen_range(rng, 0..=9999);
+                vec![prefix.to_string(), a.to_string(), format!("{:>04}", b)]
+            }
+            PhoneKind::TollFree => {
+                let a: u16 = gen_range(rng, 0..=999);
+                let b: u16 = gen_range(rng, 0..=999);
+                vec![
+                    "0120".to_string(),
+                    format!("{:>03}", a),
+                    format!("{:>03}", b),
+                ]
+            }
+        };
+        parts.join(if hyphen { "-" } else { "" })
+    }
+
     fn gen_domestic_phone_number<R: Rng>(rng: &mut R, hyphen: bool) -> String {
         let a: u8 = gen_range(rng, 0..=9);
         let b: u16 = gen_range(rng, 0..=999);
@@ -1442,4 +1755,141 @@ impl Data for JapanData {
             format!("{:>02}{:>03}{:>04}", a, b, c)
         };
     }
+
+    // Finance
+    // 4 digits code of 全銀協
+    // names and codes are of real banks intentionally so that the code passes validation of the bank code.
+    // branch, account number and account holder are fictional, so the account does not exist.
+    const BANK: &'static [&'static str] = &[
+        "みずほ銀行:0001",
+        "三菱UFJ銀行:0005",
+        "三井住友銀行:0009",
+        "りそな銀行:0010",
+        "埼玉りそな銀行:0017",
+        "PayPay銀行:0033",
+        "セブン銀行:0034",
+        "ソニー銀行:0035",
+        "楽天銀行:0036",
+        "住信SBIネット銀行:0038",
+        "auじぶん銀行:0039",
+        "イオン銀行:0040",
+        "北海道銀行:0116",
+        "千葉銀行:0134",
+        "横浜銀行:0138",
+        "静岡銀行:0149",
+        "京都銀行:0158",
+        "福岡銀行:0177",
+    ];
+    // branch code is 3 digits and not of the real branch of the bank
+    const BANK_BRANCH: &'static [&'static str] = &[
+        "本店営業部:100",
+        "東京営業部:110",
+        "新宿支店:120",
+        "渋谷支店:130",
+        "池袋支店:140",
+        "上野支店:150",
+        "品川支店:160",
+        "横浜支店:200",
+        "川崎支店:210",
+        "大宮支店:220",
+        "千葉支店:230",
+        "名古屋支店:300",
+        "京都支店:400",
+        "大阪支店:410",
+        "梅田支店:420",
+        "難波支店:430",
+        "神戸支店:440",
+        "広島支店:500",
+        "福岡支店:600",
+        "札幌支店:700",
+        "仙台支店:710",
+    ];
+    const ACCOUNT_TYPE: &'static [&'static str] = &["普通", "当座"];
+
+    // 7 digits
+    fn gen_account_number<R: Rng>(rng: &mut R) -> String {
+        digits_to_string(&gen_digits(rng, 7))
+    }
+
+    // Commerce
+    const ISBN_GROUP: &'static [&'static str] = &["4"];
+    const CURRENCY: Currency = Currency::Jpy;
+    const PRODUCT_ADJECTIVE: &'static [&'static str] = &[
+        "上質な",
+        "シンプルな",
+        "軽量な",
+        "丈夫な",
+        "おしゃれな",
+        "コンパクトな",
+        "高級な",
+        "使いやすい",
+        "なめらかな",
+        "ナチュラルな",
+        "クラシックな",
+        "モダンな",
+        "やわらかい",
+        "折りたたみ式の",
+        "防水の",
+    ];
+    const PRODUCT_MATERIAL: &'static [&'static str] = &[
+        "木",
+        "革",
+        "綿",
+        "麻",
+        "ウール",
+        "シルク",
+        "ステンレス",
+        "アルミ",
+        "ガラス",
+        "陶器",
+        "竹",
+        "和紙",
+        "檜",
+        "銅",
+        "シリコン",
+    ];
+    const PRODUCT_NOUN: &'static [&'static str] = &[
+        "チェア",
+        "テーブル",
+        "トートバッグ",
+        "財布",
+        "シャツ",
+        "マグカップ",
+        "タンブラー",
+        "ランプ",
+        "クッション",
+        "箸",
+        "弁当箱",
+        "傘",
+        "タオル",
+        "腕時計",
+        "ペンケース",
+        "スピーカー",
+    ];
+    // such as 上質な木のチェア
+    fn build_product_name(adjective: &str, material: &str, noun: &str) -> String {
+        format!("{}{}の{}", adjective, material, noun)
+    }
+    const PRODUCT_CATEGORY: &'static [&'static str] = &[
+        "家具",
+        "インテリア",
+        "キッチン用品",
+        "食器",
+        "ファッション",
+        "バッグ・小物",
+        "靴",
+        "アクセサリー",
+        "家電",
+        "パソコン・周辺機器",
+        "スマートフォン",
+        "本",
+        "文房具",
+        "おもちゃ",
+        "スポーツ・アウトドア",
+        "食品",
+        "飲料",
+        "化粧品",
+        "ヘルスケア",
+        "ペット用品",
+    ];
 }
@@ -0,0 +1,663 @@
+use crate::faker::address_style::AddressStyle;
+use crate::faker::currency::Currency;
+use crate::faker::each_locale::Data;
+use crate::faker::gender::Gender;
+use crate::faker::phone_kind::PhoneKind;
+
+use crate::helper::{digits_to_string, gen_digits, gen_range};
+
+use rand::Rng;
+
+pub struct KoreaData {}
+
+// area code for each province
+const AREA_CODE: &[(&str, &str)] = &[
+    ("서울특별시", "02"),
+    ("부산광역시", "051"),
+    ("대구광역시", "053"),
+    ("인천광역시", "032"),
+    ("광주광역시", "062"),
+    ("대전광역시", "042"),
+    ("울산광역시", "052"),
+    ("세종특별자치시", "044"),
+    ("경기도", "031"),
+    ("강원특별자치도", "033"),
+    ("충청북도", "043"),
+    ("충청남도", "041"),
+    ("전북특별자치도", "063"),
+    ("전라남도", "061"),
+    ("경상북도", "054"),
+    ("경상남도", "055"),
+    ("제주특별자치도", "064"),
+];
+
+impl Data for KoreaData {
+    // Lorem
+    const WORD: &'static [&'static str] = &[
+        "사과",
+        "바다",
+        "하늘",
+        "구름",
+        "나무",
+        "학교",
+        "도서관",
+        "자전거",
+        "기차",
+        "컴퓨터",
+        "커피",
+        "김치",
+        "산",
+        "강",
+        "바람",
+        "꽃",
+        "별",
+        "달",
+        "시장",
+        "공원",
+        "책상",
+        "창문",
+        "우산",
+        "편지",
+        "사진",
+        "음악",
+        "노래",
+        "여행",
+        "시간",
+        "친구",
+        "가방",
+        "운동화",
+        "지하철",
+        "비빔밥",
+        "한옥",
+        "단풍",
+        "겨울",
+        "봄날",
+        "등대",
+        "항구",
+    ];
+    const SENTENCE: &'static [&'static str] = &[
+        "아침 기차는 정시에 역을 출발했다.",
+        "그녀는 외투 주머니에 작은 수첩을 넣고 다녔다.",
+        "폭풍이 그렇게 빨리 지나갈 줄은 아무도 몰랐다.",
+        "도서관은 매달 첫째 월요일에 일찍 문을 닫는다.",
+        "부엌 식탁 위에서 갓 구운 빵이 식고 있었다.",
+        "위원회는 다음 주에 제안서를 검토할 예정이다.",
+        "현관에 신발을 벗어 두세요.",
+        "우리 팀은 예정보다 이틀 먼저 프로젝트를 끝냈다.",
+        "박물관은 일요일 오후에 무료로 입장할 수 있다.",
+        "오후 내내 가랑비가 내렸다.",
+        "새 정책은 내년 초부터 시행된다.",
+        "아이들이 광장 분수 주변에 모였다.",
+        "고속도로는 공사 구간 근처에서 정체되었다.",
+        "겨울 내내 산길은 눈으로 덮여 있었다.",
+        "모퉁이 가게의 커피가 동네에서 제일 맛있다.",
+        "주말마다 한강 공원에서 자전거를 탄다.",
+        "시장에는 제철 과일이 가득했다.",
+        "택배가 약속보다 하루 늦게 도착했다.",
+        "그는 모든 질문에 차분하게 대답했다.",
+        "저녁이 되자 항구의 불빛이 하나둘 켜졌다.",
+    ];
+    const PARAGRAPH: &'static [&'static str] = &[
+        "마을 축제는 매년 가을 끝자락에 열린다. 이웃 마을에서도 사람들이 찾아와 음식을 맛보고 공연을 구경한다. 해가 지면 광장에는 등불이 켜지고 늦은 밤까지 노랫소리가 이어진다.",
+        "사무실이 중앙로에 있는 건물 사 층으로 이전했다. 새 공간은 햇빛이 잘 들고 휴게실도 넓다. 버스 정류장에서 조금 더 걸어야 하지만 대부분의 직원들은 만족하고 있다.",
+        "등산로는 안내소 뒤편에서 시작해 소나무 숲을 따라 완만하게 올라간다. 한 시간쯤 걸으면 골짜기가 한눈에 내려다보이는 능선에 닿는다. 여름에는 물을 넉넉히 챙기고 이른 시간에 출발하는 것이 좋다.",
+        "도서관에 지역 역사 자료실이 새로 생겼다. 오래된 지도와 사진, 신문을 자유롭게 열람할 수 있다. 자원봉사자들이 훼손되기 쉬운 자료를 스캔해 온라인으로도 볼 수 있게 준비하고 있다.",
+        "빵집은 일요일을 제외하고 매일 해 뜨기 전에 문을 연다. 계피 향이 골목까지 퍼지면 이른 손님들이 줄을 선다. 단골들은 식빵을 사려면 일곱 시 전에 와야 한다는 것을 알고 있다.",
+        "시의회는 몇 달간의 논의 끝에 새 공원 조성 계획을 승인했다. 공원에는 놀이터와 산책로, 작은 연못이 들어선다. 공사는 봄에 시작해 연말 전에 마무리될 예정이다.",
+    ];
+
+    // Name
+    // reading is Revised Romanization of Korean
+    const MALE_FIRST_NAME: &'static [&'static str] = &[
+        "민준:Minjun",
+        "서준:Seojun",
+        "도윤:Doyun",
+        "예준:Yejun",
+        "시우:Siu",
+        "하준:Hajun",
+        "주원:Juwon",
+        "지호:Jiho",
+        "지후:Jihu",
+        "준우:Junu",
+        "현우:Hyeonu",
+        "준서:Junseo",
+        "도현:Dohyeon",
+        "건우:Geonu",
+        "우진:Ujin",
+        "민재:Minjae",
+        "현준:Hyeonjun",
+        "선우:Seonu",
+        "서진:Seojin",
+        "연우:Yeonu",
+        "정우:Jeongu",
+        "승현:Seunghyeon",
+        "성민:Seongmin",
+        "동현:Donghyeon",
+        "영호:Yeongho",
+    ];
+    const FEMALE_FIRST_NAME: &'static [&'static str] = &[
+        "서연:Seoyeon",
+        "서윤:Seoyun",
+        "지우:Jiu",
+        "서현:Seohyeon",
+        "민서:Minseo",
+        "하은:Haeun",
+        "하윤:Hayun",
+        "윤서:Yunseo",
+        "지유:Jiyu",
+        "지민:Jimin",
+        "채원:Chaewon",
+        "수아:Sua",
+        "지아:Jia",
+        "다은:Daeun",
+        "은서:Eunseo",
+        "예은:Yeeun",
+        "수빈:Subin",
+        "소윤:Soyun",
+        "예린:Yerin",
+        "지원:Jiwon",
+        "미영:Miyeong",
+        "은지:Eunji",
+        "혜진:Hyejin",
+        "수진:Sujin",
+        "영희:Yeonghui",
+    ];
+    const LAST_NAME: &'static [&'static str] = &[
+        "김:Gim",
+        "이:I",
+        "박:Bak",
+        "최:Choe",
+        "정:Jeong",
+        "강:Gang",
+        "조:Jo",
+        "윤:Yun",
+        "장:Jang",
+        "임:Im",
+        "한:Han",
+        "오:O",
+        "서:Seo",
+        "신:Sin",
+        "권:Gwon",
+        "황:Hwang",
+        "안:An",
+        "송:Song",
+        "전:Jeon",
+        "홍:Hong",
+        "고:Go",
+        "문:Mun",
+        "양:Yang",
+        "손:Son",
+        "배:Bae",
+        "백:Baek",
+        "허:Heo",
+        "유:Yu",
+        "남:Nam",
+        "심:Sim",
+        "노:No",
+        "하:Ha",
+        "곽:Gwak",
+        "성:Seong",
+        "차:Cha",
+        "주:Ju",
+        "구:Gu",
+        "민:Min",
+        "진:Jin",
+        "나:Na",
+    ];
+    // hangul name is written without space, romanized name is written with space.
+    fn build_name(last_name: &str, first_name: &str) -> String {
+        if last_name.is_ascii() && first_name.is_ascii() {
+            [last_name, first_name].join(" ")
+        } else {
+            [last_name, first_name].join("")
+        }
+    }
+    fn gender_label(gender: Gender) -> &'static str {
+        match gender {
+            Gender::Male => "남성",
+            Gender::Female => "여성",
+        }
+    }
+
+    // Internet
+    const COLOR_NAME: &'static [&'static str] = &[
+        "빨간색:#E60012",
+        "주황색:#F39800",
+        "노란색:#FFE100",
+        "연두색:#8FC31F",
+        "초록색:#009944",
+        "청록색:#008B8B",
+        "하늘색:#87CEEB",
+        "파란색:#0068B7",
+        "남색:#1D2088",
+        "쪽빛:#1A3A6B",
+        "보라색:#920783",
+        "자주색:#A8005F",
+        "분홍색:#F19CBB",
+        "살구색:#FBCEB1",
+        "갈색:#8B4513",
+        "밤색:#7B3F00",
+        "베이지색:#F5F5DC",
+        "금색:#D4AF37",
+        "은색:#C0C0C0",
+        "회색:#808080",
+        "검정색:#000000",
+        "흰색:#FFFFFF",
+    ];
+
+    // Company
+    const COMPANY_SUFFIX: &'static [&'static str] = &[
+        "주식회사",
+        "유한회사",
+        "합자회사",
+        "합명회사",
+        "유한책임회사",
+    ];
+    // reading is Revised Romanization of Korean for domain of the company
+    const COMPANY_NAME: &'static [&'static str] = &[
+        "한빛:Hanbit",
+        "미래:Mirae",
+        "대한:Daehan",
+        "새한:Saehan",
+        "동방:Dongbang",
+        "한결:Hangyeol",
+        "누리:Nuri",
+        "다온:Daon",
+        "하나로:Hanaro",
+        "푸른솔:Pureunsol",
+        "한울:Hanul",
+        "가온:Gaon",
+        "새솔:Saesol",
+        "보람:Boram",
+        "한마음:Hanmaeum",
+        "동성:Dongseong",
+        "삼정:Samjeong",
+        "우리:Uri",
+        "세진:Sejin",
+        "태양:Taeyang",
+    ];
+    // suffix is written before name such as 주식회사 한빛
+    fn build_company_name(name: &str, suffix: &str) -> String {
+        [suffix, name].join(" ")
+    }
+    const INDUSTRY: &'static [&'static str] = &[
+        "농업, 임업 및 어업",
+        "광업",
+        "제조업",
+        "전기, 가스, 증기 및 공기 조절 공급업",
+        "수도, 하수 및 폐기물 처리, 원료 재생업",
+        "건설업",
+        "도매 및 소매업",
+        "운수 및 창고업",
+        "숙박 및 음식점업",
+        "정보통신업",
+        "금융 및 보험업",
+        "부동산업",
+        "전문, 과학 및 기술 서비스업",
+        "사업시설 관리, 사업 지원 및 임대 서비스업",
+        "공공 행정, 국방 및 사회보장 행정",
+        "교육 서비스업",
+        "보건업 및 사회복지 서비스업",
+        "예술, 스포츠 및 여가관련 서비스업",
+    ];
+    const DEPARTMENT: &'static [&'static str] = &[
+        "인사팀",
+        "총무팀",
+        "재무팀",
+        "회계팀",
+        "법무팀",
+        "홍보팀",
+        "경영기획팀",
+        "영업팀",
+        "해외영업팀",
+        "마케팅팀",
+        "고객지원팀",
+        "개발팀",
+        "연구개발팀",
+        "정보시스템팀",
+        "품질보증팀",
+        "생산팀",
+        "구매팀",
+        "물류팀",
+        "감사팀",
+    ];
+    const JOB_TITLE: &'static [&'static str] = &[
+        "대표이사",
+        "전무",
+        "상무",
+        "이사",
+        "부장",
+        "차장",
+        "과장",
+        "대리",
+        "주임",
+        "사원",
+    ];
+
+    // Address
+    // road name and building number
+    const STREET_NAME: &'static [&'static str] = &[
+        "세종대로 175",
+        "테헤란로 152",
+        "을지로 65",
+        "올림픽로 300",
+        "중앙대로 120",
+        "해운대로 570",
+        "동성로 12",
+        "금남로 245",
+        "대학로 99",
+        "충장로 46",
+        "한밭대로 713",
+        "월드컵로 240",
+        "강남대로 396",
+        "퇴계로 18",
+        "수원천로 250",
+        "첨단과기로 123",
+        "중앙로 88",
+        "광복로 55",
+        "도산대로 27",
+        "은행로 3",
+    ];
+    const CITY_NAME: &'static [&'static str] = &[
+        "종로구",
+        "중구",
+        "강남구",
+        "마포구",
+        "송파구",
+        "해운대구",
+        "수성구",
+        "남동구",
+        "서구",
+        "유성구",
+        "수원시",
+        "성남시",
+        "고양시",
+        "청주시",
+        "전주시",
+        "포항시",
+        "창원시",
+        "춘천시",
+        "제주시",
+        "천안시",
+    ];
+    const STATE_NAME: &'static [&'static str] = &[
+        "서울특별시",
+        "부산광역시",
+        "대구광역시",
+        "인천광역시",
+        "광주광역시",
+        "대전광역시",
+        "울산광역시",
+        "세종특별자치시",
+        "경기도",
+        "강원특별자치도",
+        "충청북도",
+        "충청남도",
+        "전북특별자치도",
+        "전라남도",
+        "경상북도",
+        "경상남도",
+        "제주특별자치도",
+    ];
+    const STATE_AREA: &'static [(&'static str, f64, f64, f64)] = &[
+        ("서울특별시", 37.56, 126.98, 8.0),
+        ("부산광역시", 35.18, 129.05, 6.0),
+        ("대구광역시", 35.87, 128.60, 8.0),
+        ("인천광역시", 37.49, 126.72, 5.0),
+        ("광주광역시", 35.16, 126.85, 7.0),
+        ("대전광역시", 36.35, 127.38, 8.0),
+        ("울산광역시", 35.55, 129.25, 8.0),
+        ("세종특별자치시", 36.55, 127.25, 6.0),
+        ("경기도", 37.24, 127.18, 15.0),
+        ("강원특별자치도", 37.70, 128.30, 40.0),
+        ("충청북도", 36.75, 127.70, 25.0),
+        ("충청남도", 36.55, 126.85, 20.0),
+        ("전북특별자치도", 35.72, 127.15, 25.0),
+        ("전라남도", 34.95, 126.95, 15.0),
+        ("경상북도", 36.40, 128.70, 40.0),
+        ("경상남도", 35.35, 128.25, 25.0),
+        ("제주특별자치도", 33.38, 126.55, 10.0),
+    ];
+    const BUILDING: &'static [&'static str] = &[
+        "한빛빌딩",
+        "미래타워",
+        "중앙빌딩",
+        "푸른아파트",
+        "새한오피스텔",
+        "동방빌라",
+        "누리타워",
+        "가온빌딩",
+        "한울아파트",
+        "보람맨션",
+        "세진빌딩",
+        "태양오피스텔",
+    ];
+
+    fn build_address(street: &str, city: &str, state: &str) -> String {
+        [state, city, street].join(" ")
+    }
+
+    // road name
+    const TOWN_NAME: &'static [&'static str] = &[
+        "세종대로",
+        "테헤란로",
+        "을지로",
+        "올림픽로",
+        "중앙대로",
+        "해운대로",
+        "동성로",
+        "금남로",
+        "대학로",
+        "충장로",
+        "한밭대로",
+        "월드컵로",
+        "강남대로",
+        "퇴계로",
+        "수원천로",
+        "첨단과기로",
+        "중앙로",
+        "광복로",
+        "도산대로",
+        "은행로",
+    ];
+
+    fn gen_block_number<R: Rng>(rng: &mut R) -> Vec<u16> {
+        vec![gen_range(rng, 1..=999)]
+    }
+
+    // building number of the road is not affected by the style.
+    fn format_block_number(numbers: &[u16], _style: AddressStyle) -> String {
+        numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join("-")
+    }
+
+    fn build_street(town: &str, block_number: &str) -> String {
+        [town, block_number].join(" ")
+    }
+
+    // room number such as 1203호
+    fn gen_room_number<R: Rng>(rng: &mut R) -> String {
+        let floor: u8 = gen_range(rng, 1..=25);
+        let room: u8 = gen_range(rng, 1..=8);
+        format!("{}{:>02}호", floor, room)
+    }
+
+    fn build_address_line2(building: &str, room_number: &str) -> String {
+        [building, room_number].join(" ")
+    }
+
+    // detail of building is written after comma such as 세종대로 175, 한빛빌딩 1203호
+    fn build_detailed_address(street: &str, line2: &str, city: &str, state: &str) -> String {
+        format!("{} {} {}, {}", state, city, street, line2)
+    }
+
+    // postal code of five digits has no hyphen.
+    fn gen_zip_code<R: Rng>(rng: &mut R, _hyphen: bool) -> String {
+        let code: u16 = gen_range(rng, 1000..=63644);
+        format!("{:>05}", code)
+    }
+
+    const CALLING_CODE: &'static str = "82";
+
+    // number such as 02-1234-5678, 010-1234-5678, 070-1234-5678 and 080-123-4567.
+    // If the state is unknown, area code of landline is of a random province.
+    fn gen_phone_number<R: Rng>(rng: &mut R, kind: PhoneKind, state: &str, hyphen: bool) -> String {
+        let a: u16 = gen_range(rng, 2000..=9999);
+        let b: u16 = gen_range(rng, 0..=9999);
+        let parts: Vec<String> = match kind {
+            PhoneKind::Landline => {
+                let area_code: &str = match AREA_CODE.iter().find(|(s, _)| *s == state) {
+                    Some((_, code)) => code,
+                    None => AREA_CODE[gen_range(rng, 0..AREA_CODE.len())].1,
+                };
+                vec![area_code.to_string(), a.to_string(), format!("{:>04}", b)]
+            }
+            PhoneKind::Mobile => vec!["010".to_string(), a.to_string(), format!("{:>04}", b)],
+            PhoneKind::IpPhone => vec!["070".to_string(), a.to_string(), format!("{:>04}", b)],
+            PhoneKind::TollFree => vec![
+                "080".to_string(),
+                (a / 10).to_string(),
+                format!("{:>04}", b),
+            ],
+        };
+        parts.join(if hyphen { "-" } else { "" })
+    }
+
+    // mobile phone number such as 010-1234-5678
+    fn gen_domestic_phone_number<R: Rng>(rng: &mut R, hyphen: bool) -> String {
+        let a: u16 = gen_range(rng, 2000..=9999);
+        let b: u16 = gen_range(rng, 0..=9999);
+        if hyphen {
+            format!("010-{:>04}-{:>04}", a, b)
+        } else {
+            format!("010{:>04}{:>04}", a, b)
+        }
+    }
+
+    // Finance
+    // 3 digits code of 금융결제원
+    // names and codes are of real banks intentionally so that the code passes validation of the bank code.
+    // branch, account number and account holder are fictional, so the account does not exist.
+    const BANK: &'static [&'static str] = &[
+        "KDB산업은행:002",
+        "IBK기업은행:003",
+        "KB국민은행:004",
+        "NH농협은행:011",
+        "우리은행:020",
+        "SC제일은행:023",
+        "대구은행:031",
+        "부산은행:032",
+        "광주은행:034",
+        "경남은행:039",
+        "하나은행:081",
+        "신한은행:088",
+        "케이뱅크:089",
+        "카카오뱅크:090",
+        "토스뱅크:092",
+    ];
+    // branch code is not of the real branch of the bank
+    const BANK_BRANCH: &'static [&'static str] = &[
+        "본점영업부:001",
+        "명동지점:101",
+        "종로지점:102",
+        "강남지점:201",
+        "서초지점:202",
+        "잠실지점:203",
+        "여의도지점:301",
+        "목동지점:302",
+        "분당지점:401",
+        "수원지점:402",
+        "인천지점:501",
+        "대전지점:601",
+        "대구지점:701",
+        "부산지점:801",
+        "광주지점:901",
+    ];
+    const ACCOUNT_TYPE: &'static [&'static str] = &["보통예금", "당좌예금"];
+
+    // 12 digits
+    fn gen_account_number<R: Rng>(rng: &mut R) -> String {
+        digits_to_string(&gen_digits(rng, 12))
+    }
+
+    // Commerce
+    const ISBN_GROUP: &'static [&'static str] = &["89"];
+    const CURRENCY: Currency = Currency::Krw;
+    const PRODUCT_ADJECTIVE: &'static [&'static str] = &[
+        "고급스러운",
+        "심플한",
+        "가벼운",
+        "튼튼한",
+        "세련된",
+        "컴팩트한",
+        "실용적인",
+        "부드러운",
+        "클래식한",
+        "모던한",
+        "휴대용",
+        "방수",
+        "친환경",
+        "프리미엄",
+        "빈티지",
+    ];
+    const PRODUCT_MATERIAL: &'static [&'static str] = &[
+        "원목",
+        "가죽",
+        "면",
+        "린넨",
+        "울",
+        "실크",
+        "스테인리스",
+        "알루미늄",
+        "유리",
+        "도자기",
+        "대나무",
+        "한지",
+        "구리",
+        "실리콘",
+        "라탄",
+    ];
+    const PRODUCT_NOUN: &'static [&'static str] = &[
+        "의자",
+        "테이블",
+        "토트백",
+        "지갑",
+        "셔츠",
+        "머그컵",
+        "텀블러",
+        "조명",
+        "쿠션",
+        "수저 세트",
+        "도시락",
+        "우산",
+        "수건",
+        "손목시계",
+        "필통",
+        "스피커",
+    ];
+    const PRODUCT_CATEGORY: &'static [&'static str] = &[
+        "가구",
+        "인테리어",
+        "주방용품",
+        "식기",
+        "패션의류",
+        "가방·잡화",
+        "신발",
+        "액세서리",
+        "가전",
+        "컴퓨터·주변기기",
+        "휴대폰",
+        "도서",
+        "문구",
+        "완구",
+        "스포츠·레저",
+        "식품",
+        "음료",
+        "화장품",
+        "건강",
+        "반려동물용품",
+    ];
+}
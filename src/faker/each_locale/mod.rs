@@ -1,52 +1,861 @@
+use crate::faker::access_log::{AccessLogEntry, AccessLogFormat};
+use crate::faker::address_style::AddressStyle;
+use crate::faker::currency::Currency;
 use crate::faker::each_locale::japan::JapanData;
+use crate::faker::each_locale::korea::KoreaData;
+use crate::faker::each_locale::pack::LocalePack;
+use crate::faker::each_locale::usa::UsaData;
 use crate::faker::fake_options::FakeOption;
+use crate::faker::gender::Gender;
+use crate::faker::geo_area::{degree, GeoArea};
+use crate::faker::hash_format::HashFormat;
+use crate::faker::ip_range::{ipv4_in_block, ipv6_in_block, IpRange};
+use crate::faker::kana::zengin_kana;
 use crate::faker::locale::Locale;
+use crate::faker::password_policy::PasswordPolicy;
+use crate::faker::phone_kind::PhoneKind;
+use crate::faker::romaji::{card_holder, romanize, Capitalization};
+use crate::faker::url_format::{UrlFormat, UrlScheme};
+use crate::faker::user_agent::{Browser, DeviceType, Platform, UserAgentFormat};
+use crate::hash::{sha256_crypt, sha256_hex, CRYPT_CHAR};
 use crate::helper::{
-    gen_alpha_num_chars, gen_ascii_chars, gen_fraction_part, gen_password_chars, gen_range, select,
-    select_many, split,
+    ascii_label, corporate_number_check_digit, digits_to_string, format_latitude, format_longitude,
+    gen_alpha_num_chars, gen_ascii_chars, gen_by_pattern, gen_chars_of_sets, gen_digits,
+    gen_fraction_part, gen_host_part, gen_password_chars, gen_point_in_box, gen_point_in_circle,
+    gen_range, gtin_check_digit, individual_number_check_digit, isbn10_check_digit,
+    luhn_check_digit, percent_encode, select, select_many, split, to_digits,
 };
 
-use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use rand::seq::SliceRandom;
 use rand::Rng;
 use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
 
 pub mod japan;
+pub mod korea;
+pub mod pack;
+pub mod usa;
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+// reserved domain of RFC 2606
+const RESERVED_DOMAIN: &[&str] = &[
+    "example",
+    "test",
+    "example.com",
+    "example.net",
+    "example.org",
+];
+
+// keys of query parameters of URL
+const URL_QUERY_KEY: &[&str] = &[
+    "id",
+    "page",
+    "q",
+    "sort",
+    "lang",
+    "ref",
+    "category",
+    "limit",
+    "offset",
+    "utm_source",
+];
+
+// crawlers of search engines in user agent
+const BOT_AGENT: &[&str] = &[
+    "Googlebot/2.1; +http://www.google.com/bot.html",
+    "bingbot/2.0; +http://www.bing.com/bingbot.htm",
+    "YandexBot/3.0; +http://yandex.com/bots",
+    "DuckDuckBot/1.1; +http://duckduckgo.com/duckduckbot.html",
+    "Applebot/0.1; +http://www.apple.com/go/applebot",
+    "Baiduspider/2.0; +http://www.baidu.com/search/spider.html",
+];
+
+// models of android devices in user agent
+const ANDROID_MODEL: &[&str] = &[
+    "Pixel 7",
+    "Pixel 8",
+    "Pixel 8a",
+    "SM-S911B",
+    "SM-S921U",
+    "SM-A546E",
+    "SO-51C",
+    "SH-53C",
+    "2201117TG",
+    "moto g54 5G",
+];
+
+// methods of HTTP request with weight
+const HTTP_METHOD: &[(&str, u32)] = &[
+    ("GET", 80),
+    ("POST", 10),
+    ("HEAD", 3),
+    ("PUT", 3),
+    ("DELETE", 2),
+    ("PATCH", 1),
+    ("OPTIONS", 1),
+];
+
+// weight of the status code in access log. interim responses of 1xx are not logged.
+fn http_status_weight(code: u16) -> u32 {
+    match code {
+        100..=199 => 0,
+        200 => 400,
+        304 => 30,
+        404 => 20,
+        302 => 15,
+        301 => 10,
+        _ => 1,
+    }
+}
+
+// extensions of static files in path of access log
+const STATIC_EXTENSION: &[&str] = &["html", "css", "js", "png", "jpg", "svg", "ico"];
+
+// keywords of CSS named colors and their hex
+const CSS_COLOR_NAME: &[&str] = &[
+    "aliceblue:#F0F8FF",
+    "antiquewhite:#FAEBD7",
+    "aqua:#00FFFF",
+    "aquamarine:#7FFFD4",
+    "azure:#F0FFFF",
+    "beige:#F5F5DC",
+    "bisque:#FFE4C4",
+    "black:#000000",
+    "blanchedalmond:#FFEBCD",
+    "blue:#0000FF",
+    "blueviolet:#8A2BE2",
+    "brown:#A52A2A",
+    "burlywood:#DEB887",
+    "cadetblue:#5F9EA0",
+    "chartreuse:#7FFF00",
+    "chocolate:#D2691E",
+    "coral:#FF7F50",
+    "cornflowerblue:#6495ED",
+    "cornsilk:#FFF8DC",
+    "crimson:#DC143C",
+    "cyan:#00FFFF",
+    "darkblue:#00008B",
+    "darkcyan:#008B8B",
+    "darkgoldenrod:#B8860B",
+    "darkgray:#A9A9A9",
+    "darkgreen:#006400",
+    "darkgrey:#A9A9A9",
+    "darkkhaki:#BDB76B",
+    "darkmagenta:#8B008B",
+    "darkolivegreen:#556B2F",
+    "darkorange:#FF8C00",
+    "darkorchid:#9932CC",
+    "darkred:#8B0000",
+    "darksalmon:#E9967A",
+    "darkseagreen:#8FBC8F",
+    "darkslateblue:#483D8B",
+    "darkslategray:#2F4F4F",
+    "darkslategrey:#2F4F4F",
+    "darkturquoise:#00CED1",
+    "darkviolet:#9400D3",
+    "deeppink:#FF1493",
+    "deepskyblue:#00BFFF",
+    "dimgray:#696969",
+    "dimgrey:#696969",
+    "dodgerblue:#1E90FF",
+    "firebrick:#B22222",
+    "floralwhite:#FFFAF0",
+    "forestgreen:#228B22",
+    "fuchsia:#FF00FF",
+    "gainsboro:#DCDCDC",
+    "ghostwhite:#F8F8FF",
+    "gold:#FFD700",
+    "goldenrod:#DAA520",
+    "gray:#808080",
+    "green:#008000",
+    "greenyellow:#ADFF2F",
+    "grey:#808080",
+    "honeydew:#F0FFF0",
+    "hotpink:#FF69B4",
+    "indianred:#CD5C5C",
+    "indigo:#4B0082",
+    "ivory:#FFFFF0",
+    "khaki:#F0E68C",
+    "lavender:#E6E6FA",
+    "lavenderblush:#FFF0F5",
+    "lawngreen:#7CFC00",
+    "lemonchiffon:#FFFACD",
+    "lightblue:#ADD8E6",
+    "lightcoral:#F08080",
+    "lightcyan:#E0FFFF",
+    "lightgoldenrodyellow:#FAFAD2",
+    "lightgray:#D3D3D3",
+    "lightgreen:#90EE90",
+    "lightgrey:#D3D3D3",
+    "lightpink:#FFB6C1",
+    "lightsalmon:#FFA07A",
+    "lightseagreen:#20B2AA",
+    "lightskyblue:#87CEFA",
+    "lightslategray:#778899",
+    "lightslategrey:#778899",
+    "lightsteelblue:#B0C4DE",
+    "lightyellow:#FFFFE0",
+    "lime:#00FF00",
+    "limegreen:#32CD32",
+    "linen:#FAF0E6",
+    "magenta:#FF00FF",
+    "maroon:#800000",
+    "mediumaquamarine:#66CDAA",
+    "mediumblue:#0000CD",
+    "mediumorchid:#BA55D3",
+    "mediumpurple:#9370DB",
+    "mediumseagreen:#3CB371",
+    "mediumslateblue:#7B68EE",
+    "mediumspringgreen:#00FA9A",
+    "mediumturquoise:#48D1CC",
+    "mediumvioletred:#C71585",
+    "midnightblue:#191970",
+    "mintcream:#F5FFFA",
+    "mistyrose:#FFE4E1",
+    "moccasin:#FFE4B5",
+    "navajowhite:#FFDEAD",
+    "navy:#000080",
+    "oldlace:#FDF5E6",
+    "olive:#808000",
+    "olivedrab:#6B8E23",
+    "orange:#FFA500",
+    "orangered:#FF4500",
+    "orchid:#DA70D6",
+    "palegoldenrod:#EEE8AA",
+    "palegreen:#98FB98",
+    "paleturquoise:#AFEEEE",
+    "palevioletred:#DB7093",
+    "papayawhip:#FFEFD5",
+    "peachpuff:#FFDAB9",
+    "peru:#CD853F",
+    "pink:#FFC0CB",
+    "plum:#DDA0DD",
+    "powderblue:#B0E0E6",
+    "purple:#800080",
+    "rebeccapurple:#663399",
+    "red:#FF0000",
+    "rosybrown:#BC8F8F",
+    "royalblue:#4169E1",
+    "saddlebrown:#8B4513",
+    "salmon:#FA8072",
+    "sandybrown:#F4A460",
+    "seagreen:#2E8B57",
+    "seashell:#FFF5EE",
+    "sienna:#A0522D",
+    "silver:#C0C0C0",
+    "skyblue:#87CEEB",
+    "slateblue:#6A5ACD",
+    "slategray:#708090",
+    "slategrey:#708090",
+    "snow:#FFFAFA",
+    "springgreen:#00FF7F",
+    "steelblue:#4682B4",
+    "tan:#D2B48C",
+    "teal:#008080",
+    "thistle:#D8BFD8",
+    "tomato:#FF6347",
+    "turquoise:#40E0D0",
+    "violet:#EE82EE",
+    "wheat:#F5DEB3",
+    "white:#FFFFFF",
+    "whitesmoke:#F5F5F5",
+    "yellow:#FFFF00",
+    "yellowgreen:#9ACD32",
+];
+
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Generator {
     locale: Locale,
+    pack: Option<Arc<LocalePack>>,
+    // weight of (male, female) for selecting gender of first name
+    gender_ratio: (u32, u32),
+}
+
+/// parts of an address except the state. block number is kept as numbers to be formatted in each style.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AddressParts {
+    city: String,
+    town: String,
+    block_number: Vec<u16>,
+    building: String,
+    room_number: String,
 }
 
 impl Generator {
     pub fn new(locale: Locale) -> Self {
-        Generator { locale }
+        Generator {
+            locale,
+            pack: None,
+            gender_ratio: (1, 1),
+        }
     }
-    pub fn gen<R: Rng>(&mut self, rng: &mut R, option: &FakeOption) -> String {
-        match self.locale {
-            Locale::Japan => JapanData::gen(rng, option),
+    /// generator for locale of the pack which use data in the pack before data of the locale.
+    pub fn with_pack(pack: Arc<LocalePack>) -> Self {
+        Generator {
+            locale: pack.locale(),
+            pack: Some(pack),
+            gender_ratio: (1, 1),
         }
     }
+    /// weight of male and female for selecting gender. If male is 0, generate only female.
+    pub fn set_gender_ratio(&mut self, male: u32, female: u32) {
+        self.gender_ratio = (male, female);
+    }
+    pub fn gender_ratio(&self) -> (u32, u32) {
+        self.gender_ratio
+    }
+    /// generator of the locale with the same gender ratio. the pack is shared if it is of the locale.
+    pub fn for_locale(&self, locale: Locale) -> Generator {
+        let mut generator: Generator = match &self.pack {
+            Some(pack) if pack.locale() == locale => Generator::with_pack(pack.clone()),
+            _ => Generator::new(locale),
+        };
+        generator.gender_ratio = self.gender_ratio;
+        generator
+    }
+    pub fn gen<R: Rng>(&mut self, rng: &mut R, option: &FakeOption) -> String {
+        Rand::gen(self, rng, option)
+    }
     pub fn locale(&self) -> Locale {
         self.locale
     }
     pub fn build_name(&self, last_name: &str, first_name: &str) -> String {
+        Source::build_name(self, last_name, first_name)
+    }
+    pub fn gen_gender<R: Rng>(&self, rng: &mut R) -> Gender {
+        Rand::gen_gender(self, rng)
+    }
+    /// first name and furigana for the gender
+    pub fn gen_first_name<R: Rng>(&self, rng: &mut R, gender: Gender) -> (String, String) {
+        Rand::gen_first_name(self, rng, gender)
+    }
+    pub fn gender_label(&self, gender: Gender) -> &'static str {
+        Source::gender_label(self, gender)
+    }
+    /// data in the state such as address and landline phone number
+    pub fn gen_in_state<R: Rng>(&self, rng: &mut R, option: &FakeOption, state: &str) -> String {
+        Rand::gen_in_state(self, rng, option, state)
+    }
+    /// parts of an address shared by DetailedAddress, AddressLine1 and AddressLine2
+    pub fn gen_address_parts<R: Rng>(&self, rng: &mut R) -> AddressParts {
+        Rand::gen_address_parts(self, rng)
+    }
+    /// DetailedAddress, AddressLine1 or AddressLine2 of the address parts in the state
+    pub fn format_address(
+        &self,
+        option: &FakeOption,
+        address: &AddressParts,
+        state: &str,
+    ) -> String {
+        Rand::format_address(self, option, address, state)
+    }
+    /// point of latitude and longitude in the area. state is for GeoArea::State.
+    pub fn gen_point<R: Rng>(&self, rng: &mut R, area: GeoArea, state: Option<&str>) -> (f64, f64) {
+        Rand::gen_point(self, rng, area, state)
+    }
+    /// latitude, longitude or "latitude,longitude" of the point for the option
+    pub fn format_point(&self, option: &FakeOption, point: (f64, f64)) -> String {
+        Rand::format_point(self, option, point)
+    }
+    /// name and domain of a company
+    pub fn gen_company<R: Rng>(&self, rng: &mut R) -> (String, String) {
+        Rand::gen_company(self, rng)
+    }
+    /// email of the person at the domain from readings of the names
+    pub fn build_employee_email(&self, first_name: &str, last_name: &str, domain: &str) -> String {
+        Rand::build_employee_email(self, first_name, last_name, domain)
+    }
+    /// name and code of a bank
+    pub fn gen_bank<R: Rng>(&self, rng: &mut R) -> (String, String) {
+        Rand::gen_bank(self, rng)
+    }
+    /// name and code of a branch of the bank
+    pub fn gen_bank_branch<R: Rng>(&self, rng: &mut R) -> (String, String) {
+        Rand::gen_bank_branch(self, rng)
+    }
+    /// line of access log at the time
+    pub fn gen_access_log<R: Rng>(
+        &self,
+        rng: &mut R,
+        format: &AccessLogFormat,
+        time: DateTime<Local>,
+    ) -> String {
+        Rand::gen_access_log(self, rng, format, time)
+    }
+    /// hash of the password in the format
+    pub fn gen_password_hash<R: Rng>(
+        &self,
+        rng: &mut R,
+        format: HashFormat,
+        password: &str,
+    ) -> String {
+        Rand::gen_password_hash(self, rng, format, password)
+    }
+    // list of the locale without the pack
+    fn locale_list(&self, list: DataList) -> &'static [&'static str] {
+        match self.locale {
+            Locale::Japan => JapanData::list(list),
+            Locale::UnitedStates => UsaData::list(list),
+            Locale::Korea => KoreaData::list(list),
+        }
+    }
+}
+
+impl Source for Generator {
+    fn select<R: Rng>(&self, rng: &mut R, list: DataList) -> &str {
+        match self.pack.as_ref().and_then(|pack| pack.list(list)) {
+            Some(data) => data
+                .choose(rng)
+                .expect("failed select data from empty list."),
+            None => select(rng, self.locale_list(list)),
+        }
+    }
+    fn select_many<R: Rng>(
+        &self,
+        rng: &mut R,
+        list: DataList,
+        minimum: usize,
+        maximum: usize,
+    ) -> Vec<&str> {
+        match self.pack.as_ref().and_then(|pack| pack.list(list)) {
+            Some(data) => {
+                let size: usize = gen_range(rng, minimum..=maximum);
+                data.choose_multiple(rng, size)
+                    .map(|line| line.as_str())
+                    .collect()
+            }
+            None => select_many(rng, self.locale_list(list), minimum, maximum),
+        }
+    }
+    fn ipv4(&self) -> &'static [&'static (u8, u8, u8)] {
+        match self.locale {
+            Locale::Japan => JapanData {}.ipv4(),
+            Locale::UnitedStates => UsaData {}.ipv4(),
+            Locale::Korea => KoreaData {}.ipv4(),
+        }
+    }
+    fn http_status_code(&self) -> &'static [&'static u16] {
+        match self.locale {
+            Locale::Japan => JapanData {}.http_status_code(),
+            Locale::UnitedStates => UsaData {}.http_status_code(),
+            Locale::Korea => KoreaData {}.http_status_code(),
+        }
+    }
+    fn build_name(&self, last_name: &str, first_name: &str) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.build_name(last_name, first_name),
+            Locale::UnitedStates => UsaData {}.build_name(last_name, first_name),
+            Locale::Korea => KoreaData {}.build_name(last_name, first_name),
+        }
+    }
+    fn gender_label(&self, gender: Gender) -> &'static str {
+        match self.locale {
+            Locale::Japan => JapanData {}.gender_label(gender),
+            Locale::UnitedStates => UsaData {}.gender_label(gender),
+            Locale::Korea => KoreaData {}.gender_label(gender),
+        }
+    }
+    fn gender_ratio(&self) -> (u32, u32) {
+        self.gender_ratio
+    }
+    fn locale_generator(&self, locale: Locale) -> Generator {
+        self.for_locale(locale)
+    }
+    fn build_company_name(&self, name: &str, suffix: &str) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.build_company_name(name, suffix),
+            Locale::UnitedStates => UsaData {}.build_company_name(name, suffix),
+            Locale::Korea => KoreaData {}.build_company_name(name, suffix),
+        }
+    }
+    fn build_address(&self, street: &str, city: &str, state: &str) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.build_address(street, city, state),
+            Locale::UnitedStates => UsaData {}.build_address(street, city, state),
+            Locale::Korea => KoreaData {}.build_address(street, city, state),
+        }
+    }
+    fn gen_block_number<R: Rng>(&self, rng: &mut R) -> Vec<u16> {
+        match self.locale {
+            Locale::Japan => JapanData {}.gen_block_number(rng),
+            Locale::UnitedStates => UsaData {}.gen_block_number(rng),
+            Locale::Korea => KoreaData {}.gen_block_number(rng),
+        }
+    }
+    fn format_block_number(&self, numbers: &[u16], style: AddressStyle) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.format_block_number(numbers, style),
+            Locale::UnitedStates => UsaData {}.format_block_number(numbers, style),
+            Locale::Korea => KoreaData {}.format_block_number(numbers, style),
+        }
+    }
+    fn build_street(&self, town: &str, block_number: &str) -> String {
         match self.locale {
-            Locale::Japan => JapanData::build_name(last_name, first_name),
+            Locale::Japan => JapanData {}.build_street(town, block_number),
+            Locale::UnitedStates => UsaData {}.build_street(town, block_number),
+            Locale::Korea => KoreaData {}.build_street(town, block_number),
         }
     }
+    fn gen_room_number<R: Rng>(&self, rng: &mut R) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.gen_room_number(rng),
+            Locale::UnitedStates => UsaData {}.gen_room_number(rng),
+            Locale::Korea => KoreaData {}.gen_room_number(rng),
+        }
+    }
+    fn build_address_line2(&self, building: &str, room_number: &str) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.build_address_line2(building, room_number),
+            Locale::UnitedStates => UsaData {}.build_address_line2(building, room_number),
+            Locale::Korea => KoreaData {}.build_address_line2(building, room_number),
+        }
+    }
+    fn build_detailed_address(&self, street: &str, line2: &str, city: &str, state: &str) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.build_detailed_address(street, line2, city, state),
+            Locale::UnitedStates => UsaData {}.build_detailed_address(street, line2, city, state),
+            Locale::Korea => KoreaData {}.build_detailed_address(street, line2, city, state),
+        }
+    }
+    fn gen_zip_code<R: Rng>(&self, rng: &mut R, hyphen: bool) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.gen_zip_code(rng, hyphen),
+            Locale::UnitedStates => UsaData {}.gen_zip_code(rng, hyphen),
+            Locale::Korea => KoreaData {}.gen_zip_code(rng, hyphen),
+        }
+    }
+    fn gen_domestic_phone_number<R: Rng>(&self, rng: &mut R, hyphen: bool) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.gen_domestic_phone_number(rng, hyphen),
+            Locale::UnitedStates => UsaData {}.gen_domestic_phone_number(rng, hyphen),
+            Locale::Korea => KoreaData {}.gen_domestic_phone_number(rng, hyphen),
+        }
+    }
+    fn calling_code(&self) -> &'static str {
+        match self.locale {
+            Locale::Japan => JapanData {}.calling_code(),
+            Locale::UnitedStates => UsaData {}.calling_code(),
+            Locale::Korea => KoreaData {}.calling_code(),
+        }
+    }
+    fn gen_phone_number<R: Rng>(
+        &self,
+        rng: &mut R,
+        kind: PhoneKind,
+        state: &str,
+        hyphen: bool,
+    ) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.gen_phone_number(rng, kind, state, hyphen),
+            Locale::UnitedStates => UsaData {}.gen_phone_number(rng, kind, state, hyphen),
+            Locale::Korea => KoreaData {}.gen_phone_number(rng, kind, state, hyphen),
+        }
+    }
+    fn gen_account_number<R: Rng>(&self, rng: &mut R) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.gen_account_number(rng),
+            Locale::UnitedStates => UsaData {}.gen_account_number(rng),
+            Locale::Korea => KoreaData {}.gen_account_number(rng),
+        }
+    }
+    fn isbn_group(&self) -> &'static [&'static str] {
+        match self.locale {
+            Locale::Japan => JapanData {}.isbn_group(),
+            Locale::UnitedStates => UsaData {}.isbn_group(),
+            Locale::Korea => KoreaData {}.isbn_group(),
+        }
+    }
+    fn state_area(&self) -> &'static [(&'static str, f64, f64, f64)] {
+        match self.locale {
+            Locale::Japan => JapanData {}.state_area(),
+            Locale::UnitedStates => UsaData {}.state_area(),
+            Locale::Korea => KoreaData {}.state_area(),
+        }
+    }
+    fn currency(&self) -> Currency {
+        match self.locale {
+            Locale::Japan => JapanData {}.currency(),
+            Locale::UnitedStates => UsaData {}.currency(),
+            Locale::Korea => KoreaData {}.currency(),
+        }
+    }
+    fn build_product_name(&self, adjective: &str, material: &str, noun: &str) -> String {
+        match self.locale {
+            Locale::Japan => JapanData {}.build_product_name(adjective, material, noun),
+            Locale::UnitedStates => UsaData {}.build_product_name(adjective, material, noun),
+            Locale::Korea => KoreaData {}.build_product_name(adjective, material, noun),
+        }
+    }
+}
+
+/// list of text data which each locale has.
+/// Display is the name of the file for the list in a locale pack.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum DataList {
+    Word,
+    Sentence,
+    Paragraph,
+    MaleFirstName,
+    FemaleFirstName,
+    LastName,
+    CreditCard,
+    Url,
+    WebWord,
+    UserAgent,
+    ColorName,
+    CompanySuffix,
+    CompanyName,
+    Industry,
+    Department,
+    JobTitle,
+    StreetName,
+    TownName,
+    CityName,
+    StateName,
+    CountryName,
+    CountryCode,
+    TimeZone,
+    Building,
+    Bank,
+    BankBranch,
+    AccountType,
+    ProductAdjective,
+    ProductMaterial,
+    ProductNoun,
+    ProductCategory,
+    Extension,
+}
+
+impl DataList {
+    pub fn all_list() -> Vec<Self> {
+        use DataList::*;
+        vec![
+            Word,
+            Sentence,
+            Paragraph,
+            MaleFirstName,
+            FemaleFirstName,
+            LastName,
+            CreditCard,
+            Url,
+            WebWord,
+            UserAgent,
+            ColorName,
+            CompanySuffix,
+            CompanyName,
+            Industry,
+            Department,
+            JobTitle,
+            StreetName,
+            TownName,
+            CityName,
+            StateName,
+            CountryName,
+            CountryCode,
+            TimeZone,
+            Building,
+            Bank,
+            BankBranch,
+            AccountType,
+            ProductAdjective,
+            ProductMaterial,
+            ProductNoun,
+            ProductCategory,
+            Extension,
+        ]
+    }
+}
+
+impl std::fmt::Display for DataList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use DataList::*;
+        let s: String = match self {
+            Word => "word",
+            Sentence => "sentence",
+            Paragraph => "paragraph",
+            MaleFirstName => "male_first_name",
+            FemaleFirstName => "female_first_name",
+            LastName => "last_name",
+            CreditCard => "credit_card",
+            Url => "url",
+            WebWord => "web_word",
+            UserAgent => "user_agent",
+            ColorName => "color_name",
+            CompanySuffix => "company_suffix",
+            CompanyName => "company_name",
+            Industry => "industry",
+            Department => "department",
+            JobTitle => "job_title",
+            StreetName => "street_name",
+            TownName => "town_name",
+            CityName => "city_name",
+            StateName => "state_name",
+            CountryName => "country_name",
+            CountryCode => "country_code",
+            TimeZone => "time_zone",
+            Building => "building",
+            Bank => "bank",
+            BankBranch => "bank_branch",
+            AccountType => "account_type",
+            ProductAdjective => "product_adjective",
+            ProductMaterial => "product_material",
+            ProductNoun => "product_noun",
+            ProductCategory => "product_category",
+            Extension => "extension",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// data source for generating.
+/// each locale's Data is used as Source through the instance.
+trait Source {
+    fn select<R: Rng>(&self, rng: &mut R, list: DataList) -> &str;
+    fn select_many<R: Rng>(
+        &self,
+        rng: &mut R,
+        list: DataList,
+        minimum: usize,
+        maximum: usize,
+    ) -> Vec<&str>;
+    fn ipv4(&self) -> &'static [&'static (u8, u8, u8)];
+    fn http_status_code(&self) -> &'static [&'static u16];
+    fn build_name(&self, last_name: &str, first_name: &str) -> String;
+    fn gender_label(&self, gender: Gender) -> &'static str;
+    // weight of (male, female)
+    fn gender_ratio(&self) -> (u32, u32);
+    // generator of the locale for With.Locale
+    fn locale_generator(&self, locale: Locale) -> Generator;
+    fn build_company_name(&self, name: &str, suffix: &str) -> String;
+    fn build_address(&self, street: &str, city: &str, state: &str) -> String;
+    fn gen_block_number<R: Rng>(&self, rng: &mut R) -> Vec<u16>;
+    fn format_block_number(&self, numbers: &[u16], style: AddressStyle) -> String;
+    fn build_street(&self, town: &str, block_number: &str) -> String;
+    fn gen_room_number<R: Rng>(&self, rng: &mut R) -> String;
+    fn build_address_line2(&self, building: &str, room_number: &str) -> String;
+    fn build_detailed_address(&self, street: &str, line2: &str, city: &str, state: &str) -> String;
+    fn gen_zip_code<R: Rng>(&self, rng: &mut R, hyphen: bool) -> String;
+    fn gen_domestic_phone_number<R: Rng>(&self, rng: &mut R, hyphen: bool) -> String;
+    fn calling_code(&self) -> &'static str;
+    fn gen_phone_number<R: Rng>(
+        &self,
+        rng: &mut R,
+        kind: PhoneKind,
+        state: &str,
+        hyphen: bool,
+    ) -> String;
+    fn gen_account_number<R: Rng>(&self, rng: &mut R) -> String;
+    fn isbn_group(&self) -> &'static [&'static str];
+    fn state_area(&self) -> &'static [(&'static str, f64, f64, f64)];
+    fn currency(&self) -> Currency;
+    fn build_product_name(&self, adjective: &str, material: &str, noun: &str) -> String;
+}
+
+impl<D: Data> Source for D {
+    fn select<R: Rng>(&self, rng: &mut R, list: DataList) -> &str {
+        select(rng, D::list(list))
+    }
+    fn select_many<R: Rng>(
+        &self,
+        rng: &mut R,
+        list: DataList,
+        minimum: usize,
+        maximum: usize,
+    ) -> Vec<&str> {
+        select_many(rng, D::list(list), minimum, maximum)
+    }
+    fn ipv4(&self) -> &'static [&'static (u8, u8, u8)] {
+        D::IPV4
+    }
+    fn http_status_code(&self) -> &'static [&'static u16] {
+        D::HTTP_STATUS_CODE
+    }
+    fn build_name(&self, last_name: &str, first_name: &str) -> String {
+        D::build_name(last_name, first_name)
+    }
+    fn gender_label(&self, gender: Gender) -> &'static str {
+        D::gender_label(gender)
+    }
+    fn gender_ratio(&self) -> (u32, u32) {
+        (1, 1)
+    }
+    fn locale_generator(&self, locale: Locale) -> Generator {
+        Generator::new(locale)
+    }
+    fn build_company_name(&self, name: &str, suffix: &str) -> String {
+        D::build_company_name(name, suffix)
+    }
+    fn build_address(&self, street: &str, city: &str, state: &str) -> String {
+        D::build_address(street, city, state)
+    }
+    fn gen_block_number<R: Rng>(&self, rng: &mut R) -> Vec<u16> {
+        D::gen_block_number(rng)
+    }
+    fn format_block_number(&self, numbers: &[u16], style: AddressStyle) -> String {
+        D::format_block_number(numbers, style)
+    }
+    fn build_street(&self, town: &str, block_number: &str) -> String {
+        D::build_street(town, block_number)
+    }
+    fn gen_room_number<R: Rng>(&self, rng: &mut R) -> String {
+        D::gen_room_number(rng)
+    }
+    fn build_address_line2(&self, building: &str, room_number: &str) -> String {
+        D::build_address_line2(building, room_number)
+    }
+    fn build_detailed_address(&self, street: &str, line2: &str, city: &str, state: &str) -> String {
+        D::build_detailed_address(street, line2, city, state)
+    }
+    fn gen_zip_code<R: Rng>(&self, rng: &mut R, hyphen: bool) -> String {
+        D::gen_zip_code(rng, hyphen)
+    }
+    fn gen_domestic_phone_number<R: Rng>(&self, rng: &mut R, hyphen: bool) -> String {
+        D::gen_domestic_phone_number(rng, hyphen)
+    }
+    fn calling_code(&self) -> &'static str {
+        D::CALLING_CODE
+    }
+    fn gen_phone_number<R: Rng>(
+        &self,
+        rng: &mut R,
+        kind: PhoneKind,
+        state: &str,
+        hyphen: bool,
+    ) -> String {
+        D::gen_phone_number(rng, kind, state, hyphen)
+    }
+    fn gen_account_number<R: Rng>(&self, rng: &mut R) -> String {
+        D::gen_account_number(rng)
+    }
+    fn isbn_group(&self) -> &'static [&'static str] {
+        D::ISBN_GROUP
+    }
+    fn state_area(&self) -> &'static [(&'static str, f64, f64, f64)] {
+        D::STATE_AREA
+    }
+    fn currency(&self) -> Currency {
+        D::CURRENCY
+    }
+    fn build_product_name(&self, adjective: &str, material: &str, noun: &str) -> String {
+        D::build_product_name(adjective, material, noun)
+    }
 }
 
-trait Rand: Data {
-    fn gen<R: Rng>(rng: &mut R, option: &FakeOption) -> String {
+trait Rand: Source {
+    fn gen<R: Rng>(&self, rng: &mut R, option: &FakeOption) -> String {
         match option {
             // With
             FakeOption::Join(sep, data) => {
                 return data
                     .iter()
-                    .map(|d| Self::gen(rng, d))
+                    .map(|d| self.gen(rng, d))
                     .collect::<Vec<String>>()
                     .join(sep);
             }
+            FakeOption::WithLocale(locale, option) => {
+                self.locale_generator(*locale).gen(rng, option)
+            }
 
             // Fixed Value
             FakeOption::FixedString(s) => {
@@ -79,70 +888,105 @@ trait Rand: Data {
                 )
                 .to_string();
             }
+            FakeOption::SelectFromFile(_, user_values)
+            | FakeOption::SelectFromColumn(_, _, user_values) => user_values
+                .choose(rng)
+                .expect("failed select data from empty list.")
+                .to_string(),
 
             // Lorem
             FakeOption::Word => {
-                return select(rng, Self::WORD).to_string();
+                return self.select(rng, DataList::Word).to_string();
             }
             FakeOption::Words(minimum, maximum) => {
-                return select_many(rng, Self::WORD, *minimum, *maximum).join(" ");
+                return self
+                    .select_many(rng, DataList::Word, *minimum, *maximum)
+                    .join(" ");
             }
             FakeOption::Sentence => {
-                return select(rng, Self::SENTENCE).to_string();
+                return self.select(rng, DataList::Sentence).to_string();
             }
             FakeOption::Sentences(minimum, maximum) => {
-                return select_many(rng, Self::SENTENCE, *minimum, *maximum).join(" ");
+                return self
+                    .select_many(rng, DataList::Sentence, *minimum, *maximum)
+                    .join(" ");
             }
             FakeOption::Paragraph => {
-                return select(rng, Self::PARAGRAPH).to_string();
+                return self.select(rng, DataList::Paragraph).to_string();
             }
             FakeOption::Paragraphs(minimum, maximum) => {
-                return select_many(rng, Self::PARAGRAPH, *minimum, *maximum).join("\n");
+                return self
+                    .select_many(rng, DataList::Paragraph, *minimum, *maximum)
+                    .join("\n");
             }
 
             // Name
-            FakeOption::FirstName(use_furigana) => {
-                let target: String = select(rng, Self::FIRST_NAME).to_string();
+            FakeOption::FirstName(use_furigana, script) => {
+                let gender: Gender = self.gen_gender(rng);
+                let (name, furigana): (String, String) = self.gen_first_name(rng, gender);
                 return if *use_furigana {
-                    target
+                    [name, script.convert(&furigana)].join(":")
                 } else {
-                    split(&target).0.to_string()
+                    name
                 };
             }
-            FakeOption::FirstNameFurigana => {
-                let (_, furigana): (String, String) = split(select(rng, Self::FIRST_NAME));
-                furigana
+            FakeOption::FirstNameFurigana(script) => {
+                let gender: Gender = self.gen_gender(rng);
+                let (_, furigana): (String, String) = self.gen_first_name(rng, gender);
+                script.convert(&furigana)
             }
-            FakeOption::LastName(use_furigana) => {
-                let target: String = select(rng, Self::LAST_NAME).to_string();
+            FakeOption::LastName(use_furigana, script) => {
+                let (name, furigana): (String, String) =
+                    split(self.select(rng, DataList::LastName));
                 return if *use_furigana {
-                    target
+                    [name, script.convert(&furigana)].join(":")
                 } else {
-                    split(&target).0.to_string()
+                    name
                 };
             }
-            FakeOption::LastNameFurigana => {
-                let (_, furigana): (String, String) = split(select(rng, Self::LAST_NAME));
-                furigana
+            FakeOption::LastNameFurigana(script) => {
+                let (_, furigana): (String, String) = split(self.select(rng, DataList::LastName));
+                script.convert(&furigana)
             }
-            FakeOption::FullName(use_furigana) => {
-                let first: (String, String) = split(select(rng, Self::FIRST_NAME));
-                let last: (String, String) = split(select(rng, Self::LAST_NAME));
+            FakeOption::FullName(use_furigana, script) => {
+                let gender: Gender = self.gen_gender(rng);
+                let first: (String, String) = self.gen_first_name(rng, gender);
+                let last: (String, String) = split(self.select(rng, DataList::LastName));
                 let name: (String, String) = (
-                    Self::build_name(&last.0, &first.0),
-                    Self::build_name(&last.1, &first.1),
+                    self.build_name(&last.0, &first.0),
+                    self.build_name(&last.1, &first.1),
                 );
                 return if *use_furigana {
-                    [name.0, name.1].join(":")
+                    [name.0, script.convert(&name.1)].join(":")
                 } else {
                     name.0
                 };
             }
-            FakeOption::FullNameFurigana => {
-                let first: (String, String) = split(select(rng, Self::FIRST_NAME));
-                let last: (String, String) = split(select(rng, Self::LAST_NAME));
-                let furigana: String = Self::build_name(&last.1, &first.1);
-                furigana
+            FakeOption::FullNameFurigana(script) => {
+                let gender: Gender = self.gen_gender(rng);
+                let first: (String, String) = self.gen_first_name(rng, gender);
+                let last: (String, String) = split(self.select(rng, DataList::LastName));
+                let furigana: String = self.build_name(&last.1, &first.1);
+                script.convert(&furigana)
+            }
+            FakeOption::FirstNameRomaji(capitalization) => {
+                let gender: Gender = self.gen_gender(rng);
+                let (_, furigana): (String, String) = self.gen_first_name(rng, gender);
+                romanize(&furigana, *capitalization)
+            }
+            FakeOption::LastNameRomaji(capitalization) => {
+                let (_, furigana): (String, String) = split(self.select(rng, DataList::LastName));
+                romanize(&furigana, *capitalization)
+            }
+            FakeOption::FullNameRomaji(capitalization) => {
+                let gender: Gender = self.gen_gender(rng);
+                let first: (String, String) = self.gen_first_name(rng, gender);
+                let last: (String, String) = split(self.select(rng, DataList::LastName));
+                romanize(&self.build_name(&last.1, &first.1), *capitalization)
+            }
+            FakeOption::Gender => {
+                let gender: Gender = self.gen_gender(rng);
+                self.gender_label(gender).to_string()
             }
 
             // Primitive
@@ -173,16 +1017,86 @@ trait Rand: Data {
             }
             FakeOption::UserName => gen_alpha_num_chars(rng, 4, 15),
             FakeOption::Password(minimum, maximum) => gen_password_chars(rng, *minimum, *maximum),
-            FakeOption::CreditCard => return select(rng, Self::CREDIT_CARD).to_string(),
+            FakeOption::PasswordWithPolicy(minimum, maximum, policy) => {
+                gen_chars_of_sets(rng, &policy.char_sets(), *minimum, *maximum)
+            }
+            FakeOption::PasswordHash(format) => {
+                let password: String =
+                    gen_chars_of_sets(rng, &PasswordPolicy::default().char_sets(), 8, 16);
+                self.gen_password_hash(rng, *format, &password)
+            }
+            FakeOption::CreditCard => return self.select(rng, DataList::CreditCard).to_string(),
+            FakeOption::CreditCardNumber(brand) => {
+                let mut digits: Vec<u8> = to_digits(select(rng, brand.test_prefixes()));
+                digits.extend(gen_digits(rng, brand.length() - digits.len() - 1));
+                digits.push(luhn_check_digit(&digits));
+                digits_to_string(&digits)
+            }
+            // from next month to five years later
+            FakeOption::CreditCardExpiry => {
+                let today: NaiveDate = Local::now().naive_local().date();
+                let months: u32 =
+                    today.year() as u32 * 12 + today.month0() + gen_range(rng, 1..=60);
+                format!("{:>02}/{:>02}", months % 12 + 1, months / 12 % 100)
+            }
+            FakeOption::CreditCardCvv(brand) => {
+                digits_to_string(&gen_digits(rng, brand.cvv_length()))
+            }
+            FakeOption::CreditCardHolder => {
+                let gender: Gender = self.gen_gender(rng);
+                let (_, first): (String, String) = self.gen_first_name(rng, gender);
+                let (_, last): (String, String) = split(self.select(rng, DataList::LastName));
+                card_holder(&first, &last)
+            }
             FakeOption::URL => {
-                let domain: String = select(rng, Self::URL).to_string();
+                let domain: String = self.select(rng, DataList::Url).to_string();
                 let first: String = gen_alpha_num_chars(rng, 1, 10);
                 let second: String = gen_alpha_num_chars(rng, 1, 10);
                 return format!("http://{}/{}/{}", domain, first, second);
             }
+            FakeOption::URLWith(format) => {
+                let domain: String = self.select(rng, DataList::Url).to_string();
+                let mut url: String = format!("{}://{}", format.scheme, domain);
+                if format.port {
+                    let port: u16 = [8000, 8080, 8443, 3000, 5000, 9000][gen_range(rng, 0..6)];
+                    url.push_str(&format!(":{}", port));
+                }
+                let depth: usize = format.depth.min(UrlFormat::MAX_COUNT);
+                let query: usize = format.query.min(UrlFormat::MAX_COUNT);
+                for _ in 0..depth {
+                    let segment: String = if format.encoded {
+                        percent_encode(self.select(rng, DataList::Word))
+                    } else {
+                        self.select(rng, DataList::WebWord).to_string()
+                    };
+                    url.push_str(&format!("/{}", segment));
+                }
+                if depth == 0 {
+                    url.push('/');
+                }
+                // keys are not duplicated while the count is within the keys
+                let keys: Vec<String> = select_many(rng, URL_QUERY_KEY, query, query)
+                    .iter()
+                    .map(|key| key.to_string())
+                    .collect();
+                let parameters: Vec<String> = (0..query)
+                    .map(|i| {
+                        let value: String = gen_alpha_num_chars(rng, 1, 8);
+                        format!("{}={}", keys[i % keys.len()], value)
+                    })
+                    .collect();
+                if !parameters.is_empty() {
+                    url.push_str(&format!("?{}", parameters.join("&")));
+                }
+                if format.fragment {
+                    let fragment: &str = self.select(rng, DataList::WebWord);
+                    url.push_str(&format!("#{}", fragment));
+                }
+                url
+            }
             FakeOption::IPv4 => {
                 let d: u8 = rng.gen();
-                let (a, b, c): &(u8, u8, u8) = select(rng, Self::IPV4);
+                let (a, b, c): &(u8, u8, u8) = select(rng, self.ipv4());
                 return Ipv4Addr::new(*a, *b, *c, d).to_string();
             }
             FakeOption::IPv6 => {
@@ -192,7 +1106,53 @@ trait Rand: Data {
                 let f: u16 = rng.gen();
                 let g: u16 = rng.gen();
                 let h: u16 = rng.gen();
-                return Ipv6Addr::new(2001_u16, 0xdb8, c, d, e, f, g, h).to_string();
+                return Ipv6Addr::new(0x2001, 0xdb8, c, d, e, f, g, h).to_string();
+            }
+            FakeOption::IPv4InRange(range, exclude) => {
+                let blocks: Vec<(Ipv4Addr, u8)> = range.ipv4_blocks();
+                let (network, prefix): (Ipv4Addr, u8) = blocks[gen_range(rng, 0..blocks.len())];
+                let bits: u32 = 32 - prefix.min(32) as u32;
+                let host: u32 = gen_host_part(rng, bits, *exclude) as u32;
+                ipv4_in_block(network, prefix, host).to_string()
+            }
+            FakeOption::IPv6InRange(range) => {
+                let blocks: Vec<(Ipv6Addr, u8)> = range.ipv6_blocks();
+                let (network, prefix): (Ipv6Addr, u8) = blocks[gen_range(rng, 0..blocks.len())];
+                let bits: u32 = 128 - prefix.min(128) as u32;
+                let host: u128 = gen_host_part(rng, bits, false);
+                ipv6_in_block(network, prefix, host).to_string()
+            }
+            // universally administered address has the bit 1 of the first octet 0, and the bit 0 is 0 for unicast.
+            FakeOption::MAC(style, local) => {
+                let mut octets: [u8; 6] = rng.gen();
+                octets[0] &= 0b1111_1100;
+                if *local {
+                    octets[0] |= 0b0000_0010;
+                }
+                style.format(&octets)
+            }
+            FakeOption::Hostname(with_domain) => {
+                let role: &str = [
+                    "web", "app", "api", "db", "cache", "mail", "proxy", "batch", "log", "auth",
+                ][gen_range(rng, 0..10)];
+                let host: String = format!("{}-{:>02}", role, gen_range(rng, 1..=99));
+                if *with_domain {
+                    format!("{}.{}", host, self.gen_domain_name(rng))
+                } else {
+                    host
+                }
+            }
+            FakeOption::DomainName => self.gen_domain_name(rng),
+            FakeOption::Port(range) => {
+                let (min, max): (u16, u16) = range.min_max();
+                gen_range(rng, min..=max).to_string()
+            }
+            FakeOption::Slug => {
+                let count: usize = gen_range(rng, 2..=5);
+                (0..count)
+                    .map(|_| self.select(rng, DataList::WebWord).to_string())
+                    .collect::<Vec<String>>()
+                    .join("-")
             }
             FakeOption::RGB => {
                 let r: u8 = rng.gen();
@@ -207,67 +1167,167 @@ trait Rand: Data {
                 let a: u8 = rng.gen();
                 return format!("#{:>02X}{:>02X}{:>02X}{:>02X}", r, g, b, a);
             }
+            FakeOption::Color(format, tone) => {
+                let (s_min, s_max): (u8, u8) = tone.saturation();
+                let (l_min, l_max): (u8, u8) = tone.lightness();
+                let hue: u16 = gen_range(rng, 0..360);
+                let saturation: u8 = gen_range(rng, s_min..=s_max);
+                let lightness: u8 = gen_range(rng, l_min..=l_max);
+                format.format(hue, saturation, lightness)
+            }
+            FakeOption::ColorName(with_hex) => {
+                let color: &str = self.select(rng, DataList::ColorName);
+                Self::color_name(color, *with_hex)
+            }
+            FakeOption::CssColorName(with_hex) => {
+                Self::color_name(select(rng, CSS_COLOR_NAME), *with_hex)
+            }
             FakeOption::UserAgent => {
-                return select(rng, Self::USER_AGENT).to_string();
+                return self.select(rng, DataList::UserAgent).to_string();
             }
+            FakeOption::UserAgentWith(format) => self.gen_user_agent(rng, format),
+            FakeOption::AccessLog(format) => self.gen_access_log(rng, format, Local::now()),
             FakeOption::StatusCode => {
-                return format!("{}", select(rng, Self::HTTP_STATUS_CODE));
+                return format!("{}", select(rng, self.http_status_code()));
             }
 
             // Company
             FakeOption::CompanySuffix => {
-                return select(rng, Self::COMPANY_SUFFIX).to_string();
-            }
-            FakeOption::CompanyName => {
-                let name: String = select(rng, Self::COMPANY_NAME).to_string();
-                let suffix: String = select(rng, Self::COMPANY_SUFFIX).to_string();
-                return [name, suffix].join("");
+                return self.select(rng, DataList::CompanySuffix).to_string();
             }
+            FakeOption::CompanyName => self.gen_company(rng).0,
             FakeOption::Industry => {
-                return select(rng, Self::INDUSTRY).to_string();
+                return self.select(rng, DataList::Industry).to_string();
+            }
+            FakeOption::CorporateNumber => {
+                let base: Vec<u8> = gen_digits(rng, 12);
+                let check_digit: u8 = corporate_number_check_digit(&base);
+                format!("{}{}", check_digit, digits_to_string(&base))
+            }
+            FakeOption::IndividualNumber => {
+                let base: Vec<u8> = gen_digits(rng, 11);
+                let check_digit: u8 = individual_number_check_digit(&base);
+                format!("{}{}", digits_to_string(&base), check_digit)
+            }
+            FakeOption::Department => self.select(rng, DataList::Department).to_string(),
+            FakeOption::JobTitle => self.select(rng, DataList::JobTitle).to_string(),
+            FakeOption::EmployeeNumber(pattern) => gen_by_pattern(rng, pattern),
+            FakeOption::CompanyDomain => self.gen_company(rng).1,
+            FakeOption::EmployeeEmail => {
+                let gender: Gender = self.gen_gender(rng);
+                let (_, first_name): (String, String) = self.gen_first_name(rng, gender);
+                let (_, last_name): (String, String) = split(self.select(rng, DataList::LastName));
+                let (_, domain): (String, String) = self.gen_company(rng);
+                self.build_employee_email(&first_name, &last_name, &domain)
             }
 
             // Address
             FakeOption::Building => {
-                return select(rng, Self::BUILDING).to_string();
+                return self.select(rng, DataList::Building).to_string();
             }
             FakeOption::StreetName => {
-                return select(rng, Self::STREET_NAME).to_string();
+                return self.select(rng, DataList::StreetName).to_string();
             }
             FakeOption::CityName => {
-                return select(rng, Self::CITY_NAME).to_string();
-            }
-            FakeOption::StateName => {
-                return select(rng, Self::STATE_NAME).to_string();
+                return self.select(rng, DataList::CityName).to_string();
             }
             FakeOption::CountryName => {
-                return select(rng, Self::COUNTRY_NAME).to_string();
+                return self.select(rng, DataList::CountryName).to_string();
             }
             FakeOption::CountryCode => {
-                return select(rng, Self::COUNTRY_CODE).to_string();
+                return self.select(rng, DataList::CountryCode).to_string();
             }
             FakeOption::TimeZone => {
-                return select(rng, Self::TIME_ZONE).to_string();
+                return self.select(rng, DataList::TimeZone).to_string();
             }
-            FakeOption::Address => {
-                let street: String = select(rng, Self::STREET_NAME).to_string();
-                let city: String = select(rng, Self::CITY_NAME).to_string();
-                let state: String = select(rng, Self::STATE_NAME).to_string();
-                return Self::build_address(&street, &city, &state);
+            FakeOption::StateName
+            | FakeOption::Address
+            | FakeOption::DetailedAddress(_)
+            | FakeOption::AddressLine1(_)
+            | FakeOption::PhoneNumber(_, _)
+            | FakeOption::InternationalPhoneNumber(_) => {
+                let state: String = self.select(rng, DataList::StateName).to_string();
+                self.gen_in_state(rng, option, &state)
+            }
+            FakeOption::AddressLine2 => {
+                let address: AddressParts = self.gen_address_parts(rng);
+                self.format_address(option, &address, "")
             }
             FakeOption::ZipCode(hyphen) => {
-                return Self::gen_zip_code(rng, *hyphen);
+                return self.gen_zip_code(rng, *hyphen);
             }
             FakeOption::DomesticPhoneNumber(hyphen) => {
-                return Self::gen_domestic_phone_number(rng, *hyphen);
+                return self.gen_domestic_phone_number(rng, *hyphen);
+            }
+            FakeOption::Latitude => format_latitude(self.gen_point(rng, GeoArea::Globe, None).0),
+            FakeOption::Longitude => format_longitude(self.gen_point(rng, GeoArea::Globe, None).1),
+            FakeOption::LatitudeIn(area)
+            | FakeOption::LongitudeIn(area)
+            | FakeOption::Coordinates(area) => {
+                let point: (f64, f64) = self.gen_point(rng, *area, None);
+                self.format_point(option, point)
+            }
+
+            // Finance
+            FakeOption::BankName => self.gen_bank(rng).0,
+            FakeOption::BankCode => self.gen_bank(rng).1,
+            FakeOption::BranchName => self.gen_bank_branch(rng).0,
+            FakeOption::BranchCode => self.gen_bank_branch(rng).1,
+            FakeOption::AccountType => self.select(rng, DataList::AccountType).to_string(),
+            FakeOption::AccountNumber => self.gen_account_number(rng),
+            FakeOption::AccountHolder => {
+                let gender: Gender = self.gen_gender(rng);
+                let (_, first): (String, String) = self.gen_first_name(rng, gender);
+                let (_, last): (String, String) = split(self.select(rng, DataList::LastName));
+                zengin_kana(&self.build_name(&last, &first))
+            }
+
+            // Commerce
+            FakeOption::JAN => {
+                let prefix: &str = ["45", "49"][gen_range(rng, 0..2)];
+                self.gen_gtin(rng, prefix, 13)
+            }
+            FakeOption::EAN8 => {
+                let prefix: &str = ["45", "49"][gen_range(rng, 0..2)];
+                self.gen_gtin(rng, prefix, 8)
+            }
+            // number system digit of regular products
+            FakeOption::UPC => {
+                let prefix: &str = ["0", "1", "6", "7", "8"][gen_range(rng, 0..5)];
+                self.gen_gtin(rng, prefix, 12)
+            }
+            FakeOption::ISBN10 => {
+                let group: &str = select(rng, self.isbn_group());
+                let mut base: Vec<u8> = to_digits(group);
+                base.extend(gen_digits(rng, 9 - base.len()));
+                match isbn10_check_digit(&base) {
+                    10 => format!("{}X", digits_to_string(&base)),
+                    check_digit => format!("{}{}", digits_to_string(&base), check_digit),
+                }
             }
-            FakeOption::Latitude => {
-                let diff: f64 = 180 as f64 * gen_fraction_part(rng);
-                return format!("{:<+010.6}", -90 as f64 + diff);
+            FakeOption::ISBN13 => {
+                let prefix: String = format!("978{}", select(rng, self.isbn_group()));
+                self.gen_gtin(rng, &prefix, 13)
             }
-            FakeOption::Longitude => {
-                let diff: f64 = 360 as f64 * gen_fraction_part(rng);
-                return format!("{:<+011.6}", -90 as f64 + diff);
+            FakeOption::ProductName => {
+                let adjective: String = self.select(rng, DataList::ProductAdjective).to_string();
+                let material: String = self.select(rng, DataList::ProductMaterial).to_string();
+                let noun: String = self.select(rng, DataList::ProductNoun).to_string();
+                self.build_product_name(&adjective, &material, &noun)
+            }
+            FakeOption::ProductCategory => self.select(rng, DataList::ProductCategory).to_string(),
+            FakeOption::Sku(pattern) => gen_by_pattern(rng, pattern),
+            FakeOption::Price(currency, range) => {
+                let currency: Currency = self.resolve_currency(rng, *currency);
+                self.gen_price(rng, currency, *range)
+            }
+            FakeOption::CurrencyCode(currency) => {
+                self.resolve_currency(rng, *currency).code().to_string()
+            }
+            FakeOption::Quantity(from, to) => {
+                // the smaller of two makes smaller quantity more likely
+                let quantity: usize = gen_range(rng, *from..=*to).min(gen_range(rng, *from..=*to));
+                quantity.to_string()
             }
 
             // DateTime
@@ -309,14 +1369,411 @@ trait Rand: Data {
             // FileSystem
             FakeOption::FileName => {
                 let filename: String = gen_alpha_num_chars(rng, 3, 15);
-                let ext: String = select(rng, Self::EXTENSION).to_string();
+                let ext: String = self.select(rng, DataList::Extension).to_string();
                 return [filename, ext].join(".");
             }
             FakeOption::Extension => {
-                return select(rng, Self::EXTENSION).to_string();
+                return self.select(rng, DataList::Extension).to_string();
+            }
+        }
+    }
+
+    // gender is selected by weight of gender ratio. If both weights are 0, use the same weight.
+    fn gen_gender<R: Rng>(&self, rng: &mut R) -> Gender {
+        let (male, female): (u64, u64) = match self.gender_ratio() {
+            (0, 0) => (1, 1),
+            (male, female) => (male as u64, female as u64),
+        };
+        if gen_range(rng, 0..male + female) < male {
+            Gender::Male
+        } else {
+            Gender::Female
+        }
+    }
+
+    fn gen_first_name<R: Rng>(&self, rng: &mut R, gender: Gender) -> (String, String) {
+        let list: DataList = match gender {
+            Gender::Male => DataList::MaleFirstName,
+            Gender::Female => DataList::FemaleFirstName,
+        };
+        split(self.select(rng, list))
+    }
+
+    // data in the state such as address and landline phone number
+    fn gen_in_state<R: Rng>(&self, rng: &mut R, option: &FakeOption, state: &str) -> String {
+        match option {
+            FakeOption::StateName => state.to_string(),
+            FakeOption::Address => {
+                let street: String = self.select(rng, DataList::StreetName).to_string();
+                let city: String = self.select(rng, DataList::CityName).to_string();
+                self.build_address(&street, &city, state)
+            }
+            FakeOption::DetailedAddress(_) | FakeOption::AddressLine1(_) => {
+                let address: AddressParts = self.gen_address_parts(rng);
+                self.format_address(option, &address, state)
+            }
+            FakeOption::PhoneNumber(kind, hyphen) => {
+                self.gen_phone_number(rng, *kind, state, *hyphen)
+            }
+            // E.164 is calling code and number without trunk prefix 0
+            FakeOption::InternationalPhoneNumber(kind) => {
+                let number: String = self.gen_phone_number(rng, *kind, state, false);
+                let number: &str = number.strip_prefix('0').unwrap_or(&number);
+                format!("+{}{}", self.calling_code(), number)
+            }
+            _ => self.gen(rng, option),
+        }
+    }
+
+    // parts of the address except the state
+    fn gen_address_parts<R: Rng>(&self, rng: &mut R) -> AddressParts {
+        AddressParts {
+            city: self.select(rng, DataList::CityName).to_string(),
+            town: self.select(rng, DataList::TownName).to_string(),
+            block_number: self.gen_block_number(rng),
+            building: self.select(rng, DataList::Building).to_string(),
+            room_number: self.gen_room_number(rng),
+        }
+    }
+
+    // DetailedAddress, AddressLine1 or AddressLine2 of the parts.
+    // street is town with block number, and address line 2 is building with room number.
+    fn format_address(&self, option: &FakeOption, address: &AddressParts, state: &str) -> String {
+        let line2: String = self.build_address_line2(&address.building, &address.room_number);
+        let style: AddressStyle = match option {
+            FakeOption::DetailedAddress(style) | FakeOption::AddressLine1(style) => *style,
+            _ => return line2,
+        };
+        let block_number: String = self.format_block_number(&address.block_number, style);
+        let street: String = self.build_street(&address.town, &block_number);
+        match option {
+            FakeOption::DetailedAddress(_) => {
+                self.build_detailed_address(&street, &line2, &address.city, state)
+            }
+            _ => self.build_address(&street, &address.city, state),
+        }
+    }
+
+    // point in the area. area of state is of the state, or of a state of the country if unknown.
+    fn gen_point<R: Rng>(&self, rng: &mut R, area: GeoArea, state: Option<&str>) -> (f64, f64) {
+        let areas: &[(&str, f64, f64, f64)] = self.state_area();
+        match area {
+            GeoArea::Globe => gen_point_in_box(rng, -90.0, -180.0, 90.0, 180.0),
+            GeoArea::BoundingBox(south, west, north, east) => gen_point_in_box(
+                rng,
+                degree(south),
+                degree(west),
+                degree(north),
+                degree(east),
+            ),
+            GeoArea::Circle(latitude, longitude, radius) => gen_point_in_circle(
+                rng,
+                degree(latitude),
+                degree(longitude),
+                radius as f64 / 1000.0,
+            ),
+            GeoArea::Country | GeoArea::State => {
+                let (_, latitude, longitude, radius): (&str, f64, f64, f64) =
+                    match areas.iter().find(|(s, _, _, _)| Some(*s) == state) {
+                        Some(area) => *area,
+                        None => *areas.choose(rng).unwrap(),
+                    };
+                gen_point_in_circle(rng, latitude, longitude, radius)
+            }
+        }
+    }
+
+    // latitude, longitude or "latitude,longitude" of the point
+    fn format_point(&self, option: &FakeOption, point: (f64, f64)) -> String {
+        match option {
+            FakeOption::LatitudeIn(_) => format_latitude(point.0),
+            FakeOption::LongitudeIn(_) => format_longitude(point.1),
+            _ => format!("{},{}", format_latitude(point.0), format_longitude(point.1)),
+        }
+    }
+
+    // domain is the reading of the name on the reserved domain such as acme.example.com
+    fn gen_company<R: Rng>(&self, rng: &mut R) -> (String, String) {
+        let (name, reading): (String, String) = split(self.select(rng, DataList::CompanyName));
+        let suffix: String = self.select(rng, DataList::CompanySuffix).to_string();
+        let mut label: String = ascii_label(&romanize(&reading, Capitalization::Lower));
+        if label.is_empty() {
+            label = self.select(rng, DataList::WebWord).to_string();
+        }
+        (
+            self.build_company_name(&name, &suffix),
+            format!("{}.{}", label, select(rng, RESERVED_DOMAIN)),
+        )
+    }
+
+    // such as taro.yamada@acme.example.com
+    fn build_employee_email(&self, first_name: &str, last_name: &str, domain: &str) -> String {
+        format!(
+            "{}.{}@{}",
+            ascii_label(&romanize(first_name, Capitalization::Lower)),
+            ascii_label(&romanize(last_name, Capitalization::Lower)),
+            domain
+        )
+    }
+
+    fn gen_bank<R: Rng>(&self, rng: &mut R) -> (String, String) {
+        split(self.select(rng, DataList::Bank))
+    }
+
+    fn gen_bank_branch<R: Rng>(&self, rng: &mut R) -> (String, String) {
+        split(self.select(rng, DataList::BankBranch))
+    }
+
+    // name of "name:#hex" such as "Crimson", or "Crimson:#DC143C" with hex
+    fn color_name(color: &str, with_hex: bool) -> String {
+        let (name, hex): (String, String) = split(color);
+        if with_hex {
+            [name, hex].join(":")
+        } else {
+            name
+        }
+    }
+
+    // user agent of a browser and a platform in the candidates of the format, or of a bot
+    fn gen_user_agent<R: Rng>(&self, rng: &mut R, format: &UserAgentFormat) -> String {
+        let (browser, platform): (Browser, Platform) = match format.candidates().choose(rng) {
+            Some(candidate) if format.device != DeviceType::Bot => *candidate,
+            _ => {
+                return format!("Mozilla/5.0 (compatible; {})", select(rng, BOT_AGENT));
+            }
+        };
+        let (from, to): (usize, usize) = format.version.unwrap_or_else(|| browser.versions());
+        let version: usize = gen_range(rng, from..=to);
+        // such as 120.0.6099.109 of chromium
+        let build: String = format!(
+            "{}.0.{}.{}",
+            version,
+            gen_range(rng, 1000..7000),
+            gen_range(rng, 0..200)
+        );
+        let ios: String = match browser {
+            Browser::Safari => format!("{}_{}", version, gen_range(rng, 0..=6)),
+            _ => format!("{}_{}", gen_range(rng, 15..=18), gen_range(rng, 0..=6)),
+        };
+        let android: String = format!(
+            "{}; {}",
+            gen_range(rng, 11..=15),
+            select(rng, ANDROID_MODEL)
+        );
+        let system: String = match platform {
+            Platform::Windows => "Windows NT 10.0; Win64; x64".to_string(),
+            Platform::MacOs => "Macintosh; Intel Mac OS X 10_15_7".to_string(),
+            Platform::Linux => "X11; Linux x86_64".to_string(),
+            Platform::Android => format!("Linux; Android {}", android),
+            Platform::Ios | Platform::Any => format!("iPhone; CPU iPhone OS {} like Mac OS X", ios),
+        };
+        let mobile: &str = if platform == Platform::Android {
+            "Mobile "
+        } else {
+            ""
+        };
+        let chromium: String = format!(
+            "Mozilla/5.0 ({}) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{} {}Safari/537.36",
+            system, build, mobile
+        );
+        let webkit: String = format!(
+            "Mozilla/5.0 ({}) AppleWebKit/605.1.15 (KHTML, like Gecko)",
+            system
+        );
+        match (browser, platform) {
+            (Browser::Firefox, Platform::Ios) => {
+                format!(
+                    "{} FxiOS/{}.0 Mobile/15E148 Safari/605.1.15",
+                    webkit, version
+                )
+            }
+            (Browser::Firefox, Platform::Android) => format!(
+                "Mozilla/5.0 (Android {}; Mobile; rv:{}.0) Gecko/{}.0 Firefox/{}.0",
+                android, version, version, version
+            ),
+            (Browser::Firefox, _) => format!(
+                "Mozilla/5.0 ({}; rv:{}.0) Gecko/20100101 Firefox/{}.0",
+                system.replace("10_15_7", "10.15"),
+                version,
+                version
+            ),
+            (Browser::Safari, Platform::Ios) => format!(
+                "{} Version/{} Mobile/15E148 Safari/604.1",
+                webkit,
+                ios.replace('_', ".")
+            ),
+            (Browser::Safari, _) => format!(
+                "{} Version/{}.{} Safari/605.1.15",
+                webkit,
+                version,
+                gen_range(rng, 0..=6)
+            ),
+            (Browser::Chrome, Platform::Ios) => {
+                format!("{} CriOS/{} Mobile/15E148 Safari/604.1", webkit, build)
+            }
+            (Browser::Edge, Platform::Ios) => {
+                format!("{} EdgiOS/{} Mobile/15E148 Safari/605.1.15", webkit, build)
             }
+            (Browser::Edge, Platform::Android) => format!("{} EdgA/{}", chromium, build),
+            (Browser::Edge, _) => format!("{} Edg/{}", chromium, build),
+            _ => chromium,
         }
     }
+
+    // request of weighted method and status from IPv4 of documentation, with referer of safe URL
+    fn gen_access_log<R: Rng>(
+        &self,
+        rng: &mut R,
+        format: &AccessLogFormat,
+        time: DateTime<Local>,
+    ) -> String {
+        let method: &str = HTTP_METHOD.choose_weighted(rng, |(_, w)| *w).unwrap().0;
+        let status: u16 = **self
+            .http_status_code()
+            .choose_weighted(rng, |code| http_status_weight(**code))
+            .unwrap();
+        let depth: usize = gen_range(rng, 0..=3);
+        let mut path: String = (0..depth)
+            .map(|_| format!("/{}", self.select(rng, DataList::WebWord)))
+            .collect();
+        if path.is_empty() {
+            path.push('/');
+        } else if rng.gen_bool(0.3) {
+            path = format!("{}.{}", path, select(rng, STATIC_EXTENSION));
+        }
+        let query: String = if rng.gen_bool(0.2) {
+            let key: String = select(rng, URL_QUERY_KEY).to_string();
+            format!("?{}={}", key, gen_range(rng, 1..=100))
+        } else {
+            String::new()
+        };
+        let size: usize = match status {
+            _ if method == "HEAD" => 0,
+            204 | 304 => 0,
+            300..=399 => gen_range(rng, 0..=300),
+            400..=599 => gen_range(rng, 150..=1000),
+            _ => gen_range(rng, 200..=100_000),
+        };
+        let referer: Option<String> = if rng.gen_bool(0.4) {
+            let format: UrlFormat = UrlFormat {
+                scheme: UrlScheme::Https,
+                depth: gen_range(rng, 0..=2),
+                ..UrlFormat::default()
+            };
+            Some(self.gen(rng, &FakeOption::URLWith(format)))
+        } else {
+            None
+        };
+        let device: DeviceType = if rng.gen_bool(0.05) {
+            DeviceType::Bot
+        } else {
+            DeviceType::Any
+        };
+        let user_agent: String = self.gen_user_agent(
+            rng,
+            &UserAgentFormat {
+                device,
+                ..UserAgentFormat::default()
+            },
+        );
+        let forwarded_for: Option<String> = if rng.gen_bool(0.3) {
+            Some(self.gen(rng, &FakeOption::IPv4InRange(IpRange::Private, true)))
+        } else {
+            None
+        };
+        let user: Option<String> = if rng.gen_bool(0.1) {
+            Some(self.gen(rng, &FakeOption::UserName))
+        } else {
+            None
+        };
+        let entry: AccessLogEntry = AccessLogEntry {
+            remote_addr: self.gen(rng, &FakeOption::IPv4),
+            user,
+            time,
+            method: method.to_string(),
+            path,
+            query,
+            protocol: select(rng, &["HTTP/1.1", "HTTP/1.1", "HTTP/2.0"]).to_string(),
+            status,
+            size,
+            referer,
+            user_agent,
+            forwarded_for,
+            duration: gen_range(rng, 500..=500_000),
+        };
+        entry.format(format.layout())
+    }
+
+    // hex of SHA-256 or SHA-256 crypt with random salt of 16 characters
+    fn gen_password_hash<R: Rng>(&self, rng: &mut R, format: HashFormat, password: &str) -> String {
+        match format {
+            HashFormat::Sha256 => sha256_hex(password.as_bytes()),
+            HashFormat::Sha256Crypt => {
+                let salt: String = (0..16)
+                    .map(|_| *CRYPT_CHAR.as_bytes().choose(rng).unwrap() as char)
+                    .collect();
+                sha256_crypt(password, &salt)
+            }
+        }
+    }
+
+    // words of WEB_WORD and the reserved domain of RFC 2606 such as blue-sky.example
+    fn gen_domain_name<R: Rng>(&self, rng: &mut R) -> String {
+        let count: usize = gen_range(rng, 1..=2);
+        let name: String = (0..count)
+            .map(|_| self.select(rng, DataList::WebWord).to_string())
+            .collect::<Vec<String>>()
+            .join("-");
+        format!("{}.{}", name, select(rng, RESERVED_DOMAIN))
+    }
+
+    // currency of the locale for Local, and one of the currencies which have the code for Any
+    fn resolve_currency<R: Rng>(&self, rng: &mut R, currency: Currency) -> Currency {
+        match currency {
+            Currency::Local => self.currency(),
+            Currency::Any => *Currency::code_list().choose(rng).unwrap(),
+            _ => currency,
+        }
+    }
+
+    // price in the range of the major unit which is a multiple of the step of the currency
+    fn gen_price<R: Rng>(
+        &self,
+        rng: &mut R,
+        currency: Currency,
+        range: Option<(usize, usize)>,
+    ) -> String {
+        let (from, to): (usize, usize) = range.unwrap_or_else(|| currency.price_band());
+        let scale: u64 = 10u64.pow(currency.decimals() as u32);
+        // scanner rejects the range which is too large
+        let (from, to): (u64, u64) = currency
+            .minor_range(from, to)
+            .unwrap_or((u64::MAX, u64::MAX));
+        // without step if the range has no multiple of it such as 1 to 5 yen
+        let step: u64 = match currency.step() {
+            step if from.div_ceil(step) <= to / step => step,
+            _ => 1,
+        };
+        let price: u64 = gen_range(rng, from.div_ceil(step)..=to / step) * step;
+        if currency.decimals() == 0 {
+            price.to_string()
+        } else {
+            format!(
+                "{}.{:0width$}",
+                price / scale,
+                price % scale,
+                width = currency.decimals()
+            )
+        }
+    }
+
+    // number of the length which starts with the prefix and ends with check digit of GTIN
+    fn gen_gtin<R: Rng>(&self, rng: &mut R, prefix: &str, length: usize) -> String {
+        let mut base: Vec<u8> = to_digits(prefix);
+        base.extend(gen_digits(rng, length - 1 - base.len()));
+        let check_digit: u8 = gtin_check_digit(&base);
+        format!("{}{}", digits_to_string(&base), check_digit)
+    }
 }
 
 trait Data {
@@ -330,8 +1787,12 @@ trait Data {
     // If you set the furigana, use target as furigana.
     // Name data is combine FIRST_NAME and LAST_NAME
     fn build_name(last_name: &str, first_name: &str) -> String;
-    const FIRST_NAME: &'static [&'static str];
+    // FIRST_NAME is MALE_FIRST_NAME or FEMALE_FIRST_NAME
+    const MALE_FIRST_NAME: &'static [&'static str];
+    const FEMALE_FIRST_NAME: &'static [&'static str];
     const LAST_NAME: &'static [&'static str];
+    // label of gender for Name.Gender
+    fn gender_label(gender: Gender) -> &'static str;
 
     // Internet
     const CREDIT_CARD: &'static [&'static str] = &[
@@ -397,16 +1858,33 @@ trait Data {
         &501_u16, &502_u16, &503_u16, &504_u16, &505_u16, &506_u16, &507_u16, &508_u16, &510_u16,
         &511_u16,
     ];
+    // ascii words for domain name, host name and slug
+    const WEB_WORD: &'static [&'static str] = &[
+        "alpha", "apple", "blue", "bridge", "cloud", "coral", "data", "delta", "echo", "field",
+        "forest", "garden", "green", "harbor", "idea", "jet", "kite", "lab", "light", "link",
+        "maple", "moon", "net", "next", "ocean", "open", "pixel", "quick", "river", "sky", "smart",
+        "spark", "star", "stone", "sun", "tech", "town", "wave", "wind", "zen",
+    ];
+    // name of color and its hex such as "Crimson:#DC143C"
+    const COLOR_NAME: &'static [&'static str];
 
     // Compnay
     const COMPANY_SUFFIX: &'static [&'static str];
     const COMPANY_NAME: &'static [&'static str];
     const INDUSTRY: &'static [&'static str];
+    const DEPARTMENT: &'static [&'static str];
+    // from the top executive to the staff
+    const JOB_TITLE: &'static [&'static str];
+    fn build_company_name(name: &str, suffix: &str) -> String {
+        [name, suffix].join("")
+    }
 
     // Address
     const STREET_NAME: &'static [&'static str];
     const CITY_NAME: &'static [&'static str];
     const STATE_NAME: &'static [&'static str];
+    // circle of (state, latitude, longitude, radius in km) on land of the state
+    const STATE_AREA: &'static [(&'static str, f64, f64, f64)];
     const COUNTRY_NAME: &'static [&'static str] = &[
         "Aruba",
         "Afghanistan",
@@ -775,8 +2253,45 @@ trait Data {
     ];
     const BUILDING: &'static [&'static str];
     fn build_address(street: &str, city: &str, state: &str) -> String;
+    // town without block number such as 青柳 of 加茂町青柳7-13-9
+    const TOWN_NAME: &'static [&'static str];
+    // numbers of block number such as [1, 2, 3]
+    fn gen_block_number<R: Rng>(rnd: &mut R) -> Vec<u16>;
+    // block number of the style such as 1-2-3 and 1丁目2番3号
+    fn format_block_number(numbers: &[u16], style: AddressStyle) -> String;
+    fn build_street(town: &str, block_number: &str) -> String;
+    fn gen_room_number<R: Rng>(rnd: &mut R) -> String;
+    // address line 2 is combine BUILDING and room number
+    fn build_address_line2(building: &str, room_number: &str) -> String;
+    // address with address line 2
+    fn build_detailed_address(street: &str, line2: &str, city: &str, state: &str) -> String;
     fn gen_zip_code<R: Rng>(rnd: &mut R, hyphen: bool) -> String;
     fn gen_domestic_phone_number<R: Rng>(rnd: &mut R, hyphen: bool) -> String;
+    // country calling code for E.164 such as 81
+    const CALLING_CODE: &'static str;
+    // phone number of the kind. area code of landline is of the state.
+    fn gen_phone_number<R: Rng>(rnd: &mut R, kind: PhoneKind, state: &str, hyphen: bool) -> String;
+
+    // Finance
+    // bank data is made from name:code such as みずほ銀行:0001
+    const BANK: &'static [&'static str];
+    // branch data is made from name:code such as 本店営業部:100
+    const BANK_BRANCH: &'static [&'static str];
+    const ACCOUNT_TYPE: &'static [&'static str];
+    fn gen_account_number<R: Rng>(rnd: &mut R) -> String;
+
+    // Commerce
+    // registration group of ISBN such as 4 for Japan
+    const ISBN_GROUP: &'static [&'static str];
+    // currency of Currency::Local
+    const CURRENCY: Currency;
+    const PRODUCT_ADJECTIVE: &'static [&'static str];
+    const PRODUCT_MATERIAL: &'static [&'static str];
+    const PRODUCT_NOUN: &'static [&'static str];
+    fn build_product_name(adjective: &str, material: &str, noun: &str) -> String {
+        [adjective, material, noun].join(" ")
+    }
+    const PRODUCT_CATEGORY: &'static [&'static str];
 
     // FileSystem
     const EXTENSION: &'static [&'static str] = &[
@@ -795,6 +2310,111 @@ trait Data {
         "scr", "url", "csv", "cnf", "conf", "cf", "log", "dat", "bak", "bin", "dic", "old", "org",
         "tmp",
     ];
+
+    fn list(list: DataList) -> &'static [&'static str] {
+        match list {
+            DataList::Word => Self::WORD,
+            DataList::Sentence => Self::SENTENCE,
+            DataList::Paragraph => Self::PARAGRAPH,
+            DataList::MaleFirstName => Self::MALE_FIRST_NAME,
+            DataList::FemaleFirstName => Self::FEMALE_FIRST_NAME,
+            DataList::LastName => Self::LAST_NAME,
+            DataList::CreditCard => Self::CREDIT_CARD,
+            DataList::Url => Self::URL,
+            DataList::WebWord => Self::WEB_WORD,
+            DataList::UserAgent => Self::USER_AGENT,
+            DataList::ColorName => Self::COLOR_NAME,
+            DataList::CompanySuffix => Self::COMPANY_SUFFIX,
+            DataList::CompanyName => Self::COMPANY_NAME,
+            DataList::Industry => Self::INDUSTRY,
+            DataList::Department => Self::DEPARTMENT,
+            DataList::JobTitle => Self::JOB_TITLE,
+            DataList::StreetName => Self::STREET_NAME,
+            DataList::TownName => Self::TOWN_NAME,
+            DataList::CityName => Self::CITY_NAME,
+            DataList::StateName => Self::STATE_NAME,
+            DataList::CountryName => Self::COUNTRY_NAME,
+            DataList::CountryCode => Self::COUNTRY_CODE,
+            DataList::TimeZone => Self::TIME_ZONE,
+            DataList::Building => Self::BUILDING,
+            DataList::Bank => Self::BANK,
+            DataList::BankBranch => Self::BANK_BRANCH,
+            DataList::AccountType => Self::ACCOUNT_TYPE,
+            DataList::ProductAdjective => Self::PRODUCT_ADJECTIVE,
+            DataList::ProductMaterial => Self::PRODUCT_MATERIAL,
+            DataList::ProductNoun => Self::PRODUCT_NOUN,
+            DataList::ProductCategory => Self::PRODUCT_CATEGORY,
+            DataList::Extension => Self::EXTENSION,
+        }
+    }
 }
 
-impl<D: Data> Rand for D {}
+impl<S: Source> Rand for S {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::faker::card_brand::CardBrand;
+    use crate::faker::kana::KanaScript;
+    use std::fs;
+
+    #[test]
+    fn access_log_status_is_final_status_code() {
+        let mut rng = rand::thread_rng();
+        let generator: Generator = Generator::new(Locale::Japan);
+        let format: AccessLogFormat = AccessLogFormat::Custom("%>s".to_string());
+        let mut ok: usize = 0;
+        for _ in 0..1000 {
+            let status: u16 = generator
+                .gen_access_log(&mut rng, &format, Local::now())
+                .parse()
+                .unwrap();
+            assert!(generator.http_status_code().contains(&&status));
+            assert!(status >= 200, "{}", status);
+            if status == 200 {
+                ok += 1;
+            }
+        }
+        assert!(ok > 500, "{}", ok);
+    }
+
+    #[test]
+    fn credit_card_number_is_luhn_valid_in_test_ranges() {
+        let mut rng = rand::thread_rng();
+        let generator: Generator = Generator::new(Locale::Japan);
+        for brand in CardBrand::all_list() {
+            for _ in 0..100 {
+                let number: String = generator.gen(&mut rng, &FakeOption::CreditCardNumber(brand));
+                assert_eq!(number.len(), brand.length(), "{}", number);
+                assert!(
+                    brand.test_prefixes().iter().any(|p| number.starts_with(p)),
+                    "{}",
+                    number
+                );
+                let digits: Vec<u8> = to_digits(&number);
+                let (check_digit, base): (&u8, &[u8]) = digits.split_last().unwrap();
+                assert_eq!(luhn_check_digit(base), *check_digit, "{}", number);
+            }
+        }
+    }
+
+    #[test]
+    fn with_locale_of_the_pack_uses_the_pack() {
+        let dir =
+            std::env::temp_dir().join(format!("fakes-gen-with-locale-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("last_name.txt"), "試験:シケン\n").unwrap();
+        let pack: LocalePack = LocalePack::load(Locale::Japan, &dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut rng = rand::thread_rng();
+        let generator: Generator = Generator::with_pack(Arc::new(pack));
+        let last_name: FakeOption = FakeOption::LastName(false, KanaScript::default());
+        let option = |locale: Locale| FakeOption::WithLocale(locale, Box::new(last_name.clone()));
+        assert_eq!(generator.gen(&mut rng, &option(Locale::Japan)), "試験");
+        assert_ne!(
+            generator.gen(&mut rng, &option(Locale::UnitedStates)),
+            "試験"
+        );
+    }
+}
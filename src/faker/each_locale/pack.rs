@@ -0,0 +1,56 @@
+use crate::faker::each_locale::DataList;
+use crate::faker::locale::Locale;
+use crate::helper::read_lines;
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/// locale data loaded from a directory at runtime.
+/// The directory has "<list>.txt" such as "last_name.txt" for each DataList as not empty lines.
+/// Name data is formatted as name:furigana such as the data of the locale.
+/// When the file of a list does not exist, use the list of the locale.
+/// "first_name.txt" of older packs is used for both genders without "male_first_name.txt" or "female_first_name.txt".
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct LocalePack {
+    locale: Locale,
+    lists: HashMap<DataList, Vec<String>>,
+}
+
+impl LocalePack {
+    /// Share the loaded pack by Generator through Arc such as Faker::with_locale_pack(rng, Arc::new(pack)).
+    pub fn load(locale: Locale, dir: &Path) -> io::Result<LocalePack> {
+        if !dir.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} is not directory", dir.display()),
+            ));
+        }
+        let mut lists: HashMap<DataList, Vec<String>> = HashMap::new();
+        for list in DataList::all_list() {
+            let path = dir.join(format!("{}.txt", list));
+            if !path.is_file() {
+                continue;
+            }
+            lists.insert(list, read_lines(&path.to_string_lossy())?);
+        }
+        // first_name.txt of old packs is for both genders when the file of the gender does not exist
+        let path = dir.join("first_name.txt");
+        if path.is_file() {
+            let first_names: Vec<String> = read_lines(&path.to_string_lossy())?;
+            for list in [DataList::MaleFirstName, DataList::FemaleFirstName] {
+                lists.entry(list).or_insert_with(|| first_names.clone());
+            }
+        }
+        Ok(LocalePack { locale, lists })
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// list in the pack. If None, the pack does not have the list.
+    pub fn list(&self, list: DataList) -> Option<&[String]> {
+        self.lists.get(&list).map(|lines| lines.as_slice())
+    }
+}
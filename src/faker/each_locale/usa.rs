@@ -0,0 +1,877 @@
+use crate::faker::address_style::AddressStyle;
+use crate::faker::currency::Currency;
+use crate::faker::each_locale::Data;
+use crate::faker::gender::Gender;
+use crate::faker::phone_kind::PhoneKind;
+
+use crate::helper::{digits_to_string, gen_digits, gen_range};
+
+use rand::Rng;
+
+pub struct UsaData {}
+
+// area codes of geographic numbers in service for each state
+const AREA_CODE: &[(&str, &[&str])] = &[
+    ("Alabama", &["205", "251", "256", "334"]),
+    ("Alaska", &["907"]),
+    ("Arizona", &["480", "520", "602", "623", "928"]),
+    ("Arkansas", &["479", "501", "870"]),
+    (
+        "California",
+        &[
+            "209", "213", "310", "323", "408", "415", "510", "530", "559", "562", "619", "626",
+            "650", "661", "707", "714", "760", "805", "818", "831", "858", "909", "916", "925",
+            "949",
+        ],
+    ),
+    ("Colorado", &["303", "719", "720", "970"]),
+    ("Connecticut", &["203", "475", "860"]),
+    ("Delaware", &["302"]),
+    (
+        "Florida",
+        &[
+            "239", "305", "321", "352", "386", "407", "561", "727", "754", "772", "786", "813",
+            "850", "863", "904", "941", "954",
+        ],
+    ),
+    (
+        "Georgia",
+        &[
+            "229", "404", "470", "478", "678", "706", "762", "770", "912",
+        ],
+    ),
+    ("Hawaii", &["808"]),
+    ("Idaho", &["208"]),
+    (
+        "Illinois",
+        &[
+            "217", "224", "309", "312", "618", "630", "708", "773", "815", "847",
+        ],
+    ),
+    ("Indiana", &["219", "260", "317", "574", "765", "812"]),
+    ("Iowa", &["319", "515", "563", "641", "712"]),
+    ("Kansas", &["316", "620", "785", "913"]),
+    ("Kentucky", &["270", "502", "606", "859"]),
+    ("Louisiana", &["225", "318", "337", "504", "985"]),
+    ("Maine", &["207"]),
+    ("Maryland", &["240", "301", "410", "443"]),
+    (
+        "Massachusetts",
+        &[
+            "339", "351", "413", "508", "617", "774", "781", "857", "978",
+        ],
+    ),
+    (
+        "Michigan",
+        &[
+            "231", "248", "269", "313", "517", "586", "616", "734", "810", "906", "947", "989",
+        ],
+    ),
+    (
+        "Minnesota",
+        &["218", "320", "507", "612", "651", "763", "952"],
+    ),
+    ("Mississippi", &["228", "601", "662", "769"]),
+    ("Missouri", &["314", "417", "573", "636", "660", "816"]),
+    ("Montana", &["406"]),
+    ("Nebraska", &["308", "402", "531"]),
+    ("Nevada", &["702", "725", "775"]),
+    ("New Hampshire", &["603"]),
+    (
+        "New Jersey",
+        &[
+            "201", "551", "609", "732", "848", "856", "862", "908", "973",
+        ],
+    ),
+    ("New Mexico", &["505", "575"]),
+    (
+        "New York",
+        &[
+            "212", "315", "347", "516", "518", "585", "607", "631", "646", "716", "718", "845",
+            "914", "917", "929",
+        ],
+    ),
+    (
+        "North Carolina",
+        &["252", "336", "704", "828", "910", "919", "980", "984"],
+    ),
+    ("North Dakota", &["701"]),
+    (
+        "Ohio",
+        &[
+            "216", "234", "330", "419", "440", "513", "567", "614", "740", "937",
+        ],
+    ),
+    ("Oklahoma", &["405", "539", "580", "918"]),
+    ("Oregon", &["458", "503", "541", "971"]),
+    (
+        "Pennsylvania",
+        &[
+            "215", "267", "412", "484", "570", "610", "717", "724", "814", "878",
+        ],
+    ),
+    ("Rhode Island", &["401"]),
+    ("South Carolina", &["803", "843", "864"]),
+    ("South Dakota", &["605"]),
+    (
+        "Tennessee",
+        &["423", "615", "629", "731", "865", "901", "931"],
+    ),
+    (
+        "Texas",
+        &[
+            "210", "214", "254", "281", "325", "361", "409", "430", "432", "469", "512", "682",
+            "713", "737", "806", "817", "830", "832", "903", "915", "936", "940", "956", "972",
+            "979",
+        ],
+    ),
+    ("Utah", &["385", "435", "801"]),
+    ("Vermont", &["802"]),
+    (
+        "Virginia",
+        &["276", "434", "540", "571", "703", "757", "804"],
+    ),
+    ("Washington", &["206", "253", "360", "425", "509"]),
+    ("West Virginia", &["304", "681"]),
+    ("Wisconsin", &["262", "414", "608", "715", "920"]),
+    ("Wyoming", &["307"]),
+];
+
+impl Data for UsaData {
+    // Lorem
+    const WORD: &'static [&'static str] = &[
+        "apple", "harbor", "meadow", "engine", "library", "canyon", "river", "lantern", "orchard",
+        "bridge", "compass", "prairie", "thunder", "granite", "village", "market", "garden",
+        "blanket", "window", "journey", "whistle", "pioneer", "railroad", "highway", "baseball",
+        "pancake", "sunrise", "mountain", "island", "feather", "violin", "kitchen", "notebook",
+        "puzzle", "rocket", "silver", "thimble", "voyage", "wagon", "yellow", "zipper", "ocean",
+        "forest", "desert", "signal", "ticket", "cabin", "harvest", "parade", "quarter",
+    ];
+    const SENTENCE: &'static [&'static str] = &[
+        "The morning train left the station exactly on time.",
+        "She kept a small notebook in the pocket of her coat.",
+        "Nobody expected the storm to pass so quickly.",
+        "The library closes early on the first Monday of each month.",
+        "He painted the fence a bright shade of blue.",
+        "Fresh bread was cooling on the kitchen counter.",
+        "The committee will review the proposal next week.",
+        "A flock of geese flew low over the frozen lake.",
+        "Please leave your shoes by the front door.",
+        "The old bridge was replaced with a wider one last summer.",
+        "Our team finished the project two days ahead of schedule.",
+        "The museum offers free admission on Sunday afternoons.",
+        "They planted tomatoes and peppers along the south wall.",
+        "The map showed a trail that no longer existed.",
+        "Every seat in the theater was taken by seven o'clock.",
+        "The dog waited patiently at the end of the driveway.",
+        "A light rain fell throughout the afternoon.",
+        "The new policy takes effect at the beginning of the year.",
+        "He fixed the radio with nothing but a paper clip.",
+        "The farmers market moved to the parking lot downtown.",
+        "She answered every question with a calm smile.",
+        "The package arrived a day later than promised.",
+        "Children gathered around the fountain in the town square.",
+        "The recipe calls for two cups of flour and a pinch of salt.",
+        "Traffic on the highway slowed near the construction zone.",
+        "The report was printed, signed and filed before noon.",
+        "Wind chimes rang softly on the back porch.",
+        "The coach asked everyone to arrive thirty minutes early.",
+        "Snow covered the mountain pass for most of the winter.",
+        "The shop on the corner sells the best coffee in town.",
+    ];
+    const PARAGRAPH: &'static [&'static str] = &[
+        "The town held its annual fair at the end of August. Families came from the surrounding counties to see the livestock, taste the pies and ride the old wooden carousel. By evening the parking lot was full and the music from the bandstand could be heard all the way to the river.",
+        "Our office moved to the fourth floor of the building on Main Street. The new space has more natural light and a larger kitchen. Most people agreed that the short walk from the bus stop was worth the change, even on rainy days.",
+        "The hiking trail begins behind the visitor center and climbs gently through a pine forest. After about two miles it reaches a ridge with a clear view of the valley. Rangers recommend carrying plenty of water and starting early during the summer months.",
+        "When the power went out, the neighbors gathered on the porch with flashlights and a deck of cards. Someone brought lemonade and someone else brought a guitar. By the time the lights came back on, nobody was in a hurry to go inside.",
+        "The library recently added a collection of local history books. Visitors can browse old maps, photographs and newspapers dating back more than a century. Volunteers are scanning the most fragile items so that they can be viewed online.",
+        "Planning a road trip takes more time than most people expect. You have to choose a route, find places to stay and decide how many hours to drive each day. Still, the freedom of the open road makes the effort worthwhile.",
+        "The bakery opens before sunrise every day except Sunday. The smell of cinnamon rolls drifts down the block and draws a line of early customers. Regulars know to arrive before seven if they want a loaf of the sourdough.",
+        "Students presented their science projects in the school gym. Some built model volcanoes, while others measured how fast plants grew under different colored lights. The judges were impressed by the careful notes that many of them had kept.",
+        "The harbor was quiet except for the sound of gulls and the creak of the docks. Fishing boats had gone out before dawn and would not return until late afternoon. A few tourists wandered along the pier, taking pictures of the lighthouse.",
+        "After months of discussion, the city council approved the plan for a new park. It will include a playground, walking paths and a small pond. Construction is expected to begin in the spring and finish before the end of the year.",
+    ];
+
+    // Name
+    const MALE_FIRST_NAME: &'static [&'static str] = &[
+        "James",
+        "John",
+        "Robert",
+        "Michael",
+        "William",
+        "David",
+        "Richard",
+        "Joseph",
+        "Thomas",
+        "Charles",
+        "Christopher",
+        "Daniel",
+        "Matthew",
+        "Anthony",
+        "Mark",
+        "Donald",
+        "Steven",
+        "Paul",
+        "Andrew",
+        "Joshua",
+        "Kenneth",
+        "Kevin",
+        "Brian",
+        "George",
+        "Timothy",
+        "Ronald",
+        "Edward",
+        "Jason",
+        "Jeffrey",
+        "Ryan",
+        "Jacob",
+        "Gary",
+        "Nicholas",
+        "Eric",
+        "Jonathan",
+        "Stephen",
+        "Larry",
+        "Justin",
+        "Scott",
+        "Brandon",
+    ];
+    const FEMALE_FIRST_NAME: &'static [&'static str] = &[
+        "Mary",
+        "Patricia",
+        "Jennifer",
+        "Linda",
+        "Elizabeth",
+        "Barbara",
+        "Susan",
+        "Jessica",
+        "Sarah",
+        "Karen",
+        "Lisa",
+        "Nancy",
+        "Betty",
+        "Margaret",
+        "Sandra",
+        "Ashley",
+        "Kimberly",
+        "Emily",
+        "Donna",
+        "Michelle",
+        "Carol",
+        "Amanda",
+        "Dorothy",
+        "Melissa",
+        "Deborah",
+        "Stephanie",
+        "Rebecca",
+        "Sharon",
+        "Laura",
+        "Cynthia",
+        "Kathleen",
+        "Amy",
+        "Angela",
+        "Shirley",
+        "Anna",
+        "Brenda",
+        "Pamela",
+        "Emma",
+        "Nicole",
+        "Helen",
+    ];
+    const LAST_NAME: &'static [&'static str] = &[
+        "Smith",
+        "Johnson",
+        "Williams",
+        "Brown",
+        "Jones",
+        "Garcia",
+        "Miller",
+        "Davis",
+        "Rodriguez",
+        "Martinez",
+        "Hernandez",
+        "Lopez",
+        "Gonzalez",
+        "Wilson",
+        "Anderson",
+        "Thomas",
+        "Taylor",
+        "Moore",
+        "Jackson",
+        "Martin",
+        "Lee",
+        "Perez",
+        "Thompson",
+        "White",
+        "Harris",
+        "Sanchez",
+        "Clark",
+        "Ramirez",
+        "Lewis",
+        "Robinson",
+        "Walker",
+        "Young",
+        "Allen",
+        "King",
+        "Wright",
+        "Scott",
+        "Torres",
+        "Nguyen",
+        "Hill",
+        "Flores",
+        "Green",
+        "Adams",
+        "Nelson",
+        "Baker",
+        "Hall",
+        "Rivera",
+        "Campbell",
+        "Mitchell",
+        "Carter",
+        "Roberts",
+        "Gomez",
+        "Phillips",
+        "Evans",
+        "Turner",
+        "Diaz",
+        "Parker",
+        "Cruz",
+        "Edwards",
+        "Collins",
+        "Reyes",
+        "Stewart",
+        "Morris",
+        "Morales",
+        "Murphy",
+        "Cook",
+        "Rogers",
+        "Gutierrez",
+        "Ortiz",
+        "Morgan",
+        "Cooper",
+        "Peterson",
+        "Bailey",
+        "Reed",
+        "Kelly",
+        "Howard",
+        "Ramos",
+        "Kim",
+        "Cox",
+        "Ward",
+        "Richardson",
+    ];
+    fn build_name(last_name: &str, first_name: &str) -> String {
+        [first_name, last_name].join(" ")
+    }
+    fn gender_label(gender: Gender) -> &'static str {
+        match gender {
+            Gender::Male => "Male",
+            Gender::Female => "Female",
+        }
+    }
+
+    // Internet
+    const COLOR_NAME: &'static [&'static str] = &[
+        "Crimson:#DC143C",
+        "Ruby:#E0115F",
+        "Burgundy:#800020",
+        "Maroon:#800000",
+        "Rose:#FF007F",
+        "Salmon:#FA8072",
+        "Coral:#FF7F50",
+        "Peach:#FFE5B4",
+        "Amber:#FFBF00",
+        "Gold:#FFD700",
+        "Mustard:#FFDB58",
+        "Khaki:#C3B091",
+        "Tan:#D2B48C",
+        "Chocolate:#D2691E",
+        "Olive:#808000",
+        "Mint:#98FF98",
+        "Emerald:#50C878",
+        "Forest Green:#228B22",
+        "Teal:#008080",
+        "Turquoise:#40E0D0",
+        "Sky Blue:#87CEEB",
+        "Royal Blue:#4169E1",
+        "Sapphire:#0F52BA",
+        "Navy Blue:#000080",
+        "Indigo:#4B0082",
+        "Lavender:#E6E6FA",
+        "Plum:#DDA0DD",
+        "Beige:#F5F5DC",
+        "Ivory:#FFFFF0",
+        "Silver:#C0C0C0",
+        "Slate Gray:#708090",
+        "Charcoal:#36454F",
+    ];
+
+    // Company
+    const COMPANY_SUFFIX: &'static [&'static str] = &[
+        "Inc.", "LLC", "Corp.", "Co.", "Ltd.", "LLP", "Group", "Holdings",
+    ];
+    const COMPANY_NAME: &'static [&'static str] = &[
+        "Acme",
+        "Summit",
+        "Pioneer",
+        "Liberty",
+        "Evergreen",
+        "Redwood",
+        "Blue Ridge",
+        "Silver Lake",
+        "Northstar",
+        "Keystone",
+        "Golden Gate",
+        "Frontier",
+        "Heartland",
+        "Bayside",
+        "Granite",
+        "Prairie",
+        "Cascade",
+        "Harbor",
+        "Pinnacle",
+        "Lakeshore",
+    ];
+    fn build_company_name(name: &str, suffix: &str) -> String {
+        [name, suffix].join(" ")
+    }
+    const INDUSTRY: &'static [&'static str] = &[
+        "Agriculture",
+        "Mining",
+        "Utilities",
+        "Construction",
+        "Manufacturing",
+        "Wholesale Trade",
+        "Retail Trade",
+        "Transportation and Warehousing",
+        "Information",
+        "Finance and Insurance",
+        "Real Estate",
+        "Professional Services",
+        "Management of Companies",
+        "Waste Management",
+        "Educational Services",
+        "Health Care",
+        "Arts and Entertainment",
+        "Accommodation and Food Services",
+        "Public Administration",
+    ];
+    const DEPARTMENT: &'static [&'static str] = &[
+        "Human Resources",
+        "Finance",
+        "Accounting",
+        "Legal",
+        "Public Relations",
+        "Operations",
+        "Sales",
+        "Business Development",
+        "Marketing",
+        "Customer Success",
+        "Customer Support",
+        "Engineering",
+        "Research and Development",
+        "Information Technology",
+        "Quality Assurance",
+        "Product Management",
+        "Design",
+        "Manufacturing",
+        "Procurement",
+        "Logistics",
+    ];
+    const JOB_TITLE: &'static [&'static str] = &[
+        "Chief Executive Officer",
+        "Chief Technology Officer",
+        "Chief Financial Officer",
+        "Vice President",
+        "Director",
+        "Senior Manager",
+        "Manager",
+        "Team Lead",
+        "Senior Engineer",
+        "Software Engineer",
+        "Account Executive",
+        "Analyst",
+        "Specialist",
+        "Coordinator",
+        "Associate",
+    ];
+
+    // Address
+    const STREET_NAME: &'static [&'static str] = &[
+        "123 Main Street",
+        "456 Oak Avenue",
+        "789 Maple Drive",
+        "1010 Pine Street",
+        "2200 Cedar Lane",
+        "350 Elm Street",
+        "77 Washington Avenue",
+        "1600 Lake Shore Drive",
+        "48 Park Place",
+        "915 Hillcrest Road",
+        "302 Sunset Boulevard",
+        "1401 River Road",
+        "86 Church Street",
+        "2750 Highland Avenue",
+        "19 Willow Court",
+        "640 Jefferson Street",
+        "5100 Lincoln Way",
+        "233 Spring Street",
+        "71 Chestnut Street",
+        "4820 Meadow Lane",
+        "1215 Franklin Avenue",
+        "390 Walnut Street",
+        "27 Forest Drive",
+        "8800 Airport Road",
+        "560 Madison Avenue",
+    ];
+    const CITY_NAME: &'static [&'static str] = &[
+        "Springfield",
+        "Riverside",
+        "Franklin",
+        "Greenville",
+        "Bristol",
+        "Clinton",
+        "Fairview",
+        "Salem",
+        "Madison",
+        "Georgetown",
+        "Arlington",
+        "Ashland",
+        "Burlington",
+        "Manchester",
+        "Oxford",
+        "Jackson",
+        "Milton",
+        "Newport",
+        "Dover",
+        "Auburn",
+    ];
+    const STATE_NAME: &'static [&'static str] = &[
+        "Alabama",
+        "Alaska",
+        "Arizona",
+        "Arkansas",
+        "California",
+        "Colorado",
+        "Connecticut",
+        "Delaware",
+        "Florida",
+        "Georgia",
+        "Hawaii",
+        "Idaho",
+        "Illinois",
+        "Indiana",
+        "Iowa",
+        "Kansas",
+        "Kentucky",
+        "Louisiana",
+        "Maine",
+        "Maryland",
+        "Massachusetts",
+        "Michigan",
+        "Minnesota",
+        "Mississippi",
+        "Missouri",
+        "Montana",
+        "Nebraska",
+        "Nevada",
+        "New Hampshire",
+        "New Jersey",
+        "New Mexico",
+        "New York",
+        "North Carolina",
+        "North Dakota",
+        "Ohio",
+        "Oklahoma",
+        "Oregon",
+        "Pennsylvania",
+        "Rhode Island",
+        "South Carolina",
+        "South Dakota",
+        "Tennessee",
+        "Texas",
+        "Utah",
+        "Vermont",
+        "Virginia",
+        "Washington",
+        "West Virginia",
+        "Wisconsin",
+        "Wyoming",
+    ];
+    const STATE_AREA: &'static [(&'static str, f64, f64, f64)] = &[
+        ("Alabama", 32.8, -86.8, 120.0),
+        ("Alaska", 64.0, -150.0, 250.0),
+        ("Arizona", 34.3, -111.7, 180.0),
+        ("Arkansas", 34.9, -92.4, 120.0),
+        ("California", 37.2, -119.5, 150.0),
+        ("Colorado", 39.0, -105.5, 150.0),
+        ("Connecticut", 41.6, -72.7, 30.0),
+        ("Delaware", 39.0, -75.55, 8.0),
+        ("Florida", 28.5, -81.6, 80.0),
+        ("Georgia", 32.7, -83.4, 150.0),
+        ("Hawaii", 19.6, -155.5, 30.0),
+        ("Idaho", 44.1, -114.7, 90.0),
+        ("Illinois", 40.0, -89.2, 150.0),
+        ("Indiana", 39.9, -86.3, 120.0),
+        ("Iowa", 42.0, -93.5, 150.0),
+        ("Kansas", 38.5, -98.4, 180.0),
+        ("Kentucky", 37.5, -85.3, 100.0),
+        ("Louisiana", 31.0, -92.4, 100.0),
+        ("Maine", 45.3, -69.2, 100.0),
+        ("Maryland", 39.45, -77.3, 15.0),
+        ("Massachusetts", 42.3, -71.9, 35.0),
+        ("Michigan", 43.6, -84.7, 120.0),
+        ("Minnesota", 46.3, -94.3, 180.0),
+        ("Mississippi", 32.7, -89.7, 120.0),
+        ("Missouri", 38.4, -92.5, 150.0),
+        ("Montana", 47.0, -109.6, 250.0),
+        ("Nebraska", 41.5, -99.8, 180.0),
+        ("Nevada", 39.3, -116.6, 200.0),
+        ("New Hampshire", 43.6, -71.6, 40.0),
+        ("New Jersey", 40.2, -74.6, 30.0),
+        ("New Mexico", 34.4, -106.1, 200.0),
+        ("New York", 42.9, -75.5, 100.0),
+        ("North Carolina", 35.6, -79.4, 150.0),
+        ("North Dakota", 47.5, -100.5, 150.0),
+        ("Ohio", 40.3, -82.8, 120.0),
+        ("Oklahoma", 35.5, -97.5, 150.0),
+        ("Oregon", 44.0, -120.5, 150.0),
+        ("Pennsylvania", 40.9, -77.8, 120.0),
+        ("Rhode Island", 41.7, -71.55, 10.0),
+        ("South Carolina", 33.9, -80.9, 100.0),
+        ("South Dakota", 44.4, -100.2, 180.0),
+        ("Tennessee", 35.8, -86.4, 80.0),
+        ("Texas", 31.5, -99.3, 300.0),
+        ("Utah", 39.3, -111.7, 150.0),
+        ("Vermont", 44.0, -72.7, 40.0),
+        ("Virginia", 37.5, -78.8, 100.0),
+        ("Washington", 47.4, -120.5, 100.0),
+        ("West Virginia", 38.6, -80.6, 60.0),
+        ("Wisconsin", 44.6, -89.8, 150.0),
+        ("Wyoming", 43.0, -107.5, 200.0),
+    ];
+    const BUILDING: &'static [&'static str] = &[
+        "Liberty Tower",
+        "Harbor View Apartments",
+        "Maple Court",
+        "Riverside Plaza",
+        "Summit Building",
+        "Oak Ridge Apartments",
+        "Lakeview Terrace",
+        "Parkside Lofts",
+        "Union Square Building",
+        "Heritage House",
+        "Cedar Point Condominiums",
+        "Franklin Center",
+        "Willow Creek Apartments",
+        "Metro Tower",
+        "Highland Manor",
+    ];
+
+    fn build_address(street: &str, city: &str, state: &str) -> String {
+        format!("{}, {}, {}", street, city, state)
+    }
+
+    const TOWN_NAME: &'static [&'static str] = &[
+        "Main Street",
+        "Oak Avenue",
+        "Maple Drive",
+        "Pine Street",
+        "Cedar Lane",
+        "Elm Street",
+        "Washington Avenue",
+        "Lake Shore Drive",
+        "Park Avenue",
+        "Hillcrest Road",
+        "Sunset Boulevard",
+        "Jefferson Street",
+        "Lincoln Avenue",
+        "Church Street",
+        "Highland Avenue",
+        "Meadow Lane",
+        "Ridge Road",
+        "Walnut Street",
+        "Spring Street",
+        "River Road",
+    ];
+
+    fn gen_block_number<R: Rng>(rng: &mut R) -> Vec<u16> {
+        vec![gen_range(rng, 1..=9999)]
+    }
+
+    // house number is not affected by the style.
+    fn format_block_number(numbers: &[u16], _style: AddressStyle) -> String {
+        numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join("-")
+    }
+
+    fn build_street(town: &str, block_number: &str) -> String {
+        format!("{} {}", block_number, town)
+    }
+
+    // unit such as Apt 204
+    fn gen_room_number<R: Rng>(rng: &mut R) -> String {
+        let unit: &str = ["Apt", "Suite", "Unit"][gen_range(rng, 0..3)];
+        let floor: u8 = gen_range(rng, 1..=20);
+        let room: u8 = gen_range(rng, 1..=12);
+        format!("{} {}{:>02}", unit, floor, room)
+    }
+
+    fn build_address_line2(building: &str, room_number: &str) -> String {
+        format!("{}, {}", building, room_number)
+    }
+
+    fn build_detailed_address(street: &str, line2: &str, city: &str, state: &str) -> String {
+        format!("{}, {}, {}, {}", street, line2, city, state)
+    }
+
+    // ZIP code of five digits or ZIP+4 at random. ZIP+4 is such as 12345-6789, or 123456789 without hyphen.
+    fn gen_zip_code<R: Rng>(rng: &mut R, hyphen: bool) -> String {
+        let zip: u32 = gen_range(rng, 501..=99950);
+        if !rng.gen_bool(0.3) {
+            return format!("{:>05}", zip);
+        }
+        let plus_four: u16 = gen_range(rng, 1..=9999);
+        if hyphen {
+            format!("{:>05}-{:>04}", zip, plus_four)
+        } else {
+            format!("{:>05}{:>04}", zip, plus_four)
+        }
+    }
+
+    const CALLING_CODE: &'static str = "1";
+
+    // mobile and ip phone have the same number as landline but their area code is of a random state.
+    // If the state is unknown, area code of landline is of a random state.
+    fn gen_phone_number<R: Rng>(rng: &mut R, kind: PhoneKind, state: &str, hyphen: bool) -> String {
+        match kind {
+            // no range of toll free number is reserved for fictional use.
+            // 555-01XX is used as well as the other kinds, but the number may be assigned.
+            PhoneKind::TollFree => {
+                let code: &str =
+                    ["800", "833", "844", "855", "866", "877", "888"][gen_range(rng, 0..7)];
+                let line: u16 = gen_range(rng, 100..=199);
+                if hyphen {
+                    format!("{}-555-{:>04}", code, line)
+                } else {
+                    format!("{}555{:>04}", code, line)
+                }
+            }
+            PhoneKind::Landline => {
+                let codes: &[&str] = match AREA_CODE.iter().find(|(s, _)| *s == state) {
+                    Some((_, codes)) => codes,
+                    None => AREA_CODE[gen_range(rng, 0..AREA_CODE.len())].1,
+                };
+                let area: &str = codes[gen_range(rng, 0..codes.len())];
+                let line: u16 = gen_range(rng, 100..=199);
+                if hyphen {
+                    format!("{}-555-{:>04}", area, line)
+                } else {
+                    format!("{}555{:>04}", area, line)
+                }
+            }
+            _ => Self::gen_domestic_phone_number(rng, hyphen),
+        }
+    }
+
+    // 555-0100 through 555-0199 are reserved for fictional use.
+    fn gen_domestic_phone_number<R: Rng>(rng: &mut R, hyphen: bool) -> String {
+        let codes: &[&str] = AREA_CODE[gen_range(rng, 0..AREA_CODE.len())].1;
+        let area: &str = codes[gen_range(rng, 0..codes.len())];
+        let line: u16 = gen_range(rng, 100..=199);
+        if hyphen {
+            format!("{}-555-{:>04}", area, line)
+        } else {
+            format!("{}555{:>04}", area, line)
+        }
+    }
+
+    // Finance
+    // code is ABA routing number
+    // names and codes are of real banks intentionally so that the code passes validation of the bank code.
+    // branch, account number and account holder are fictional, so the account does not exist.
+    const BANK: &'static [&'static str] = &[
+        "JPMorgan Chase Bank:021000021",
+        "Bank of America:026009593",
+        "Wells Fargo Bank:121000248",
+        "Citibank:021000089",
+        "U.S. Bank:091000022",
+        "PNC Bank:043000096",
+        "Truist Bank:061000104",
+        "Capital One:051405515",
+        "TD Bank:031101266",
+        "Fifth Third Bank:042000314",
+    ];
+    // branch code is not of the real branch of the bank
+    const BANK_BRANCH: &'static [&'static str] = &[
+        "Main Office:001",
+        "Downtown Branch:002",
+        "Uptown Branch:003",
+        "Midtown Branch:004",
+        "Westside Branch:005",
+        "Eastside Branch:006",
+        "Northgate Branch:007",
+        "Southpark Branch:008",
+        "Riverside Branch:009",
+        "Lakeside Branch:010",
+    ];
+    const ACCOUNT_TYPE: &'static [&'static str] = &["Checking", "Savings"];
+
+    // 10 digits
+    fn gen_account_number<R: Rng>(rng: &mut R) -> String {
+        digits_to_string(&gen_digits(rng, 10))
+    }
+
+    // Commerce
+    const ISBN_GROUP: &'static [&'static str] = &["0", "1"];
+    const CURRENCY: Currency = Currency::Usd;
+    const PRODUCT_ADJECTIVE: &'static [&'static str] = &[
+        "Ergonomic",
+        "Rustic",
+        "Sleek",
+        "Durable",
+        "Lightweight",
+        "Handcrafted",
+        "Premium",
+        "Compact",
+        "Vintage",
+        "Modern",
+        "Classic",
+        "Portable",
+        "Waterproof",
+        "Eco-Friendly",
+        "Deluxe",
+    ];
+    const PRODUCT_MATERIAL: &'static [&'static str] = &[
+        "Wooden", "Leather", "Cotton", "Linen", "Wool", "Silk", "Steel", "Aluminum", "Glass",
+        "Ceramic", "Bamboo", "Granite", "Copper", "Rubber", "Plastic",
+    ];
+    const PRODUCT_NOUN: &'static [&'static str] = &[
+        "Chair", "Table", "Tote Bag", "Wallet", "Shirt", "Mug", "Tumbler", "Lamp", "Pillow",
+        "Backpack", "Watch", "Keyboard", "Speaker", "Bottle", "Jacket", "Sneakers",
+    ];
+    const PRODUCT_CATEGORY: &'static [&'static str] = &[
+        "Furniture",
+        "Home Decor",
+        "Kitchen",
+        "Tableware",
+        "Clothing",
+        "Bags and Accessories",
+        "Shoes",
+        "Jewelry",
+        "Electronics",
+        "Computers",
+        "Cell Phones",
+        "Books",
+        "Office Supplies",
+        "Toys",
+        "Sports and Outdoors",
+        "Grocery",
+        "Beverages",
+        "Beauty",
+        "Health",
+        "Pet Supplies",
+    ];
+}
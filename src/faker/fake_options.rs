@@ -1,10 +1,30 @@
+use crate::faker::access_log::AccessLogFormat;
+use crate::faker::address_style::AddressStyle;
+use crate::faker::card_brand::CardBrand;
 use crate::faker::category::Category;
-use crate::helper::{not_string_formatted, string_formatted};
+use crate::faker::color_format::{ColorFormat, ColorTone};
+use crate::faker::currency::Currency;
+use crate::faker::geo_area::GeoArea;
+use crate::faker::hash_format::HashFormat;
+use crate::faker::ip_range::IpRange;
+use crate::faker::kana::KanaScript;
+use crate::faker::locale::Locale;
+use crate::faker::mac_style::MacStyle;
+use crate::faker::password_policy::PasswordPolicy;
+use crate::faker::phone_kind::PhoneKind;
+use crate::faker::port_range::PortRange;
+use crate::faker::romaji::Capitalization;
+use crate::faker::url_format::UrlFormat;
+use crate::faker::user_agent::UserAgentFormat;
+use crate::helper::{not_string_formatted, read_csv_column, read_lines, string_formatted};
+use std::io;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
 pub enum FakeOption {
     // with other FakeOptions
     Join(String, Vec<Box<FakeOption>>),
+    // generate the FakeOption with the locale instead of the locale of Faker
+    WithLocale(Locale, Box<FakeOption>),
 
     // Fixed Value
     FixedString(String),
@@ -13,6 +33,10 @@ pub enum FakeOption {
     // Random select from user value
     SelectString(Vec<String>),
     SelectNotString(Vec<String>),
+    // file path and values read from the file
+    SelectFromFile(String, Vec<String>),
+    // file path, column name and values read from the column
+    SelectFromColumn(String, String, Vec<String>),
 
     // Lorem
     Word,
@@ -22,14 +46,20 @@ pub enum FakeOption {
     Paragraph,
     Paragraphs(usize, usize),
 
-    // Name(use furigana)
+    // Name(use furigana, script of furigana)
     // generate "name":"furigana"
-    FirstName(bool),
-    FirstNameFurigana,
-    LastName(bool),
-    LastNameFurigana,
-    FullName(bool),
-    FullNameFurigana,
+    FirstName(bool, KanaScript),
+    FirstNameFurigana(KanaScript),
+    LastName(bool, KanaScript),
+    LastNameFurigana(KanaScript),
+    FullName(bool, KanaScript),
+    FullNameFurigana(KanaScript),
+    // romaji of furigana with Hepburn
+    FirstNameRomaji(Capitalization),
+    LastNameRomaji(Capitalization),
+    FullNameRomaji(Capitalization),
+    // gender of the first name in the same record
+    Gender,
 
     // Primitive
     Integer,
@@ -43,19 +73,69 @@ pub enum FakeOption {
     Email,
     UserName,
     Password(usize, usize),
+    // characters of the classes in the policy with repetition
+    PasswordWithPolicy(usize, usize, PasswordPolicy),
+    // hash of the password in the same record
+    PasswordHash(HashFormat),
     CreditCard,
+    // Luhn valid number of the brand in test ranges
+    CreditCardNumber(CardBrand),
+    // MM/YY in the future
+    CreditCardExpiry,
+    // security code of the brand
+    CreditCardCvv(CardBrand),
+    // romaji of the person in the same record such as "TARO YAMADA"
+    CreditCardHolder,
     URL,
+    // URL with scheme, path depth, query parameters, fragment, port and percent-encoded path
+    URLWith(UrlFormat),
     IPv4,
     IPv6,
+    // address in the range. bool is excluding network address and broadcast address or not.
+    IPv4InRange(IpRange, bool),
+    IPv6InRange(IpRange),
+    // MAC address of the style. bool is locally administered or not.
+    MAC(MacStyle, bool),
+    // host name such as web-01. bool is with domain name or not.
+    Hostname(bool),
+    // domain name of the reserved TLD such as .example and .test
+    DomainName,
+    // port number in the range
+    Port(PortRange),
+    // lower case words joined by hyphen for URL
+    Slug,
     RGB,
     RGBA,
+    // color of the tone in the format such as "rgb(18, 72, 15)"
+    Color(ColorFormat, ColorTone),
+    // name of color of the locale, such as traditional color of Japan, with hex such as "藍色:#165E83"
+    ColorName(bool),
+    // keyword of CSS named colors with hex such as "crimson:#DC143C"
+    CssColorName(bool),
     UserAgent,
+    // user agent of the browser, device type and platform
+    UserAgentWith(UserAgentFormat),
+    // line of access log of web server whose time increases for each record
+    AccessLog(AccessLogFormat),
     StatusCode,
 
     // Company
     CompanySuffix,
     CompanyName,
     Industry,
+    // 法人番号 of 13 digits with check digit at the head
+    CorporateNumber,
+    // 個人番号 of 12 digits with check digit at the tail
+    IndividualNumber,
+    Department,
+    // job title such as 部長 and 課長
+    JobTitle,
+    // employee number of the pattern whose "9" is a digit, "A" is an upper case letter and "\" escapes the next character
+    EmployeeNumber(String),
+    // domain derived from the company name in the record
+    CompanyDomain,
+    // email of the person in the record at the domain of the company in the record
+    EmployeeEmail,
 
     // Address
     Building,
@@ -66,12 +146,60 @@ pub enum FakeOption {
     CountryName,
     TimeZone,
     Address,
+    // address with block number of the style, building and room number
+    DetailedAddress(AddressStyle),
+    // address line 1 and address line 2 such as input form of EC site
+    AddressLine1(AddressStyle),
+    AddressLine2,
     ZipCode(bool),
     // use hyphen?
     DomesticPhoneNumber(bool),
-    // use hyphen?
+    // phone number of the kind. area code of landline is of the state in the same record.
+    PhoneNumber(PhoneKind, bool),
+    // phone number of the kind as E.164 such as +81312345678
+    InternationalPhoneNumber(PhoneKind),
+    // uniformly distributed on the whole earth
     Latitude,
     Longitude,
+    // latitude, longitude and "latitude,longitude" of the same point in the area in the same record
+    LatitudeIn(GeoArea),
+    LongitudeIn(GeoArea),
+    Coordinates(GeoArea),
+
+    // Finance
+    // name and code of the bank and the branch are of the same account in the same record
+    BankName,
+    BankCode,
+    BranchName,
+    BranchCode,
+    // such as 普通 and 当座
+    AccountType,
+    AccountNumber,
+    // half-width katakana of the person in the same record such as "ﾔﾏﾀﾞ ﾀﾛｳ"
+    AccountHolder,
+
+    // Commerce
+    // barcode numbers with valid check digit
+    // JAN (EAN-13) of Japanese prefix 45 or 49
+    JAN,
+    // short JAN (EAN-8) of Japanese prefix 45 or 49
+    EAN8,
+    // UPC-A of 12 digits
+    UPC,
+    // ISBN of the registration group of the locale such as 4 for Japan
+    ISBN10,
+    ISBN13,
+    // adjective, material and noun of the locale such as Ergonomic Wooden Chair
+    ProductName,
+    ProductCategory,
+    // SKU of the pattern whose "9" is a digit, "A" is an upper case letter and "\" escapes the next character
+    Sku(String),
+    // price of the currency with its decimals such as 1980 of JPY and 19.99 of USD.
+    // range is in the major unit. if None, use the price band of the currency.
+    Price(Currency, Option<(usize, usize)>),
+    CurrencyCode(Currency),
+    // quantity of the range where smaller quantity is more likely
+    Quantity(usize, usize),
 
     // Date Time
     // format-str: https://docs.rs/chrono/0.4.9/chrono/format/strftime/index.html#specifiers
@@ -103,22 +231,50 @@ impl std::fmt::Display for FakeOption {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            WithLocale(locale, data) => {
+                format!("{}.Locale(locale: \"{}\", data: \"{}\")", cat, locale, data)
+            }
             FixedString(s) => format!("{}.String(target: \"{}\")", cat, s),
             FixedNotString(s) => format!("{}.NotString(target: \"{}\")", cat, s),
             SelectString(list) => format!("{}.SelectString(list: {:?})", cat, list),
             SelectNotString(list) => format!("{}.SelectNotString(list: {:?})", cat, list),
+            SelectFromFile(path, _) => format!("{}.SelectFromFile(path: \"{}\")", cat, path),
+            SelectFromColumn(path, column, _) => format!(
+                "{}.SelectFromColumn(path: \"{}\", column: \"{}\")",
+                cat, path, column
+            ),
             Word => format!("{}.Word", cat),
             Words(from, to) => format!("{}.Words(count: {}<=n<={})", cat, from, to),
             Sentence => format!("{}.Sentence", cat),
             Sentences(from, to) => format!("{}.Sentences(count: {}<=n<={})", cat, from, to),
             Paragraph => format!("{}.Paragraph", cat),
             Paragraphs(from, to) => format!("{}.Paragraphs(count: {}<=n<={})", cat, from, to),
-            FirstName(furigana) => format!("{}.FirstName(with_furigana: {})", cat, furigana),
-            FirstNameFurigana => format!("{}.FirstNameFurigana", cat),
-            LastName(furigana) => format!("{}.LastName(with_furigana: {})", cat, furigana),
-            LastNameFurigana => format!("{}.LastNameFurigana", cat),
-            FullName(furigana) => format!("{}.FullName(with_furigana: {})", cat, furigana),
-            FullNameFurigana => format!("{}.FullNameFurigana", cat),
+            FirstName(furigana, script) => format!(
+                "{}.FirstName(with_furigana: {}, script: {})",
+                cat, furigana, script
+            ),
+            FirstNameFurigana(script) => format!("{}.FirstNameFurigana(script: {})", cat, script),
+            LastName(furigana, script) => format!(
+                "{}.LastName(with_furigana: {}, script: {})",
+                cat, furigana, script
+            ),
+            LastNameFurigana(script) => format!("{}.LastNameFurigana(script: {})", cat, script),
+            FullName(furigana, script) => format!(
+                "{}.FullName(with_furigana: {}, script: {})",
+                cat, furigana, script
+            ),
+            FullNameFurigana(script) => format!("{}.FullNameFurigana(script: {})", cat, script),
+            FirstNameRomaji(capitalization) => format!(
+                "{}.FirstNameRomaji(capitalization: {})",
+                cat, capitalization
+            ),
+            LastNameRomaji(capitalization) => {
+                format!("{}.LastNameRomaji(capitalization: {})", cat, capitalization)
+            }
+            FullNameRomaji(capitalization) => {
+                format!("{}.FullNameRomaji(capitalization: {})", cat, capitalization)
+            }
+            Gender => format!("{}.Gender", cat),
             Integer => format!("{}.Integer", cat),
             IntegerRange(from, to) => format!("{}.Integer(range: {}<=n<={})", cat, from, to),
             Float => format!("{}.Float", cat),
@@ -128,17 +284,48 @@ impl std::fmt::Display for FakeOption {
             Email => format!("{}.Email", cat),
             UserName => format!("{}.UserName", cat),
             Password(from, to) => format!("{}.Password(length: {}<=n<={})", cat, from, to),
+            PasswordWithPolicy(from, to, policy) => {
+                format!("{}.Password(length: {}<=n<={}, {})", cat, from, to, policy)
+            }
+            PasswordHash(format) => format!("{}.PasswordHash(format: {})", cat, format),
             CreditCard => format!("{}.CreditCard", cat),
+            CreditCardNumber(brand) => format!("{}.CreditCardNumber(brand: {})", cat, brand),
+            CreditCardExpiry => format!("{}.CreditCardExpiry", cat),
+            CreditCardCvv(brand) => format!("{}.CreditCardCvv(brand: {})", cat, brand),
+            CreditCardHolder => format!("{}.CreditCardHolder", cat),
             URL => format!("{}.URL", cat),
+            URLWith(format) => format!("{}.URL({})", cat, format),
             IPv4 => format!("{}.IPv4", cat),
             IPv6 => format!("{}.IPv6", cat),
+            IPv4InRange(range, exclude) => format!(
+                "{}.IPv4(range: {}, exclude_network_and_broadcast: {})",
+                cat, range, exclude
+            ),
+            IPv6InRange(range) => format!("{}.IPv6(range: {})", cat, range),
+            MAC(style, local) => format!("{}.MAC(style: {}, local: {})", cat, style, local),
+            Hostname(with_domain) => format!("{}.Hostname(with_domain: {})", cat, with_domain),
+            DomainName => format!("{}.DomainName", cat),
+            Port(range) => format!("{}.Port(range: {})", cat, range),
+            Slug => format!("{}.Slug", cat),
             RGB => format!("{}.RGB", cat),
             RGBA => format!("{}.RGBA", cat),
+            Color(format, tone) => format!("{}.Color(format: {}, tone: {})", cat, format, tone),
+            ColorName(with_hex) => format!("{}.ColorName(with_hex: {})", cat, with_hex),
+            CssColorName(with_hex) => format!("{}.CssColorName(with_hex: {})", cat, with_hex),
             UserAgent => format!("{}.UserAgent", cat),
+            UserAgentWith(format) => format!("{}.UserAgent({})", cat, format),
+            AccessLog(format) => format!("{}.AccessLog(format: {})", cat, format),
             StatusCode => format!("{}.StatusCode", cat),
             CompanySuffix => format!("{}.CompanySuffix", cat),
             CompanyName => format!("{}.CompanyName", cat),
             Industry => format!("{}.Industry", cat),
+            CorporateNumber => format!("{}.CorporateNumber", cat),
+            IndividualNumber => format!("{}.IndividualNumber", cat),
+            Department => format!("{}.Department", cat),
+            JobTitle => format!("{}.JobTitle", cat),
+            EmployeeNumber(pattern) => format!("{}.EmployeeNumber(pattern: {})", cat, pattern),
+            CompanyDomain => format!("{}.CompanyDomain", cat),
+            EmployeeEmail => format!("{}.EmployeeEmail", cat),
             Building => format!("{}.Building", cat),
             StreetName => format!("{}.StreetName", cat),
             CityName => format!("{}.CityName", cat),
@@ -147,12 +334,49 @@ impl std::fmt::Display for FakeOption {
             CountryName => format!("{}.CountryName", cat),
             TimeZone => format!("{}.TimeZone", cat),
             Address => format!("{}.Address", cat),
+            DetailedAddress(style) => format!("{}.DetailedAddress(style: {})", cat, style),
+            AddressLine1(style) => format!("{}.AddressLine1(style: {})", cat, style),
+            AddressLine2 => format!("{}.AddressLine2", cat),
             ZipCode(hyphen) => format!("{}.ZipCode(use_hyphen: {})", cat, hyphen),
             DomesticPhoneNumber(hyphen) => {
                 format!("{}.DomesticPhoneNumber(use_hyphen: {})", cat, hyphen)
             }
+            PhoneNumber(kind, hyphen) => format!(
+                "{}.PhoneNumber(kind: {}, use_hyphen: {})",
+                cat, kind, hyphen
+            ),
+            InternationalPhoneNumber(kind) => {
+                format!("{}.InternationalPhoneNumber(kind: {})", cat, kind)
+            }
             Latitude => format!("{}.Latitude", cat),
             Longitude => format!("{}.Longitude", cat),
+            LatitudeIn(area) => format!("{}.Latitude(area: {})", cat, area),
+            LongitudeIn(area) => format!("{}.Longitude(area: {})", cat, area),
+            Coordinates(area) => format!("{}.Coordinates(area: {})", cat, area),
+            BankName => format!("{}.BankName", cat),
+            BankCode => format!("{}.BankCode", cat),
+            BranchName => format!("{}.BranchName", cat),
+            BranchCode => format!("{}.BranchCode", cat),
+            AccountType => format!("{}.AccountType", cat),
+            AccountNumber => format!("{}.AccountNumber", cat),
+            AccountHolder => format!("{}.AccountHolder", cat),
+            JAN => format!("{}.JAN", cat),
+            EAN8 => format!("{}.EAN8", cat),
+            UPC => format!("{}.UPC", cat),
+            ISBN10 => format!("{}.ISBN10", cat),
+            ISBN13 => format!("{}.ISBN13", cat),
+            ProductName => format!("{}.ProductName", cat),
+            ProductCategory => format!("{}.ProductCategory", cat),
+            Sku(pattern) => format!("{}.Sku(pattern: {})", cat, pattern),
+            Price(currency, range) => match range {
+                Some((from, to)) => format!(
+                    "{}.Price(currency: {}, range: {}<=n<={})",
+                    cat, currency, from, to
+                ),
+                None => format!("{}.Price(currency: {})", cat, currency),
+            },
+            CurrencyCode(currency) => format!("{}.CurrencyCode(currency: {})", cat, currency),
+            Quantity(from, to) => format!("{}.Quantity(range: {}<=n<={})", cat, from, to),
             Time(format) => format!("{}.Time(format: {})", cat, format),
             Date(format) => format!("{}.Date(format: {})", cat, format),
             DateTime(format) => format!("{}.DateTime(format: {})", cat, format),
@@ -165,32 +389,82 @@ impl std::fmt::Display for FakeOption {
 }
 
 impl FakeOption {
+    /// select from not empty lines of the file
+    pub fn select_from_file(path: &str) -> io::Result<FakeOption> {
+        Ok(FakeOption::SelectFromFile(
+            path.to_string(),
+            read_lines(path)?,
+        ))
+    }
+
+    /// select from not empty values of the column in csv file with header
+    pub fn select_from_column(path: &str, column: &str) -> io::Result<FakeOption> {
+        Ok(FakeOption::SelectFromColumn(
+            path.to_string(),
+            column.to_string(),
+            read_csv_column(path, column)?,
+        ))
+    }
+
     pub fn category(&self) -> Category {
         use FakeOption::*;
         match self {
-            Join(_, _) => Category::With,
+            Join(_, _) | WithLocale(_, _) => Category::With,
             FixedString(_) | FixedNotString(_) => Category::Fixed,
-            SelectString(_) | SelectNotString(_) => Category::Select,
+            SelectString(_)
+            | SelectNotString(_)
+            | SelectFromFile(_, _)
+            | SelectFromColumn(_, _, _) => Category::Select,
             Word | Words(_, _) | Sentence | Sentences(_, _) | Paragraph | Paragraphs(_, _) => {
                 Category::Lorem
             }
-            FirstName(_) | FirstNameFurigana | LastName(_) | LastNameFurigana | FullName(_)
-            | FullNameFurigana => Category::Name,
+            FirstName(_, _)
+            | FirstNameFurigana(_)
+            | LastName(_, _)
+            | LastNameFurigana(_)
+            | FullName(_, _)
+            | FullNameFurigana(_)
+            | FirstNameRomaji(_)
+            | LastNameRomaji(_)
+            | FullNameRomaji(_)
+            | Gender => Category::Name,
             Integer | IntegerRange(_, _) | Float | FloatRange(_, _) | Ascii(_, _) | Boolean => {
                 Category::Primitive
             }
             Email
             | UserName
             | Password(_, _)
+            | PasswordWithPolicy(_, _, _)
+            | PasswordHash(_)
             | CreditCard
+            | CreditCardNumber(_)
+            | CreditCardExpiry
+            | CreditCardCvv(_)
+            | CreditCardHolder
             | URL
+            | URLWith(_)
             | IPv4
             | IPv6
+            | IPv4InRange(_, _)
+            | IPv6InRange(_)
+            | MAC(_, _)
+            | Hostname(_)
+            | DomainName
+            | Port(_)
+            | Slug
             | RGB
             | RGBA
+            | Color(_, _)
+            | ColorName(_)
+            | CssColorName(_)
             | UserAgent
+            | UserAgentWith(_)
+            | AccessLog(_)
             | StatusCode => Category::Internet,
-            CompanySuffix | CompanyName | Industry => Category::Company,
+            CompanySuffix | CompanyName | Industry | CorporateNumber | IndividualNumber
+            | Department | JobTitle | EmployeeNumber(_) | CompanyDomain | EmployeeEmail => {
+                Category::Company
+            }
             Building
             | StreetName
             | CityName
@@ -199,10 +473,31 @@ impl FakeOption {
             | CountryName
             | TimeZone
             | Address
+            | DetailedAddress(_)
+            | AddressLine1(_)
+            | AddressLine2
             | ZipCode(_)
             | DomesticPhoneNumber(_)
+            | PhoneNumber(_, _)
+            | InternationalPhoneNumber(_)
             | Latitude
-            | Longitude => Category::Address,
+            | Longitude
+            | LatitudeIn(_)
+            | LongitudeIn(_)
+            | Coordinates(_) => Category::Address,
+            BankName | BankCode | BranchName | BranchCode | AccountType | AccountNumber
+            | AccountHolder => Category::Finance,
+            JAN
+            | EAN8
+            | UPC
+            | ISBN10
+            | ISBN13
+            | ProductName
+            | ProductCategory
+            | Sku(_)
+            | Price(_, _)
+            | CurrencyCode(_)
+            | Quantity(_, _) => Category::Commerce,
             Time(_) | Date(_) | DateTime(_) => Category::DateTime,
             FileName | Extension => Category::FileSystem,
         }
@@ -217,7 +512,11 @@ impl FakeOption {
             | IntegerRange(_, _)
             | Float
             | FloatRange(_, _)
-            | Boolean => false,
+            | Boolean
+            | Port(_)
+            | Price(_, _)
+            | Quantity(_, _) => false,
+            WithLocale(_, option) => option.is_string_type(),
             _ => true,
         }
     }
@@ -230,11 +529,59 @@ impl FakeOption {
         }
     }
 
+    /// data which depends on the state such as address and landline phone number
+    pub fn is_in_state(&self) -> bool {
+        use FakeOption::*;
+        matches!(
+            self,
+            StateName
+                | Address
+                | DetailedAddress(_)
+                | AddressLine1(_)
+                | PhoneNumber(_, _)
+                | InternationalPhoneNumber(_)
+        )
+    }
+
+    /// address whose city, street, building and room are shared in the record
+    pub fn is_detailed_address(&self) -> bool {
+        use FakeOption::*;
+        matches!(self, DetailedAddress(_) | AddressLine1(_) | AddressLine2)
+    }
+
+    /// data of the bank account whose name and code must agree
+    pub fn is_bank_account(&self) -> bool {
+        use FakeOption::*;
+        matches!(self, BankName | BankCode | BranchName | BranchCode)
+    }
+
+    /// company whose name and domain are shared in the record
+    pub fn is_company(&self) -> bool {
+        use FakeOption::*;
+        matches!(self, CompanyName | CompanyDomain | EmployeeEmail)
+    }
+
+    /// password whose plaintext is shared with PasswordHash
+    pub fn is_password(&self) -> bool {
+        use FakeOption::*;
+        matches!(self, Password(_, _) | PasswordWithPolicy(_, _, _))
+    }
+
     pub fn is_person_name(&self) -> bool {
         use FakeOption::*;
         match self {
-            FirstName(_) | FirstNameFurigana | LastName(_) | LastNameFurigana | FullName(_)
-            | FullNameFurigana => true,
+            FirstName(_, _)
+            | FirstNameFurigana(_)
+            | LastName(_, _)
+            | LastNameFurigana(_)
+            | FullName(_, _)
+            | FullNameFurigana(_)
+            | FirstNameRomaji(_)
+            | LastNameRomaji(_)
+            | FullNameRomaji(_)
+            | Gender
+            | CreditCardHolder
+            | AccountHolder => true,
             _ => false,
         }
     }
@@ -0,0 +1,25 @@
+/// gender of person name
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+impl Gender {
+    pub fn all_list() -> Vec<Self> {
+        use Gender::*;
+        vec![Male, Female]
+    }
+}
+
+impl std::fmt::Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use Gender::*;
+        let s: String = match self {
+            Male => "male",
+            Female => "female",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,163 @@
+/// micro degree in one degree
+const MICRO_DEGREE: f64 = 1_000_000.0;
+
+/// half of the circumference of the earth in meter. circle of this radius covers the whole earth.
+const MAX_RADIUS: u32 = 20_015_087;
+
+/// area of latitude and longitude.
+/// Globe is the whole earth, Country is land of the country of the locale and State is land of the state in the record.
+/// BoundingBox is (south, west, north, east) and Circle is (latitude, longitude, radius) of the center.
+/// degree is in micro degree and radius is in meter. make them from degree and km by bounding_box and circle.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum GeoArea {
+    #[default]
+    Globe,
+    Country,
+    State,
+    BoundingBox(i32, i32, i32, i32),
+    Circle(i32, i32, u32),
+}
+
+/// micro degree such as 35681236 to degree such as 35.681236
+pub fn degree(micro_degree: i32) -> f64 {
+    micro_degree as f64 / MICRO_DEGREE
+}
+
+// degree to micro degree if it is in -limit..=limit
+fn micro_degree(degree: f64, limit: f64) -> Option<i32> {
+    if (-limit..=limit).contains(&degree) {
+        Some((degree * MICRO_DEGREE).round() as i32)
+    } else {
+        None
+    }
+}
+
+impl GeoArea {
+    /// box of degree such as (30.0, 129.0, 46.0, 146.0). west greater than east is the box across the 180th meridian.
+    /// None if latitudes are not in -90..=90, longitudes are not in -180..=180 or south is north of north.
+    pub fn bounding_box(south: f64, west: f64, north: f64, east: f64) -> Option<Self> {
+        let area: GeoArea = GeoArea::BoundingBox(
+            micro_degree(south, 90.0)?,
+            micro_degree(west, 180.0)?,
+            micro_degree(north, 90.0)?,
+            micro_degree(east, 180.0)?,
+        );
+        if area.is_usable() {
+            Some(area)
+        } else {
+            None
+        }
+    }
+
+    /// circle of the center in degree and the radius in km such as (35.68, 139.76, 10.0).
+    /// None if latitude is not in -90..=90, longitude is not in -180..=180 or radius is not in 0.001..=20015.087.
+    pub fn circle(latitude: f64, longitude: f64, radius: f64) -> Option<Self> {
+        let radius: f64 = (radius * 1000.0).round();
+        if !(1.0..=MAX_RADIUS as f64).contains(&radius) {
+            return None;
+        }
+        Some(GeoArea::Circle(
+            micro_degree(latitude, 90.0)?,
+            micro_degree(longitude, 180.0)?,
+            radius as u32,
+        ))
+    }
+
+    /// name of the kind of the area such as "box"
+    pub fn name(&self) -> &'static str {
+        use GeoArea::*;
+        match self {
+            Globe => "globe",
+            Country => "country",
+            State => "state",
+            BoundingBox(_, _, _, _) => "box",
+            Circle(_, _, _) => "circle",
+        }
+    }
+
+    /// values of the area in degree and km such as [south, west, north, east] of the box
+    pub fn values(&self) -> Vec<f64> {
+        use GeoArea::*;
+        match *self {
+            Globe | Country | State => vec![],
+            BoundingBox(south, west, north, east) => {
+                vec![degree(south), degree(west), degree(north), degree(east)]
+            }
+            Circle(latitude, longitude, radius) => {
+                vec![degree(latitude), degree(longitude), radius as f64 / 1000.0]
+            }
+        }
+    }
+
+    /// whether the values are in the ranges of bounding_box and circle
+    pub fn is_usable(&self) -> bool {
+        let is_latitude = |v: i32| (-90_000_000..=90_000_000).contains(&v);
+        let is_longitude = |v: i32| (-180_000_000..=180_000_000).contains(&v);
+        match *self {
+            GeoArea::BoundingBox(south, west, north, east) => {
+                is_latitude(south)
+                    && is_latitude(north)
+                    && south <= north
+                    && is_longitude(west)
+                    && is_longitude(east)
+            }
+            GeoArea::Circle(latitude, longitude, radius) => {
+                is_latitude(latitude)
+                    && is_longitude(longitude)
+                    && (1..=MAX_RADIUS).contains(&radius)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl std::fmt::Display for GeoArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let values: Vec<String> = self.values().iter().map(|v| v.to_string()).collect();
+        if values.is_empty() {
+            write!(f, "{}", self.name())
+        } else {
+            write!(f, "{}({})", self.name(), values.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_in_micro_degree_and_meter() {
+        assert_eq!(
+            GeoArea::bounding_box(30.0, 129.0, 46.0, 146.0),
+            Some(GeoArea::BoundingBox(
+                30_000_000,
+                129_000_000,
+                46_000_000,
+                146_000_000
+            ))
+        );
+        assert_eq!(
+            GeoArea::circle(35.681236, 139.767125, 10.5),
+            Some(GeoArea::Circle(35_681_236, 139_767_125, 10_500))
+        );
+        assert_eq!(
+            GeoArea::circle(35.681236, 139.767125, 10.5)
+                .unwrap()
+                .to_string(),
+            "circle(35.681236, 139.767125, 10.5)"
+        );
+    }
+
+    #[test]
+    fn area_out_of_range_is_none() {
+        assert_eq!(GeoArea::bounding_box(-91.0, 0.0, 0.0, 0.0), None);
+        assert_eq!(GeoArea::bounding_box(0.0, 0.0, 0.0, 180.5), None);
+        // south is north of north
+        assert_eq!(GeoArea::bounding_box(10.0, 0.0, 0.0, 1.0), None);
+        assert_eq!(GeoArea::circle(0.0, 0.0, 0.0), None);
+        assert_eq!(GeoArea::circle(0.0, 0.0, 20015.088), None);
+        assert!(GeoArea::circle(0.0, 0.0, 20015.087).is_some());
+        assert!(!GeoArea::Circle(0, 0, MAX_RADIUS + 1).is_usable());
+    }
+}
@@ -0,0 +1,26 @@
+/// format of hashed password such as SHA-256 hex and "$5$salt$hash" of crypt
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum HashFormat {
+    #[default]
+    Sha256,
+    Sha256Crypt,
+}
+
+impl HashFormat {
+    pub fn all_list() -> Vec<Self> {
+        use HashFormat::*;
+        vec![Sha256, Sha256Crypt]
+    }
+}
+
+impl std::fmt::Display for HashFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use HashFormat::*;
+        let s: String = match self {
+            Sha256 => "sha256",
+            Sha256Crypt => "sha256_crypt",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
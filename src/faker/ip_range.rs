@@ -0,0 +1,149 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// range of IP address such as private addresses and CIDR block "10.0.0.0/8"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum IpRange {
+    Private,
+    #[default]
+    Documentation,
+    Loopback,
+    LinkLocal,
+    // network address and prefix length
+    Cidr(IpAddr, u8),
+}
+
+impl IpRange {
+    /// named ranges without CIDR block
+    pub fn all_list() -> Vec<Self> {
+        use IpRange::*;
+        vec![Private, Documentation, Loopback, LinkLocal]
+    }
+
+    /// blocks of IPv4 as network address and prefix length.
+    /// CIDR block of IPv6 is not IPv4, so it is documentation blocks.
+    pub fn ipv4_blocks(&self) -> Vec<(Ipv4Addr, u8)> {
+        use IpRange::*;
+        match self {
+            Private => vec![
+                (Ipv4Addr::new(10, 0, 0, 0), 8),
+                (Ipv4Addr::new(172, 16, 0, 0), 12),
+                (Ipv4Addr::new(192, 168, 0, 0), 16),
+            ],
+            Documentation | Cidr(IpAddr::V6(_), _) => vec![
+                (Ipv4Addr::new(192, 0, 2, 0), 24),
+                (Ipv4Addr::new(198, 51, 100, 0), 24),
+                (Ipv4Addr::new(203, 0, 113, 0), 24),
+            ],
+            Loopback => vec![(Ipv4Addr::new(127, 0, 0, 0), 8)],
+            LinkLocal => vec![(Ipv4Addr::new(169, 254, 0, 0), 16)],
+            Cidr(IpAddr::V4(network), prefix) => vec![(*network, *prefix)],
+        }
+    }
+
+    /// blocks of IPv6 as network address and prefix length.
+    /// CIDR block of IPv4 is IPv4-mapped IPv6 address such as ::ffff:10.0.0.0/104.
+    pub fn ipv6_blocks(&self) -> Vec<(Ipv6Addr, u8)> {
+        use IpRange::*;
+        match self {
+            Private => vec![(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 0), 8)],
+            Documentation => vec![(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)],
+            Loopback => vec![(Ipv6Addr::LOCALHOST, 128)],
+            LinkLocal => vec![(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 64)],
+            Cidr(IpAddr::V6(network), prefix) => vec![(*network, *prefix)],
+            Cidr(IpAddr::V4(network), prefix) => vec![(network.to_ipv6_mapped(), 96 + *prefix)],
+        }
+    }
+}
+
+/// address of the host part in the block such as 10.0.0.1 of 10.0.0.0/8 and 1.
+/// bits of the network out of the prefix and bits of the host in the prefix are cleared.
+pub fn ipv4_in_block(network: Ipv4Addr, prefix: u8, host: u32) -> Ipv4Addr {
+    let bits: u32 = 32 - prefix.min(32) as u32;
+    let mask: u32 = u32::MAX.checked_shl(bits).unwrap_or(0);
+    Ipv4Addr::from(u32::from(network) & mask | host & !mask)
+}
+
+/// address of the host part in the block such as 2001:db8::1 of 2001:db8::/32 and 1.
+/// bits of the network out of the prefix and bits of the host in the prefix are cleared.
+pub fn ipv6_in_block(network: Ipv6Addr, prefix: u8, host: u128) -> Ipv6Addr {
+    let bits: u32 = 128 - prefix.min(128) as u32;
+    let mask: u128 = u128::MAX.checked_shl(bits).unwrap_or(0);
+    Ipv6Addr::from(u128::from(network) & mask | host & !mask)
+}
+
+impl std::fmt::Display for IpRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use IpRange::*;
+        let s: String = match self {
+            Private => "private".to_string(),
+            Documentation => "documentation".to_string(),
+            Loopback => "loopback".to_string(),
+            LinkLocal => "link_local".to_string(),
+            Cidr(network, prefix) => format!("{}/{}", network, prefix),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ipv4_in_block_keeps_only_network_bits_of_the_prefix() {
+        let network: Ipv4Addr = Ipv4Addr::new(10, 1, 2, 3);
+        assert_eq!(ipv4_in_block(network, 8, 0), Ipv4Addr::new(10, 0, 0, 0));
+        assert_eq!(ipv4_in_block(network, 8, 1), Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(
+            ipv4_in_block(network, 8, 0x00ff_ffff),
+            Ipv4Addr::new(10, 255, 255, 255)
+        );
+        assert_eq!(
+            ipv4_in_block(Ipv4Addr::new(172, 16, 0, 0), 12, 0x000f_ffff),
+            Ipv4Addr::new(172, 31, 255, 255)
+        );
+        // host bits in the prefix are ignored
+        assert_eq!(
+            ipv4_in_block(Ipv4Addr::new(192, 168, 0, 0), 16, u32::MAX),
+            Ipv4Addr::new(192, 168, 255, 255)
+        );
+        assert_eq!(ipv4_in_block(network, 32, 5), network);
+        assert_eq!(
+            ipv4_in_block(network, 0, 0x0102_0304),
+            Ipv4Addr::new(1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn ipv6_in_block_keeps_only_network_bits_of_the_prefix() {
+        let documentation: Ipv6Addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+        assert_eq!(
+            ipv6_in_block(documentation, 32, 1),
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)
+        );
+        assert_eq!(
+            ipv6_in_block(documentation, 32, u128::MAX),
+            Ipv6Addr::new(0x2001, 0xdb8, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff)
+        );
+        assert_eq!(
+            ipv6_in_block(Ipv6Addr::LOCALHOST, 128, 7),
+            Ipv6Addr::LOCALHOST
+        );
+        assert_eq!(ipv6_in_block(documentation, 0, 1), Ipv6Addr::LOCALHOST);
+    }
+
+    #[test]
+    fn cidr_block_of_ipv4_is_ipv4_mapped_in_ipv6() {
+        let range: IpRange = IpRange::Cidr(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8);
+        assert_eq!(range.ipv4_blocks(), vec![(Ipv4Addr::new(10, 0, 0, 0), 8)]);
+        let blocks: Vec<(Ipv6Addr, u8)> = range.ipv6_blocks();
+        assert_eq!(
+            blocks,
+            vec![(Ipv4Addr::new(10, 0, 0, 0).to_ipv6_mapped(), 104)]
+        );
+        assert_eq!(
+            ipv6_in_block(blocks[0].0, blocks[0].1, 0x00ff_ffff).to_string(),
+            "::ffff:10.255.255.255"
+        );
+    }
+}
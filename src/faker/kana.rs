@@ -0,0 +1,109 @@
+/// script of kana for furigana such as "ヤマダ" and "やまだ"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum KanaScript {
+    #[default]
+    Katakana,
+    Hiragana,
+}
+
+impl KanaScript {
+    pub fn all_list() -> Vec<Self> {
+        use KanaScript::*;
+        vec![Katakana, Hiragana]
+    }
+
+    /// convert kana in the text to the script, and other characters such as "ー" pass through.
+    pub fn convert(&self, text: &str) -> String {
+        text.chars().map(|c| self.convert_char(c)).collect()
+    }
+
+    fn convert_char(&self, c: char) -> char {
+        use KanaScript::*;
+        // "ァ" to "ヶ" and "ぁ" to "ゖ" are the same order.
+        let converted: Option<char> = match (self, c) {
+            (Katakana, 'ぁ'..='ゖ') => std::char::from_u32(c as u32 + 0x60),
+            (Hiragana, 'ァ'..='ヶ') => std::char::from_u32(c as u32 - 0x60),
+            _ => None,
+        };
+        converted.unwrap_or(c)
+    }
+}
+
+impl std::fmt::Display for KanaScript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use KanaScript::*;
+        let s: String = match self {
+            Katakana => "katakana",
+            Hiragana => "hiragana",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+const FULL_WIDTH_KANA: &str =
+    "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
+const HALF_WIDTH_KANA: &str = "ｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜｦﾝ";
+
+/// half-width katakana for zengin transfer such as "ﾔﾏﾀﾞ ﾀﾛｳ".
+/// small kana are written as large kana, "ー" is "-" and alphabets are in upper case.
+pub fn zengin_kana(text: &str) -> String {
+    let mut zengin = String::new();
+    for c in KanaScript::Katakana.convert(text).chars() {
+        // voiced kana such as "ガ" follow the unvoiced one, and also small kana such as "ァ" precede the large one.
+        let (base, mark): (char, Option<char>) = match c {
+            'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ャ' | 'ュ' | 'ョ' | 'ッ' | 'ヮ' => {
+                (std::char::from_u32(c as u32 + 1).unwrap_or(c), None)
+            }
+            'ガ' | 'ギ' | 'グ' | 'ゲ' | 'ゴ' | 'ザ' | 'ジ' | 'ズ' | 'ゼ' | 'ゾ' | 'ダ' | 'ヂ'
+            | 'ヅ' | 'デ' | 'ド' | 'バ' | 'ビ' | 'ブ' | 'ベ' | 'ボ' => {
+                (std::char::from_u32(c as u32 - 1).unwrap_or(c), Some('ﾞ'))
+            }
+            'パ' | 'ピ' | 'プ' | 'ペ' | 'ポ' => {
+                (std::char::from_u32(c as u32 - 2).unwrap_or(c), Some('ﾟ'))
+            }
+            'ヴ' => ('ウ', Some('ﾞ')),
+            'ー' | '－' => ('-', None),
+            '　' => (' ', None),
+            _ => (c.to_ascii_uppercase(), None),
+        };
+        match FULL_WIDTH_KANA.chars().position(|k| k == base) {
+            Some(index) => zengin.push(HALF_WIDTH_KANA.chars().nth(index).unwrap_or(base)),
+            None => zengin.push(base),
+        }
+        if let Some(mark) = mark {
+            zengin.push(mark);
+        }
+    }
+    zengin
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_between_katakana_and_hiragana() {
+        assert_eq!(
+            KanaScript::Hiragana.convert("ヤマダ タロウ"),
+            "やまだ たろう"
+        );
+        assert_eq!(
+            KanaScript::Katakana.convert("やまだ たろう"),
+            "ヤマダ タロウ"
+        );
+        // small kana and the last kana of the same order
+        assert_eq!(KanaScript::Hiragana.convert("ァヴヶ"), "ぁゔゖ");
+        assert_eq!(KanaScript::Katakana.convert("ぁゔゖ"), "ァヴヶ");
+        // other characters pass through
+        assert_eq!(KanaScript::Hiragana.convert("ユーコ・A1"), "ゆーこ・A1");
+    }
+
+    #[test]
+    fn zengin_kana_is_half_width_large_kana() {
+        assert_eq!(zengin_kana("ヤマダ　タロウ"), "ﾔﾏﾀﾞ ﾀﾛｳ");
+        assert_eq!(zengin_kana("きょうこ"), "ｷﾖｳｺ");
+        assert_eq!(zengin_kana("ポーヴァ"), "ﾎﾟ-ｳﾞｱ");
+        assert_eq!(zengin_kana("abc"), "ABC");
+    }
+}
@@ -1,6 +1,8 @@
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 pub enum Locale {
     Japan,
+    UnitedStates,
+    Korea,
 }
 
 impl Default for Locale {
@@ -9,11 +11,36 @@ impl Default for Locale {
     }
 }
 
+impl Locale {
+    pub fn all_list() -> Vec<Self> {
+        use Locale::*;
+        vec![Japan, UnitedStates, Korea]
+    }
+
+    /// 3-char's country code such as "jpn"
+    pub fn code(&self) -> &'static str {
+        use Locale::*;
+        match self {
+            Japan => "jpn",
+            UnitedStates => "usa",
+            Korea => "kor",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::all_list()
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(code))
+    }
+}
+
 impl std::fmt::Display for Locale {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         use Locale::*;
         let s: String = match self {
             Japan => "Japan",
+            UnitedStates => "United States",
+            Korea => "Korea",
         }
         .to_string();
 
@@ -0,0 +1,46 @@
+/// style of MAC address such as "00:1a:2b:3c:4d:5e" and "001a.2b3c.4d5e"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum MacStyle {
+    #[default]
+    Colon,
+    Hyphen,
+    Dot,
+    Plain,
+}
+
+impl MacStyle {
+    pub fn all_list() -> Vec<Self> {
+        use MacStyle::*;
+        vec![Colon, Hyphen, Dot, Plain]
+    }
+
+    /// octets in the style. hyphen is in upper case, and others are in lower case.
+    pub fn format(&self, octets: &[u8]) -> String {
+        use MacStyle::*;
+        let hex: Vec<String> = octets.iter().map(|o| format!("{:02x}", o)).collect();
+        match self {
+            Colon => hex.join(":"),
+            Hyphen => hex.join("-").to_uppercase(),
+            Dot => hex
+                .chunks(2)
+                .map(|pair| pair.concat())
+                .collect::<Vec<String>>()
+                .join("."),
+            Plain => hex.concat(),
+        }
+    }
+}
+
+impl std::fmt::Display for MacStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use MacStyle::*;
+        let s: String = match self {
+            Colon => "colon",
+            Hyphen => "hyphen",
+            Dot => "dot",
+            Plain => "plain",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
@@ -1,23 +1,55 @@
 pub mod each_locale;
 
+pub mod access_log;
+pub mod address_style;
+pub mod card_brand;
 pub mod category;
+pub mod color_format;
+pub mod currency;
 pub mod fake_options;
+pub mod gender;
+pub mod geo_area;
+pub mod hash_format;
+pub mod ip_range;
+pub mod kana;
 pub mod locale;
+pub mod mac_style;
+pub mod password_policy;
+pub mod phone_kind;
+pub mod port_range;
+pub mod romaji;
+pub mod url_format;
+pub mod user_agent;
 
-use crate::helper::split;
+use crate::helper::{gen_range, split};
 
-use crate::faker::each_locale::Generator;
+use crate::faker::currency::Currency;
+use crate::faker::each_locale::pack::LocalePack;
+use crate::faker::each_locale::{AddressParts, Generator};
 use crate::faker::fake_options::FakeOption;
+use crate::faker::gender::Gender;
+use crate::faker::geo_area::GeoArea;
+use crate::faker::kana::{zengin_kana, KanaScript};
 use crate::faker::locale::Locale;
+use crate::faker::password_policy::PasswordPolicy;
+use crate::faker::romaji::{card_holder, romanize};
 
+use chrono::{DateTime, Duration, Local};
 use rand::prelude::ThreadRng;
+use rand::seq::SliceRandom;
 use rand::{thread_rng, Rng};
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct Faker<R: Rng> {
     rng: R,
     locale: Locale,
     generator: Generator,
+    // generator and its weight for selecting a locale of each record.
+    // If empty, use generator for all records.
+    locale_mix: Vec<(Generator, u32)>,
+    // time of the last access log. time of access log increases for each record.
+    clock: Option<DateTime<Local>>,
 }
 
 impl Default for Faker<ThreadRng> {
@@ -26,6 +58,8 @@ impl Default for Faker<ThreadRng> {
             rng: thread_rng(),
             locale: Locale::default(),
             generator: Generator::new(Locale::default()),
+            locale_mix: Vec::new(),
+            clock: None,
         }
     }
 }
@@ -36,6 +70,38 @@ impl<R: Rng> Faker<R> {
             rng,
             locale,
             generator: Generator::new(locale),
+            locale_mix: Vec::new(),
+            clock: None,
+        }
+    }
+
+    /// faker for locale of the pack which use data in the pack before data of the locale.
+    pub fn with_locale_pack(rng: R, pack: Arc<LocalePack>) -> Faker<R> {
+        Faker {
+            rng,
+            locale: pack.locale(),
+            generator: Generator::with_pack(pack),
+            locale_mix: Vec::new(),
+            clock: None,
+        }
+    }
+
+    /// faker which select a locale for each record by the weight such as [(Japan, 70), (UnitedStates, 30)].
+    /// The locale of faker is the first locale. If all weights are 0, use only the locale of faker.
+    pub fn with_locale_mix(rng: R, locale_mix: &[(Locale, u32)]) -> Faker<R> {
+        let locale: Locale = locale_mix
+            .first()
+            .map(|(locale, _)| *locale)
+            .unwrap_or_default();
+        Faker {
+            rng,
+            locale,
+            generator: Generator::new(locale),
+            locale_mix: locale_mix
+                .iter()
+                .map(|(locale, weight)| (Generator::new(*locale), *weight))
+                .collect(),
+            clock: None,
         }
     }
 
@@ -43,68 +109,294 @@ impl<R: Rng> Faker<R> {
         self.locale
     }
 
+    /// weight of male and female for selecting gender of person name such as (60, 40).
+    /// If male is 0, generate only female. If both are 0, use the same weight.
+    pub fn set_gender_ratio(&mut self, male: u32, female: u32) {
+        self.generator.set_gender_ratio(male, female);
+        for (generator, _) in self.locale_mix.iter_mut() {
+            generator.set_gender_ratio(male, female);
+        }
+    }
+
     /// one data
     pub fn gen(&mut self, option: &FakeOption) -> String {
+        if let FakeOption::AccessLog(format) = option {
+            let time: DateTime<Local> = self.tick();
+            return self.generator.gen_access_log(&mut self.rng, format, time);
+        }
         self.generator.gen(&mut self.rng, option)
     }
 
-    fn gen_name_set(&mut self, options: &[FakeOption]) -> Option<PersonName> {
-        if options.iter().any(|op| op.is_person_name()) {
-            Some(PersonName::new(&mut self.rng, &mut self.generator))
-        } else {
-            None
+    // next time of access log which increases from 1 millisecond up to a second.
+    // If the clock is not started, it starts at a day ago.
+    fn tick(&mut self) -> DateTime<Local> {
+        let time: DateTime<Local> = match self.clock {
+            Some(clock) => clock + Duration::milliseconds(gen_range(&mut self.rng, 1..=1000)),
+            None => Local::now() - Duration::days(1),
+        };
+        self.clock = Some(time);
+        time
+    }
+
+    // start the clock so that the access log of the last record is at about now
+    fn start_clock(&mut self, count: usize) {
+        if self.clock.is_none() {
+            // mean of the step is 500.5 milliseconds
+            let elapsed: i64 = (count as i64).saturating_mul(1001) / 2;
+            self.clock = Some(Local::now() - Duration::milliseconds(elapsed));
         }
     }
 
+    fn record_generator(&mut self) -> Generator {
+        let generator: Generator = self.generator.clone();
+        self.locale_mix
+            .choose_weighted(&mut self.rng, |(_, weight)| *weight as u64)
+            .map(|(generator, _)| generator.clone())
+            .unwrap_or(generator)
+    }
+
     /// one record
     /// when option is "With.xxx", ignore "name" and so on.
+    /// all data of the record is generated with the same locale except "With.Locale".
+    /// So, person name is the same person and state is the same state in the record for each locale.
     pub fn gen_record(&mut self, options: &[FakeOption]) -> Vec<String> {
-        let mut record: Vec<String> = Vec::new();
-        let person_name: Option<PersonName> = self.gen_name_set(options);
+        let generator: Generator = self.record_generator();
+        let mut context: RecordContext = RecordContext {
+            password_option: options.iter().find(|o| o.is_password()).cloned(),
+            ..RecordContext::default()
+        };
+        options
+            .iter()
+            .map(|option| self.gen_in_record(generator.clone(), &mut context, option))
+            .collect()
+    }
 
-        if person_name.is_none() {
-            for option in options {
-                record.push(self.gen(option));
+    fn gen_in_record(
+        &mut self,
+        mut generator: Generator,
+        context: &mut RecordContext,
+        option: &FakeOption,
+    ) -> String {
+        if let FakeOption::WithLocale(locale, option) = option {
+            let generator: Generator = self.generator.for_locale(*locale);
+            return self.gen_in_record(generator, context, option);
+        }
+        let locale: Locale = generator.locale();
+        if option.is_detailed_address() {
+            let index: usize = self.state_index(&mut generator, context);
+            let address: AddressParts = match context.addresses.iter().find(|(l, _)| *l == locale) {
+                Some((_, address)) => address.clone(),
+                None => {
+                    let address: AddressParts = generator.gen_address_parts(&mut self.rng);
+                    context.addresses.push((locale, address.clone()));
+                    address
+                }
+            };
+            return generator.format_address(option, &address, &context.states[index].1);
+        }
+        if option.is_in_state() {
+            let index: usize = self.state_index(&mut generator, context);
+            return generator.gen_in_state(&mut self.rng, option, &context.states[index].1);
+        }
+        if let FakeOption::LatitudeIn(area)
+        | FakeOption::LongitudeIn(area)
+        | FakeOption::Coordinates(area) = option
+        {
+            let point: (f64, f64) = match context
+                .points
+                .iter()
+                .find(|(l, a, _)| *l == locale && a == area)
+            {
+                Some((_, _, point)) => *point,
+                None => {
+                    let state: Option<String> = if *area == GeoArea::State {
+                        let index: usize = self.state_index(&mut generator, context);
+                        Some(context.states[index].1.to_string())
+                    } else {
+                        None
+                    };
+                    let point: (f64, f64) =
+                        generator.gen_point(&mut self.rng, *area, state.as_deref());
+                    context.points.push((locale, *area, point));
+                    point
+                }
+            };
+            return generator.format_point(option, point);
+        }
+        if option.is_bank_account() {
+            let bank_accounts: &mut Vec<BankAccount> = &mut context.bank_accounts;
+            let index: usize = match bank_accounts.iter().position(|b| b.locale == locale) {
+                Some(index) => index,
+                None => {
+                    bank_accounts.push(BankAccount::new(&mut self.rng, &generator));
+                    bank_accounts.len() - 1
+                }
+            };
+            let bank_account: &BankAccount = &bank_accounts[index];
+            return match option {
+                FakeOption::BankName => bank_account.bank_name.to_string(),
+                FakeOption::BankCode => bank_account.bank_code.to_string(),
+                FakeOption::BranchName => bank_account.branch_name.to_string(),
+                FakeOption::BranchCode => bank_account.branch_code.to_string(),
+                _ => generator.gen(&mut self.rng, option),
+            };
+        }
+        if option.is_company() {
+            let companies: &mut Vec<Company> = &mut context.companies;
+            let index: usize = match companies.iter().position(|c| c.locale == locale) {
+                Some(index) => index,
+                None => {
+                    companies.push(Company::new(&mut self.rng, &generator));
+                    companies.len() - 1
+                }
+            };
+            let company: Company = companies[index].clone();
+            return match option {
+                FakeOption::CompanyName => company.name,
+                FakeOption::CompanyDomain => company.domain,
+                _ => {
+                    let index: usize = self.person_name_index(&mut generator, context);
+                    let person_name: &PersonName = &context.person_names[index];
+                    generator.build_employee_email(
+                        &person_name.first_name_furigana,
+                        &person_name.last_name_furigana,
+                        &company.domain,
+                    )
+                }
+            };
+        }
+        if let FakeOption::Price(Currency::Any, _) | FakeOption::CurrencyCode(Currency::Any) =
+            option
+        {
+            let rng: &mut R = &mut self.rng;
+            let currency: Currency = *context
+                .currency
+                .get_or_insert_with(|| *Currency::code_list().choose(rng).unwrap());
+            let option: FakeOption = match option {
+                FakeOption::Price(_, range) => FakeOption::Price(currency, *range),
+                _ => FakeOption::CurrencyCode(currency),
+            };
+            return generator.gen(&mut self.rng, &option);
+        }
+        if let FakeOption::AccessLog(format) = option {
+            // DateTime is also a variant of FakeOption in this function
+            let time = match context.access_time {
+                Some(time) => time,
+                None => self.tick(),
+            };
+            context.access_time = Some(time);
+            return generator.gen_access_log(&mut self.rng, format, time);
+        }
+        if option.is_password() && context.is_password_hashed {
+            // only the first password column is hashed
+            return generator.gen(&mut self.rng, option);
+        }
+        if option.is_password() || matches!(option, FakeOption::PasswordHash(_)) {
+            if option.is_password() {
+                context.is_password_hashed = true;
             }
-            return record;
-        } else {
-            let person_name: PersonName = person_name.unwrap();
-            for option in options {
-                use FakeOption::*;
-                let dummy: String = match option {
-                    FirstName(false) => person_name.first_name.to_string(),
-                    FirstName(true) => [
-                        person_name.first_name.to_string(),
-                        person_name.first_name_furigana.to_string(),
-                    ]
-                    .join(":"),
-                    FirstNameFurigana => person_name.first_name_furigana.to_string(),
-                    LastName(false) => person_name.last_name.to_string(),
-                    LastName(true) => [
-                        person_name.last_name.to_string(),
-                        person_name.last_name_furigana.to_string(),
-                    ]
-                    .join(":"),
-                    LastNameFurigana => person_name.last_name_furigana.to_string(),
-                    FullName(false) => person_name.full_name.to_string(),
-                    FullName(true) => [
-                        person_name.full_name.to_string(),
-                        person_name.full_name_furigana.to_string(),
-                    ]
-                    .join(":"),
-                    FullNameFurigana => person_name.full_name_furigana.to_string(),
-                    _ => self.gen(option),
-                };
-                record.push(dummy);
+            if context.password.is_none() {
+                let password_option: FakeOption =
+                    context
+                        .password_option
+                        .clone()
+                        .unwrap_or(FakeOption::PasswordWithPolicy(
+                            8,
+                            16,
+                            PasswordPolicy::default(),
+                        ));
+                context.password = Some(generator.gen(&mut self.rng, &password_option));
             }
+            let password: &str = context.password.as_deref().unwrap_or_default();
+            return match option {
+                FakeOption::PasswordHash(format) => {
+                    generator.gen_password_hash(&mut self.rng, *format, password)
+                }
+                _ => password.to_string(),
+            };
+        }
+        if !option.is_person_name() {
+            return generator.gen(&mut self.rng, option);
+        }
 
-            return record;
+        let index: usize = self.person_name_index(&mut generator, context);
+        let person_name: &PersonName = &context.person_names[index];
+        use FakeOption::*;
+        match option {
+            FirstName(false, _) => person_name.first_name.to_string(),
+            FirstName(true, script) => [
+                person_name.first_name.to_string(),
+                script.convert(&person_name.first_name_furigana),
+            ]
+            .join(":"),
+            FirstNameFurigana(script) => script.convert(&person_name.first_name_furigana),
+            LastName(false, _) => person_name.last_name.to_string(),
+            LastName(true, script) => [
+                person_name.last_name.to_string(),
+                script.convert(&person_name.last_name_furigana),
+            ]
+            .join(":"),
+            LastNameFurigana(script) => script.convert(&person_name.last_name_furigana),
+            FullName(false, _) => person_name.full_name.to_string(),
+            FullName(true, script) => [
+                person_name.full_name.to_string(),
+                script.convert(&person_name.full_name_furigana),
+            ]
+            .join(":"),
+            FullNameFurigana(script) => script.convert(&person_name.full_name_furigana),
+            FirstNameRomaji(capitalization) => {
+                romanize(&person_name.first_name_furigana, *capitalization)
+            }
+            LastNameRomaji(capitalization) => {
+                romanize(&person_name.last_name_furigana, *capitalization)
+            }
+            FullNameRomaji(capitalization) => {
+                romanize(&person_name.full_name_furigana, *capitalization)
+            }
+            FakeOption::Gender => generator.gender_label(person_name.gender).to_string(),
+            CreditCardHolder => card_holder(
+                &person_name.first_name_furigana,
+                &person_name.last_name_furigana,
+            ),
+            AccountHolder => zengin_kana(&person_name.full_name_furigana),
+            _ => generator.gen(&mut self.rng, option),
+        }
+    }
+
+    // index of the state of the locale in the record
+    fn state_index(&mut self, generator: &mut Generator, context: &mut RecordContext) -> usize {
+        let locale: Locale = generator.locale();
+        match context.states.iter().position(|(l, _)| *l == locale) {
+            Some(index) => index,
+            None => {
+                let state: String = generator.gen(&mut self.rng, &FakeOption::StateName);
+                context.states.push((locale, state));
+                context.states.len() - 1
+            }
+        }
+    }
+
+    // index of the person name of the locale in the record
+    fn person_name_index(
+        &mut self,
+        generator: &mut Generator,
+        context: &mut RecordContext,
+    ) -> usize {
+        let locale: Locale = generator.locale();
+        let person_names: &mut Vec<PersonName> = &mut context.person_names;
+        match person_names.iter().position(|p| p.locale == locale) {
+            Some(index) => index,
+            None => {
+                person_names.push(PersonName::new(&mut self.rng, generator));
+                person_names.len() - 1
+            }
         }
     }
 
     /// many record
     pub fn gen_data_set(&mut self, count: usize, options: &[FakeOption]) -> Vec<Vec<String>> {
         let mut data_set: Vec<Vec<String>> = Vec::new();
+        self.start_clock(count);
         for _ in 1..=count {
             data_set.push(self.gen_record(options));
         }
@@ -112,8 +404,32 @@ impl<R: Rng> Faker<R> {
     }
 }
 
+/// data shared by columns in one record for each locale
+#[derive(Debug, Default)]
+struct RecordContext {
+    person_names: Vec<PersonName>,
+    states: Vec<(Locale, String)>,
+    // address columns of the locale are formatted from the same parts in the state
+    addresses: Vec<(Locale, AddressParts)>,
+    bank_accounts: Vec<BankAccount>,
+    companies: Vec<Company>,
+    // plaintext of password and its hash agree. the plaintext is of the first password option in the record,
+    // and other password options are generated by themselves.
+    password: Option<String>,
+    password_option: Option<FakeOption>,
+    is_password_hashed: bool,
+    // access logs in a record are at the same time
+    access_time: Option<DateTime<Local>>,
+    // currency of Currency::Any selected for the record
+    currency: Option<Currency>,
+    // latitude and longitude columns of the same area are of the same point
+    points: Vec<(Locale, GeoArea, (f64, f64))>,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 struct PersonName {
+    locale: Locale,
+    gender: Gender,
     first_name: String,
     first_name_furigana: String,
     last_name: String,
@@ -124,13 +440,17 @@ struct PersonName {
 
 impl PersonName {
     fn new<R: Rng>(rng: &mut R, generator: &mut Generator) -> Self {
-        let last_name: (String, String) = split(&generator.gen(rng, &FakeOption::LastName(true)));
-        let first_name: (String, String) = split(&generator.gen(rng, &FakeOption::FirstName(true)));
+        let last_name: (String, String) =
+            split(&generator.gen(rng, &FakeOption::LastName(true, KanaScript::default())));
+        let gender: Gender = generator.gen_gender(rng);
+        let first_name: (String, String) = generator.gen_first_name(rng, gender);
         let full_name: (String, String) = (
             generator.build_name(&last_name.0, &first_name.0),
             generator.build_name(&last_name.1, &first_name.1),
         );
         return PersonName {
+            locale: generator.locale(),
+            gender,
             first_name: first_name.0,
             first_name_furigana: first_name.1,
             last_name: last_name.0,
@@ -140,3 +460,112 @@ impl PersonName {
         };
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct BankAccount {
+    locale: Locale,
+    bank_name: String,
+    bank_code: String,
+    branch_name: String,
+    branch_code: String,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Company {
+    locale: Locale,
+    name: String,
+    domain: String,
+}
+
+impl Company {
+    fn new<R: Rng>(rng: &mut R, generator: &Generator) -> Self {
+        let (name, domain): (String, String) = generator.gen_company(rng);
+        Company {
+            locale: generator.locale(),
+            name,
+            domain,
+        }
+    }
+}
+
+impl BankAccount {
+    fn new<R: Rng>(rng: &mut R, generator: &Generator) -> Self {
+        let bank: (String, String) = generator.gen_bank(rng);
+        let branch: (String, String) = generator.gen_bank_branch(rng);
+        BankAccount {
+            locale: generator.locale(),
+            bank_name: bank.0,
+            bank_code: bank.1,
+            branch_name: branch.0,
+            branch_code: branch.1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::faker::address_style::AddressStyle;
+
+    #[test]
+    fn access_time_of_data_set_increases_until_about_now() {
+        let count: usize = 100_000;
+        let mut faker: Faker<ThreadRng> = Faker::default();
+        faker.start_clock(count);
+        let start: DateTime<Local> = faker.clock.unwrap();
+        let mut last: DateTime<Local> = start;
+        for _ in 0..count {
+            let time: DateTime<Local> = faker.tick();
+            assert!(time > last);
+            last = time;
+        }
+        let now: DateTime<Local> = Local::now();
+        assert!(start < now - Duration::seconds(40_000), "{}", start);
+        // the deviation of the sum of the steps is about 91 seconds
+        assert!(last > now - Duration::minutes(10), "{}", last);
+        assert!(last < now + Duration::minutes(10), "{}", last);
+    }
+
+    #[test]
+    fn address_columns_in_record_are_of_the_same_address() {
+        let options: Vec<FakeOption> = vec![
+            FakeOption::DetailedAddress(AddressStyle::Hyphen),
+            FakeOption::AddressLine1(AddressStyle::Hyphen),
+            FakeOption::AddressLine1(AddressStyle::Kanji),
+            FakeOption::AddressLine2,
+            FakeOption::StateName,
+        ];
+        for locale in Locale::all_list() {
+            let mut faker: Faker<ThreadRng> = Faker::new(thread_rng(), locale);
+            for _ in 0..100 {
+                let record: Vec<String> = faker.gen_record(&options);
+                let (detailed, line1, line1_kanji, line2, state) =
+                    (&record[0], &record[1], &record[2], &record[3], &record[4]);
+                assert!(detailed.contains(state.as_str()), "{:?}", record);
+                let expected: String = match locale {
+                    Locale::Japan => format!("{} {}", line1, line2),
+                    Locale::UnitedStates => line1.replacen(", ", &format!(", {}, ", line2), 1),
+                    Locale::Korea => format!("{}, {}", line1, line2),
+                };
+                assert_eq!(detailed, &expected, "{:?}", record);
+                if locale == Locale::Japan {
+                    let block_number: &str = line1
+                        .rsplit(|c: char| !c.is_ascii_digit() && c != '-')
+                        .next()
+                        .unwrap();
+                    let numbers: Vec<&str> = block_number.split('-').collect();
+                    let kanji: String = format!(
+                        "{}{}丁目{}番{}号",
+                        line1.strip_suffix(block_number).unwrap(),
+                        numbers[0],
+                        numbers[1],
+                        numbers[2]
+                    );
+                    assert_eq!(line1_kanji, &kanji, "{:?}", record);
+                } else {
+                    assert_eq!(line1_kanji, line1, "{:?}", record);
+                }
+            }
+        }
+    }
+}
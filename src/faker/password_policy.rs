@@ -0,0 +1,105 @@
+/// class of characters in password
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum PasswordClass {
+    Upper,
+    Lower,
+    Digit,
+    Symbol,
+}
+
+impl PasswordClass {
+    pub fn all_list() -> Vec<Self> {
+        use PasswordClass::*;
+        vec![Upper, Lower, Digit, Symbol]
+    }
+
+    /// letter of the class in the policy such as "u" for upper
+    pub fn letter(&self) -> char {
+        use PasswordClass::*;
+        match self {
+            Upper => 'u',
+            Lower => 'l',
+            Digit => 'd',
+            Symbol => 's',
+        }
+    }
+
+    pub fn chars(&self) -> &'static str {
+        use PasswordClass::*;
+        match self {
+            Upper => "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            Lower => "abcdefghijklmnopqrstuvwxyz",
+            Digit => "0123456789",
+            Symbol => "!@#$%^&*()+-={}[]:;<>,./?_~|",
+        }
+    }
+}
+
+impl std::fmt::Display for PasswordClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use PasswordClass::*;
+        let s: String = match self {
+            Upper => "upper",
+            Lower => "lower",
+            Digit => "digit",
+            Symbol => "symbol",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// policy of password. password has at least one character of each class and only characters of the classes without excluded characters.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PasswordPolicy {
+    pub classes: Vec<PasswordClass>,
+    // characters never used such as "0O1lI"
+    pub excluded: String,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            classes: PasswordClass::all_list(),
+            excluded: String::new(),
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// letters of the classes such as "ulds"
+    pub fn letters(&self) -> String {
+        self.classes.iter().map(|c| c.letter()).collect()
+    }
+
+    /// usable characters of each class. class whose characters are all excluded has an empty set.
+    pub fn char_sets(&self) -> Vec<Vec<char>> {
+        self.classes
+            .iter()
+            .map(|class| {
+                class
+                    .chars()
+                    .chars()
+                    .filter(|c| !self.excluded.contains(*c))
+                    .collect::<Vec<char>>()
+            })
+            .collect()
+    }
+
+    /// whether each class has a usable character and the maximum length can have a character of each class
+    pub fn is_usable(&self, maximum: usize) -> bool {
+        maximum >= self.classes.len() && self.char_sets().iter().all(|set| !set.is_empty())
+    }
+}
+
+impl std::fmt::Display for PasswordPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let classes: Vec<String> = self.classes.iter().map(|c| c.to_string()).collect();
+        write!(
+            f,
+            "classes: [{}], excluded: {:?}",
+            classes.join(", "),
+            self.excluded
+        )
+    }
+}
@@ -0,0 +1,30 @@
+/// kind of phone number such as landline "03-1234-5678" and mobile "090-1234-5678"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum PhoneKind {
+    #[default]
+    Landline,
+    Mobile,
+    IpPhone,
+    TollFree,
+}
+
+impl PhoneKind {
+    pub fn all_list() -> Vec<Self> {
+        use PhoneKind::*;
+        vec![Landline, Mobile, IpPhone, TollFree]
+    }
+}
+
+impl std::fmt::Display for PhoneKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use PhoneKind::*;
+        let s: String = match self {
+            Landline => "landline",
+            Mobile => "mobile",
+            IpPhone => "ip",
+            TollFree => "toll_free",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
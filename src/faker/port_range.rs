@@ -0,0 +1,41 @@
+/// range of port number such as well known ports and ephemeral ports
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum PortRange {
+    #[default]
+    Any,
+    WellKnown,
+    Registered,
+    Ephemeral,
+}
+
+impl PortRange {
+    pub fn all_list() -> Vec<Self> {
+        use PortRange::*;
+        vec![Any, WellKnown, Registered, Ephemeral]
+    }
+
+    /// minimum and maximum of the range. port 0 is reserved, so it is not used.
+    pub fn min_max(&self) -> (u16, u16) {
+        use PortRange::*;
+        match self {
+            Any => (1, 65535),
+            WellKnown => (1, 1023),
+            Registered => (1024, 49151),
+            Ephemeral => (49152, 65535),
+        }
+    }
+}
+
+impl std::fmt::Display for PortRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use PortRange::*;
+        let s: String = match self {
+            Any => "any",
+            WellKnown => "well_known",
+            Registered => "registered",
+            Ephemeral => "ephemeral",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
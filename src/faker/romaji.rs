@@ -0,0 +1,313 @@
+use crate::faker::kana::KanaScript;
+
+/// capitalization of romaji such as "Taro Yamada", "TARO YAMADA" and "taro yamada"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum Capitalization {
+    #[default]
+    Capitalized,
+    Upper,
+    Lower,
+}
+
+impl Capitalization {
+    pub fn all_list() -> Vec<Self> {
+        use Capitalization::*;
+        vec![Capitalized, Upper, Lower]
+    }
+
+    fn apply(&self, word: &str) -> String {
+        use Capitalization::*;
+        match self {
+            Capitalized => {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(head) => head
+                        .to_uppercase()
+                        .chain(chars.flat_map(|c| c.to_lowercase()))
+                        .collect(),
+                    None => String::new(),
+                }
+            }
+            Upper => word.to_uppercase(),
+            Lower => word.to_lowercase(),
+        }
+    }
+}
+
+impl std::fmt::Display for Capitalization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use Capitalization::*;
+        let s: String = match self {
+            Capitalized => "capitalized",
+            Upper => "upper",
+            Lower => "lower",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+// two kana are checked before one kana.
+const DIGRAPH: &[(&str, &str)] = &[
+    ("キャ", "kya"),
+    ("キュ", "kyu"),
+    ("キョ", "kyo"),
+    ("シャ", "sha"),
+    ("シュ", "shu"),
+    ("ショ", "sho"),
+    ("シェ", "she"),
+    ("チャ", "cha"),
+    ("チュ", "chu"),
+    ("チョ", "cho"),
+    ("チェ", "che"),
+    ("ニャ", "nya"),
+    ("ニュ", "nyu"),
+    ("ニョ", "nyo"),
+    ("ヒャ", "hya"),
+    ("ヒュ", "hyu"),
+    ("ヒョ", "hyo"),
+    ("ミャ", "mya"),
+    ("ミュ", "myu"),
+    ("ミョ", "myo"),
+    ("リャ", "rya"),
+    ("リュ", "ryu"),
+    ("リョ", "ryo"),
+    ("ギャ", "gya"),
+    ("ギュ", "gyu"),
+    ("ギョ", "gyo"),
+    ("ジャ", "ja"),
+    ("ジュ", "ju"),
+    ("ジョ", "jo"),
+    ("ジェ", "je"),
+    ("ヂャ", "ja"),
+    ("ヂュ", "ju"),
+    ("ヂョ", "jo"),
+    ("ビャ", "bya"),
+    ("ビュ", "byu"),
+    ("ビョ", "byo"),
+    ("ピャ", "pya"),
+    ("ピュ", "pyu"),
+    ("ピョ", "pyo"),
+    ("ティ", "ti"),
+    ("ディ", "di"),
+    ("ファ", "fa"),
+    ("フィ", "fi"),
+    ("フェ", "fe"),
+    ("フォ", "fo"),
+    ("ウィ", "wi"),
+    ("ウェ", "we"),
+    ("ウォ", "wo"),
+    ("ヴァ", "va"),
+    ("ヴィ", "vi"),
+    ("ヴェ", "ve"),
+    ("ヴォ", "vo"),
+];
+
+const MONOGRAPH: &[(char, &str)] = &[
+    ('ア', "a"),
+    ('イ', "i"),
+    ('ウ', "u"),
+    ('エ', "e"),
+    ('オ', "o"),
+    ('カ', "ka"),
+    ('キ', "ki"),
+    ('ク', "ku"),
+    ('ケ', "ke"),
+    ('コ', "ko"),
+    ('サ', "sa"),
+    ('シ', "shi"),
+    ('ス', "su"),
+    ('セ', "se"),
+    ('ソ', "so"),
+    ('タ', "ta"),
+    ('チ', "chi"),
+    ('ツ', "tsu"),
+    ('テ', "te"),
+    ('ト', "to"),
+    ('ナ', "na"),
+    ('ニ', "ni"),
+    ('ヌ', "nu"),
+    ('ネ', "ne"),
+    ('ノ', "no"),
+    ('ハ', "ha"),
+    ('ヒ', "hi"),
+    ('フ', "fu"),
+    ('ヘ', "he"),
+    ('ホ', "ho"),
+    ('マ', "ma"),
+    ('ミ', "mi"),
+    ('ム', "mu"),
+    ('メ', "me"),
+    ('モ', "mo"),
+    ('ヤ', "ya"),
+    ('ユ', "yu"),
+    ('ヨ', "yo"),
+    ('ラ', "ra"),
+    ('リ', "ri"),
+    ('ル', "ru"),
+    ('レ', "re"),
+    ('ロ', "ro"),
+    ('ワ', "wa"),
+    ('ヰ', "i"),
+    ('ヱ', "e"),
+    ('ヲ', "o"),
+    ('ガ', "ga"),
+    ('ギ', "gi"),
+    ('グ', "gu"),
+    ('ゲ', "ge"),
+    ('ゴ', "go"),
+    ('ザ', "za"),
+    ('ジ', "ji"),
+    ('ズ', "zu"),
+    ('ゼ', "ze"),
+    ('ゾ', "zo"),
+    ('ダ', "da"),
+    ('ヂ', "ji"),
+    ('ヅ', "zu"),
+    ('デ', "de"),
+    ('ド', "do"),
+    ('バ', "ba"),
+    ('ビ', "bi"),
+    ('ブ', "bu"),
+    ('ベ', "be"),
+    ('ボ', "bo"),
+    ('パ', "pa"),
+    ('ピ', "pi"),
+    ('プ', "pu"),
+    ('ペ', "pe"),
+    ('ポ', "po"),
+    ('ヴ', "vu"),
+    ('ァ', "a"),
+    ('ィ', "i"),
+    ('ゥ', "u"),
+    ('ェ', "e"),
+    ('ォ', "o"),
+    ('ャ', "ya"),
+    ('ュ', "yu"),
+    ('ョ', "yo"),
+];
+
+const SOKUON: char = 'ッ';
+const HATSUON: char = 'ン';
+const CHOON: char = 'ー';
+
+/// romaji of kana with passport style Hepburn, and other characters pass through.
+/// long vowels such as "オウ", "オオ", "ウウ" and "ー" are written as one vowel except "ウエ" such as イノウエ,
+/// "ッ" doubles the next consonant ("tch" before "ch") and "ン" is "m" before b, m and p.
+pub fn romanize(kana: &str, capitalization: Capitalization) -> String {
+    let chars: Vec<char> = KanaScript::Katakana.convert(kana).chars().collect();
+    // each syllable as (romaji, kana of the syllable)
+    let mut syllables: Vec<(String, Vec<char>)> = Vec::new();
+    let mut index: usize = 0;
+    while index < chars.len() {
+        if index + 1 < chars.len() {
+            let pair: String = chars[index..index + 2].iter().collect();
+            if let Some((_, romaji)) = DIGRAPH.iter().find(|(k, _)| *k == pair) {
+                syllables.push((romaji.to_string(), chars[index..index + 2].to_vec()));
+                index += 2;
+                continue;
+            }
+        }
+        let c: char = chars[index];
+        let romaji: String = match MONOGRAPH.iter().find(|(k, _)| *k == c) {
+            Some((_, romaji)) => romaji.to_string(),
+            None => c.to_string(),
+        };
+        syllables.push((romaji, vec![c]));
+        index += 1;
+    }
+
+    let mut romanized: String = String::new();
+    for (index, (romaji, kana)) in syllables.iter().enumerate() {
+        let next: Option<&(String, Vec<char>)> = syllables.get(index + 1);
+        let next_romaji: &str = next.map(|(r, _)| r.as_str()).unwrap_or("");
+        let next_kana: &[char] = next.map(|(_, k)| k.as_slice()).unwrap_or(&[]);
+        let is_long_vowel: bool = match kana.as_slice() {
+            ['オ'] => romanized.ends_with('o'),
+            ['ウ'] => {
+                (romanized.ends_with('o') || romanized.ends_with('u')) && next_kana != ['エ']
+            }
+            [c] => *c == CHOON,
+            _ => false,
+        };
+        if is_long_vowel {
+            continue;
+        }
+        match kana.as_slice() {
+            [c] if *c == SOKUON => {
+                if next_romaji.starts_with("ch") {
+                    romanized.push('t');
+                } else if let Some(head) = next_romaji
+                    .chars()
+                    .next()
+                    .filter(|h| h.is_ascii_alphabetic())
+                {
+                    romanized.push(head);
+                }
+            }
+            [c] if *c == HATSUON => {
+                let before_labial: bool = next_romaji.starts_with('b')
+                    || next_romaji.starts_with('m')
+                    || next_romaji.starts_with('p');
+                romanized.push(if before_labial { 'm' } else { 'n' });
+            }
+            _ => romanized.push_str(romaji),
+        }
+    }
+
+    romanized
+        .split(' ')
+        .map(|word| capitalization.apply(word))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// name on credit card such as "TARO YAMADA" which is given name first in upper case.
+pub fn card_holder(first_furigana: &str, last_furigana: &str) -> String {
+    [
+        romanize(first_furigana, Capitalization::Upper),
+        romanize(last_furigana, Capitalization::Upper),
+    ]
+    .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn romanize_with_hepburn() {
+        use Capitalization::*;
+        assert_eq!(romanize("ヤマダ タロウ", Capitalized), "Yamada Taro");
+        assert_eq!(romanize("やまだ たろう", Capitalized), "Yamada Taro");
+        assert_eq!(romanize("ツチヤ フジコ", Upper), "TSUCHIYA FUJIKO");
+        assert_eq!(romanize("シンイチ", Lower), "shinichi");
+    }
+
+    #[test]
+    fn romanize_long_vowels_as_one_vowel() {
+        use Capitalization::*;
+        assert_eq!(romanize("サトウ", Capitalized), "Sato");
+        assert_eq!(romanize("オオノ", Capitalized), "Ono");
+        assert_eq!(romanize("ユウキ", Capitalized), "Yuki");
+        assert_eq!(romanize("ユーコ", Capitalized), "Yuko");
+        assert_eq!(romanize("キョウコ", Capitalized), "Kyoko");
+        // "ウエ" keeps both vowels
+        assert_eq!(romanize("イノウエ", Capitalized), "Inoue");
+    }
+
+    #[test]
+    fn romanize_sokuon_and_hatsuon() {
+        use Capitalization::*;
+        assert_eq!(romanize("ハットリ", Capitalized), "Hattori");
+        assert_eq!(romanize("ハッチ", Capitalized), "Hatchi");
+        assert_eq!(romanize("ナンバ", Capitalized), "Namba");
+        assert_eq!(romanize("ジュンペイ", Capitalized), "Jumpei");
+        assert_eq!(romanize("ホンダ", Capitalized), "Honda");
+    }
+
+    #[test]
+    fn card_holder_is_given_name_first_in_upper_case() {
+        assert_eq!(card_holder("タロウ", "ヤマダ"), "TARO YAMADA");
+    }
+}
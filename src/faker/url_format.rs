@@ -0,0 +1,73 @@
+/// scheme of URL such as "http" and "https"
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum UrlScheme {
+    #[default]
+    Http,
+    Https,
+}
+
+impl UrlScheme {
+    pub fn all_list() -> Vec<Self> {
+        use UrlScheme::*;
+        vec![Http, Https]
+    }
+}
+
+impl std::fmt::Display for UrlScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use UrlScheme::*;
+        let s: String = match self {
+            Http => "http",
+            Https => "https",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// parts of URL such as "https://example.com:8080/blue/sky?page=2#top"
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct UrlFormat {
+    pub scheme: UrlScheme,
+    // count of path segments
+    pub depth: usize,
+    // count of query parameters
+    pub query: usize,
+    pub fragment: bool,
+    pub port: bool,
+    // path segments are words of the locale with percent-encoding such as %E3%81%82
+    pub encoded: bool,
+}
+
+impl Default for UrlFormat {
+    fn default() -> Self {
+        UrlFormat {
+            scheme: UrlScheme::default(),
+            depth: 2,
+            query: 0,
+            fragment: false,
+            port: false,
+            encoded: false,
+        }
+    }
+}
+
+impl UrlFormat {
+    /// upper limit of depth and count of query parameters
+    pub const MAX_COUNT: usize = 32;
+
+    /// depth and count of query parameters are at most MAX_COUNT
+    pub fn is_usable(&self) -> bool {
+        self.depth <= Self::MAX_COUNT && self.query <= Self::MAX_COUNT
+    }
+}
+
+impl std::fmt::Display for UrlFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "scheme: {}, depth: {}, query: {}, fragment: {}, port: {}, encoded: {}",
+            self.scheme, self.depth, self.query, self.fragment, self.port, self.encoded
+        )
+    }
+}
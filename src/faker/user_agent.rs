@@ -0,0 +1,185 @@
+/// family of browser in user agent
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum Browser {
+    #[default]
+    Any,
+    Chrome,
+    Firefox,
+    Safari,
+    Edge,
+}
+
+impl Browser {
+    pub fn all_list() -> Vec<Self> {
+        use Browser::*;
+        vec![Any, Chrome, Firefox, Safari, Edge]
+    }
+
+    /// minimum and maximum of major version of recent releases
+    pub fn versions(&self) -> (usize, usize) {
+        use Browser::*;
+        match self {
+            Any | Chrome | Edge => (120, 131),
+            Firefox => (115, 133),
+            Safari => (15, 18),
+        }
+    }
+
+    /// platforms which the browser runs on
+    pub fn platforms(&self) -> Vec<Platform> {
+        use Platform::*;
+        match self {
+            Browser::Any => vec![],
+            Browser::Chrome | Browser::Firefox => vec![Windows, MacOs, Linux, Android, Ios],
+            Browser::Safari => vec![MacOs, Ios],
+            Browser::Edge => vec![Windows, MacOs, Android, Ios],
+        }
+    }
+}
+
+impl std::fmt::Display for Browser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use Browser::*;
+        let s: String = match self {
+            Any => "any",
+            Chrome => "chrome",
+            Firefox => "firefox",
+            Safari => "safari",
+            Edge => "edge",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// type of device in user agent. bot is crawler of search engine.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum DeviceType {
+    #[default]
+    Any,
+    Desktop,
+    Mobile,
+    Bot,
+}
+
+impl DeviceType {
+    pub fn all_list() -> Vec<Self> {
+        use DeviceType::*;
+        vec![Any, Desktop, Mobile, Bot]
+    }
+}
+
+impl std::fmt::Display for DeviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use DeviceType::*;
+        let s: String = match self {
+            Any => "any",
+            Desktop => "desktop",
+            Mobile => "mobile",
+            Bot => "bot",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// operating system in user agent
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum Platform {
+    #[default]
+    Any,
+    Windows,
+    MacOs,
+    Linux,
+    Android,
+    Ios,
+}
+
+impl Platform {
+    pub fn all_list() -> Vec<Self> {
+        use Platform::*;
+        vec![Any, Windows, MacOs, Linux, Android, Ios]
+    }
+
+    pub fn device_type(&self) -> DeviceType {
+        use Platform::*;
+        match self {
+            Any => DeviceType::Any,
+            Windows | MacOs | Linux => DeviceType::Desktop,
+            Android | Ios => DeviceType::Mobile,
+        }
+    }
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        use Platform::*;
+        let s: String = match self {
+            Any => "any",
+            Windows => "windows",
+            MacOs => "mac_os",
+            Linux => "linux",
+            Android => "android",
+            Ios => "ios",
+        }
+        .to_string();
+        write!(f, "{}", s)
+    }
+}
+
+/// dimensions of user agent. Any is not restricted.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct UserAgentFormat {
+    pub browser: Browser,
+    pub device: DeviceType,
+    pub platform: Platform,
+    // range of major version of the browser. If none, versions of recent releases.
+    pub version: Option<(usize, usize)>,
+}
+
+impl UserAgentFormat {
+    /// bot is not restricted by browser and platform. Others have at least one candidate.
+    pub fn is_usable(&self) -> bool {
+        if self.device == DeviceType::Bot {
+            self.browser == Browser::Any && self.platform == Platform::Any
+        } else {
+            !self.candidates().is_empty()
+        }
+    }
+
+    /// pairs of browser and platform which agree with the format.
+    /// empty for bot or impossible combination such as safari on android.
+    pub fn candidates(&self) -> Vec<(Browser, Platform)> {
+        if self.device == DeviceType::Bot {
+            return vec![];
+        }
+        Browser::all_list()
+            .into_iter()
+            .filter(|browser| self.browser == Browser::Any || *browser == self.browser)
+            .flat_map(|browser| {
+                browser
+                    .platforms()
+                    .into_iter()
+                    .map(move |platform| (browser, platform))
+            })
+            .filter(|(_, platform)| self.platform == Platform::Any || *platform == self.platform)
+            .filter(|(_, platform)| {
+                self.device == DeviceType::Any || platform.device_type() == self.device
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for UserAgentFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "browser: {}, device: {}, platform: {}",
+            self.browser, self.device, self.platform
+        )?;
+        if let Some((from, to)) = self.version {
+            write!(f, ", version: {}<=n<={}", from, to)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+const ROUND_CONSTANT: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INITIAL_HASH: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// digest of SHA-256 (FIPS 180-4)
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    // padding is 0x80, zeros and length in bits as big endian so that the length is multiple of 64 bytes.
+    let mut message: Vec<u8> = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    let mut hash: [u32; 8] = INITIAL_HASH;
+    for block in message.chunks(64) {
+        let mut w: [u32; 64] = [0; 64];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0: u32 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1: u32 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = hash;
+        for i in 0..64 {
+            let s1: u32 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch: u32 = (e & f) ^ (!e & g);
+            let temp1: u32 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(ROUND_CONSTANT[i])
+                .wrapping_add(w[i]);
+            let s0: u32 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj: u32 = (a & b) ^ (a & c) ^ (b & c);
+            let temp2: u32 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+        for (value, add) in hash.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+            *value = value.wrapping_add(*add);
+        }
+    }
+
+    let mut digest: [u8; 32] = [0; 32];
+    for (i, value) in hash.iter().enumerate() {
+        digest[i * 4..i * 4 + 4].copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// digest of SHA-256 in lower case hex such as "e3b0c442..."
+pub fn sha256_hex(data: &[u8]) -> String {
+    sha256(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// characters for salt and hash of crypt
+pub const CRYPT_CHAR: &str = "./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+// order of bytes of the digest in the hash of SHA-256 crypt
+const CRYPT_ORDER: [(usize, usize, usize); 10] = [
+    (0, 10, 20),
+    (21, 1, 11),
+    (12, 22, 2),
+    (3, 13, 23),
+    (24, 4, 14),
+    (15, 25, 5),
+    (6, 16, 26),
+    (27, 7, 17),
+    (18, 28, 8),
+    (9, 19, 29),
+];
+
+// bytes of the digest repeated to the length
+fn repeat_to(digest: &[u8], length: usize) -> Vec<u8> {
+    digest.iter().cycle().take(length).cloned().collect()
+}
+
+/// SHA-256 crypt with default 5000 rounds such as "$5$salt$hash" (https://www.akkadia.org/drepper/SHA-crypt.txt).
+/// salt is up to 16 characters of CRYPT_CHAR.
+pub fn sha256_crypt(password: &str, salt: &str) -> String {
+    let password: &[u8] = password.as_bytes();
+    let salt: &[u8] = &salt.as_bytes()[..salt.len().min(16)];
+
+    let alternate: [u8; 32] = sha256(&[password, salt, password].concat());
+    let mut message: Vec<u8> = [password, salt].concat();
+    message.extend(repeat_to(&alternate, password.len()));
+    let mut length: usize = password.len();
+    while length > 0 {
+        if length & 1 == 1 {
+            message.extend_from_slice(&alternate);
+        } else {
+            message.extend_from_slice(password);
+        }
+        length >>= 1;
+    }
+    let mut digest: [u8; 32] = sha256(&message);
+
+    let password_sequence: Vec<u8> =
+        repeat_to(&sha256(&password.repeat(password.len())), password.len());
+    let salt_sequence: Vec<u8> =
+        repeat_to(&sha256(&salt.repeat(16 + digest[0] as usize)), salt.len());
+    for round in 0..5000 {
+        let mut message: Vec<u8> = Vec::new();
+        if round % 2 == 1 {
+            message.extend_from_slice(&password_sequence);
+        } else {
+            message.extend_from_slice(&digest);
+        }
+        if round % 3 != 0 {
+            message.extend_from_slice(&salt_sequence);
+        }
+        if round % 7 != 0 {
+            message.extend_from_slice(&password_sequence);
+        }
+        if round % 2 == 1 {
+            message.extend_from_slice(&digest);
+        } else {
+            message.extend_from_slice(&password_sequence);
+        }
+        digest = sha256(&message);
+    }
+
+    let chars: Vec<char> = CRYPT_CHAR.chars().collect();
+    let encode = |b2: u8, b1: u8, b0: u8, count: usize| -> String {
+        let mut w: u32 = (b2 as u32) << 16 | (b1 as u32) << 8 | b0 as u32;
+        (0..count)
+            .map(|_| {
+                let c: char = chars[(w & 0x3f) as usize];
+                w >>= 6;
+                c
+            })
+            .collect()
+    };
+    let mut hash: String = CRYPT_ORDER
+        .iter()
+        .map(|(b2, b1, b0)| encode(digest[*b2], digest[*b1], digest[*b0], 4))
+        .collect();
+    hash.push_str(&encode(0, digest[31], digest[30], 3));
+    format!("$5${}${}", String::from_utf8_lossy(salt), hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // FIPS 180-4 examples
+    #[test]
+    fn sha256_of_fips_examples() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha256_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        assert_eq!(
+            sha256_hex(&[b'a'; 1_000_000]),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+    }
+
+    // padding to the next block when the length is 56 bytes or more
+    #[test]
+    fn sha256_at_block_boundary() {
+        assert_eq!(
+            sha256_hex(&[b'a'; 55]),
+            "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"
+        );
+        assert_eq!(
+            sha256_hex(&[b'a'; 56]),
+            "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"
+        );
+        assert_eq!(
+            sha256_hex(&[b'a'; 64]),
+            "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"
+        );
+    }
+
+    // examples of https://www.akkadia.org/drepper/SHA-crypt.txt with 5000 rounds
+    #[test]
+    fn sha256_crypt_of_drepper_examples() {
+        assert_eq!(
+            sha256_crypt("Hello world!", "saltstring"),
+            "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5"
+        );
+        assert_eq!(
+            sha256_crypt("This is just a test", "toolongsaltstring"),
+            "$5$toolongsaltstrin$Un/5jzAHMgOGZ5.mWJpuVolil07guHPvOW8mGRcvxa5"
+        );
+        assert_eq!(
+            sha256_crypt(
+                "we have a short salt string but not a short password",
+                "short"
+            ),
+            "$5$short$k38kpRP0CfxHLIcmymaSTFLK.iFfF4tKryDuEGKp6SC"
+        );
+    }
+}
@@ -2,6 +2,8 @@ use rand::distributions::uniform::{SampleRange, SampleUniform};
 use rand::seq::SliceRandom;
 use rand::Rng;
 use std::fmt::Display;
+use std::fs;
+use std::io;
 
 pub fn string_formatted<T: Display + ?Sized>(text: &T) -> String {
     format!("\"{}\"", text)
@@ -20,7 +22,7 @@ pub fn split(text: &str) -> (String, String) {
     };
 }
 
-pub fn select<'a, R: Rng, I: ?Sized>(rng: &'a mut R, data: &'a [&I]) -> &'a I {
+pub fn select<'a, R: Rng, I: ?Sized>(rng: &mut R, data: &'a [&'a I]) -> &'a I {
     return data
         .choose(rng)
         .expect("failed select data from empty list.");
@@ -31,13 +33,14 @@ pub fn gen_range<R: Rng, T: SampleUniform, SR: SampleRange<T>>(rng: &mut R, rang
     rng.gen_range::<T, SR>(range)
 }
 
+/// 0 <= n < 1
 pub fn gen_fraction_part<R: Rng>(rng: &mut R) -> f64 {
-    gen_range(rng, 0 as f64..=0 as f64)
+    gen_range(rng, 0.0..1.0)
 }
 
 pub fn select_many<'a, R: Rng, I: ?Sized>(
-    rng: &'a mut R,
-    data: &'a [&I],
+    rng: &mut R,
+    data: &'a [&'a I],
     minimum: usize,
     maximum: usize,
 ) -> Vec<&'a I> {
@@ -46,9 +49,9 @@ pub fn select_many<'a, R: Rng, I: ?Sized>(
 }
 
 const ASCII: &'static str = "!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
-const ALPHA_NUM: &'static str = "0123456789ABCDEFGHIJKLMNOPWRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const ALPHA_NUM: &'static str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
 const PASSWORD_CHAR: &'static str =
-    "0123456789ABCDEFGHIJKLMNOPWRSTUVWXYZabcdefghijklmnopqrstuvwxyz!@#$%^&*()+-={}[]:;<>,./?_~|";
+    "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!@#$%^&*()+-={}[]:;<>,./?_~|";
 
 fn gen_chars<R: Rng>(base: &str, rng: &mut R, minimum: usize, maximum: usize) -> String {
     let size: usize = gen_range(rng, minimum..=maximum);
@@ -72,3 +75,506 @@ pub fn gen_alpha_num_chars<R: Rng>(gnd: &mut R, from: usize, to: usize) -> Strin
 pub fn gen_password_chars<R: Rng>(rng: &mut R, from: usize, to: usize) -> String {
     gen_chars(PASSWORD_CHAR, rng, from, to)
 }
+
+/// characters with repetition which have at least one character of each non-empty set.
+/// length is in from..=to and at least the count of the sets if to is enough.
+pub fn gen_chars_of_sets<R: Rng>(
+    rng: &mut R,
+    sets: &[Vec<char>],
+    from: usize,
+    to: usize,
+) -> String {
+    let all: Vec<char> = sets.concat();
+    if all.is_empty() {
+        return String::new();
+    }
+    let size: usize = gen_range(rng, from.max(sets.len()).min(to)..=to);
+    let mut chars: Vec<char> = sets
+        .iter()
+        .filter_map(|set| set.choose(rng))
+        .cloned()
+        .collect();
+    chars.shuffle(rng);
+    chars.truncate(size);
+    while chars.len() < size {
+        chars.push(*all.choose(rng).unwrap());
+    }
+    chars.shuffle(rng);
+    chars.into_iter().collect()
+}
+
+/// "9" is a digit, "A" is an upper case letter and "\" escapes the next character such as EMP-999999
+pub fn gen_by_pattern<R: Rng>(rng: &mut R, pattern: &str) -> String {
+    let mut text: String = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '9' => text.push((b'0' + rng.gen_range(0..10)) as char),
+            'A' => text.push((b'A' + rng.gen_range(0..26)) as char),
+            '\\' => text.extend(chars.next()),
+            _ => text.push(c),
+        }
+    }
+    text
+}
+
+/// lower case ascii letters and digits of the text such as "blueridge" of "Blue Ridge"
+pub fn ascii_label(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+// mean radius of the earth in km
+const EARTH_RADIUS: f64 = 6371.0088;
+
+/// longitude in -180 <= n < 180
+fn normalize_longitude(longitude: f64) -> f64 {
+    (longitude + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// latitude and longitude in degree uniformly distributed on the sphere in the box.
+/// box whose west is greater than east crosses the 180th meridian.
+pub fn gen_point_in_box<R: Rng>(
+    rng: &mut R,
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+) -> (f64, f64) {
+    // area between latitudes is proportional to difference of sine of them
+    let (sin_south, sin_north): (f64, f64) = (south.to_radians().sin(), north.to_radians().sin());
+    let latitude: f64 = (sin_south + (sin_north - sin_south) * gen_fraction_part(rng))
+        .asin()
+        .to_degrees();
+    let width: f64 = if west <= east {
+        east - west
+    } else {
+        east - west + 360.0
+    };
+    let longitude: f64 = normalize_longitude(west + width * gen_fraction_part(rng));
+    (latitude, longitude)
+}
+
+/// latitude and longitude in degree uniformly distributed on the sphere within the radius in km of the center
+pub fn gen_point_in_circle<R: Rng>(
+    rng: &mut R,
+    latitude: f64,
+    longitude: f64,
+    radius: f64,
+) -> (f64, f64) {
+    // area of spherical cap is proportional to 1 - cos of its angular radius
+    let angular_radius: f64 = (radius / EARTH_RADIUS).min(std::f64::consts::PI);
+    let distance: f64 = (1.0 - gen_fraction_part(rng) * (1.0 - angular_radius.cos())).acos();
+    let bearing: f64 = 2.0 * std::f64::consts::PI * gen_fraction_part(rng);
+    let (phi, lambda): (f64, f64) = (latitude.to_radians(), longitude.to_radians());
+    let target_phi: f64 =
+        (phi.sin() * distance.cos() + phi.cos() * distance.sin() * bearing.cos()).asin();
+    let target_lambda: f64 = lambda
+        + (bearing.sin() * distance.sin() * phi.cos())
+            .atan2(distance.cos() - phi.sin() * target_phi.sin());
+    (
+        target_phi.to_degrees(),
+        normalize_longitude(target_lambda.to_degrees()),
+    )
+}
+
+/// such as +35.681236
+pub fn format_latitude(latitude: f64) -> String {
+    format!("{:<+010.6}", latitude)
+}
+
+/// such as +139.767125
+pub fn format_longitude(longitude: f64) -> String {
+    format!("{:<+011.6}", longitude)
+}
+
+/// red, green and blue of hue in degree, saturation and lightness in percent
+pub fn hsl_to_rgb(hue: u16, saturation: u8, lightness: u8) -> (u8, u8, u8) {
+    let s: f64 = saturation as f64 / 100.0;
+    let l: f64 = lightness as f64 / 100.0;
+    let chroma: f64 = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h: f64 = (hue % 360) as f64 / 60.0;
+    let x: f64 = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b): (f64, f64, f64) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m: f64 = l - chroma / 2.0;
+    let to_byte = |v: f64| -> u8 { ((v + m) * 255.0).round() as u8 };
+    (to_byte(r), to_byte(g), to_byte(b))
+}
+
+/// percent-encoding of UTF-8 bytes except unreserved characters of URI such as "あ" to "%E3%81%82"
+pub fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// host part of IP address of the bits.
+/// If exclude_first_last is true and the bits are 2 or more, network address and broadcast address are not generated.
+pub fn gen_host_part<R: Rng>(rng: &mut R, bits: u32, exclude_first_last: bool) -> u128 {
+    if bits == 0 {
+        return 0;
+    }
+    let max: u128 = if bits >= 128 {
+        u128::MAX
+    } else {
+        (1 << bits) - 1
+    };
+    if exclude_first_last && bits >= 2 {
+        gen_range(rng, 1..max)
+    } else {
+        gen_range(rng, 0..=max)
+    }
+}
+
+/// digits of the size such as [0, 4, 9]
+pub fn gen_digits<R: Rng>(rng: &mut R, size: usize) -> Vec<u8> {
+    (0..size).map(|_| gen_range(rng, 0..=9)).collect()
+}
+
+pub fn digits_to_string(digits: &[u8]) -> String {
+    digits.iter().map(|d| d.to_string()).collect()
+}
+
+/// digits of the text such as "049" to [0, 4, 9]. other characters are ignored.
+pub fn to_digits(text: &str) -> Vec<u8> {
+    text.chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| d as u8)
+        .collect()
+}
+
+/// check digit of corporate number (法人番号) for the base number of 12 digits.
+/// 9 - (sum of P_n * Q_n) % 9 where P_n is n-th digit from the right and Q_n is 1 for odd n and 2 for even n.
+pub fn corporate_number_check_digit(base: &[u8]) -> u8 {
+    let sum: u32 = base
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| *d as u32 * if i % 2 == 0 { 1 } else { 2 })
+        .sum();
+    (9 - sum % 9) as u8
+}
+
+/// check digit of individual number (個人番号) for the base number of 11 digits.
+/// 11 - (sum of P_n * Q_n) % 11 where P_n is n-th digit from the right and Q_n is n + 1 for n <= 6 and n - 5 for n >= 7.
+/// If the remainder is 0 or 1, check digit is 0.
+pub fn individual_number_check_digit(base: &[u8]) -> u8 {
+    let sum: u32 = base
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            let n: u32 = i as u32 + 1;
+            *d as u32 * if n <= 6 { n + 1 } else { n - 5 }
+        })
+        .sum();
+    match sum % 11 {
+        0 | 1 => 0,
+        remainder => (11 - remainder) as u8,
+    }
+}
+
+/// check digit of Luhn algorithm for the base number without check digit.
+/// (10 - (sum of P_n * Q_n) % 10) % 10 where P_n is n-th digit from the right and Q_n is 2 for odd n and 1 for even n.
+/// digits of P_n * Q_n are summed such as 1 + 6 for 16.
+pub fn luhn_check_digit(base: &[u8]) -> u8 {
+    let sum: u32 = base
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| match (i % 2, *d as u32 * 2) {
+            (0, doubled) if doubled > 9 => doubled - 9,
+            (0, doubled) => doubled,
+            _ => *d as u32,
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// check digit of GTIN such as JAN (EAN-13), EAN-8, UPC-A and ISBN-13 for the base number without check digit.
+/// (10 - (sum of P_n * Q_n) % 10) % 10 where P_n is n-th digit from the right and Q_n is 3 for odd n and 1 for even n.
+pub fn gtin_check_digit(base: &[u8]) -> u8 {
+    let sum: u32 = base
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| *d as u32 * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// check digit of ISBN-10 for the base number of 9 digits.
+/// (11 - (sum of P_n * Q_n) % 11) % 11 where P_n is n-th digit from the left and Q_n is 11 - n.
+/// check digit 10 is written as X.
+pub fn isbn10_check_digit(base: &[u8]) -> u8 {
+    let sum: u32 = base
+        .iter()
+        .enumerate()
+        .map(|(i, d)| *d as u32 * (10 - i as u32))
+        .sum();
+    ((11 - sum % 11) % 11) as u8
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// not empty lines of the file
+pub fn read_lines(path: &str) -> io::Result<Vec<String>> {
+    let text: String = fs::read_to_string(path)?;
+    let lines: Vec<String> = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .map(|l: &str| l.trim().to_string())
+        .filter(|l: &String| !l.is_empty())
+        .collect();
+    if lines.is_empty() {
+        return Err(invalid_data(format!("{} has no value", path)));
+    }
+    Ok(lines)
+}
+
+/// not empty values of the column in csv file whose first line is header
+pub fn read_csv_column(path: &str, column: &str) -> io::Result<Vec<String>> {
+    let text: String = fs::read_to_string(path)?;
+    let mut rows = text.trim_start_matches('\u{feff}').lines();
+    let header: Vec<String> = match rows.next() {
+        Some(line) => split_csv_line(line),
+        None => return Err(invalid_data(format!("{} has no header", path))),
+    };
+    let index: usize = header
+        .iter()
+        .position(|h: &String| h.trim() == column)
+        .ok_or_else(|| invalid_data(format!("{} has no column {}", path, column)))?;
+    let values: Vec<String> = rows
+        .filter_map(|line: &str| {
+            split_csv_line(line)
+                .get(index)
+                .map(|v| v.trim().to_string())
+        })
+        .filter(|v: &String| !v.is_empty())
+        .collect();
+    if values.is_empty() {
+        return Err(invalid_data(format!("{} has no value in {}", path, column)));
+    }
+    Ok(values)
+}
+
+// not support line break in quoted value
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    let mut value: String = String::new();
+    let mut quoted: bool = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => values.push(std::mem::take(&mut value)),
+            _ => value.push(c),
+        }
+    }
+    values.push(value);
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 7000012050002 is the corporate number of National Tax Agency
+    #[test]
+    fn corporate_number_check_digit_of_known_number() {
+        assert_eq!(corporate_number_check_digit(&to_digits("000012050002")), 7);
+        // remainder 0 makes check digit 9
+        assert_eq!(corporate_number_check_digit(&to_digits("000000000000")), 9);
+    }
+
+    // 123456789018 is the example of individual number
+    #[test]
+    fn individual_number_check_digit_of_known_number() {
+        assert_eq!(individual_number_check_digit(&to_digits("12345678901")), 8);
+        // remainder 0 makes check digit 0
+        assert_eq!(individual_number_check_digit(&to_digits("00000000000")), 0);
+        // remainder 1 makes check digit 0
+        assert_eq!(individual_number_check_digit(&to_digits("00000000006")), 0);
+    }
+
+    #[test]
+    fn luhn_check_digit_of_known_numbers() {
+        assert_eq!(luhn_check_digit(&to_digits("7992739871")), 3);
+        assert_eq!(luhn_check_digit(&to_digits("411111111111111")), 1);
+        assert_eq!(luhn_check_digit(&to_digits("37828224631000")), 5);
+        // remainder 0 makes check digit 0
+        assert_eq!(luhn_check_digit(&to_digits("000000000000000")), 0);
+    }
+
+    #[test]
+    fn to_digits_ignores_other_characters() {
+        assert_eq!(to_digits("978-4-0"), vec![9, 7, 8, 4, 0]);
+        assert_eq!(digits_to_string(&[0, 4, 9]), "049");
+    }
+
+    #[test]
+    fn gtin_check_digit_of_known_codes() {
+        // JAN (EAN-13)
+        assert_eq!(gtin_check_digit(&to_digits("490123456789")), 4);
+        // UPC-A
+        assert_eq!(gtin_check_digit(&to_digits("03600029145")), 2);
+        // EAN-8
+        assert_eq!(gtin_check_digit(&to_digits("9638507")), 4);
+        // ISBN-13 of ISBN-10 0-306-40615-2
+        assert_eq!(gtin_check_digit(&to_digits("978030640615")), 7);
+        // remainder 0 makes check digit 0
+        assert_eq!(gtin_check_digit(&to_digits("000000000000")), 0);
+    }
+
+    #[test]
+    fn isbn10_check_digit_of_known_codes() {
+        assert_eq!(isbn10_check_digit(&to_digits("030640615")), 2);
+        // check digit X
+        assert_eq!(isbn10_check_digit(&to_digits("080442957")), 10);
+        assert_eq!(isbn10_check_digit(&to_digits("000000000")), 0);
+    }
+
+    #[test]
+    fn gen_host_part_in_the_bits() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(gen_host_part(&mut rng, 0, true), 0);
+        for _ in 0..100 {
+            assert!(gen_host_part(&mut rng, 1, false) <= 1);
+            // network address 0 and broadcast address 3 are excluded
+            assert!((1..=2).contains(&gen_host_part(&mut rng, 2, true)));
+            assert!(gen_host_part(&mut rng, 8, false) <= 255);
+        }
+        // no overflow of all bits
+        gen_host_part(&mut rng, 128, true);
+    }
+
+    #[test]
+    fn percent_encode_except_unreserved_characters() {
+        assert_eq!(percent_encode("a-Z_0.9~"), "a-Z_0.9~");
+        assert_eq!(percent_encode("a b&c=d/"), "a%20b%26c%3Dd%2F");
+        assert_eq!(percent_encode("あ"), "%E3%81%82");
+    }
+
+    #[test]
+    fn hsl_to_rgb_of_known_colors() {
+        assert_eq!(hsl_to_rgb(0, 100, 50), (255, 0, 0));
+        assert_eq!(hsl_to_rgb(30, 100, 50), (255, 128, 0));
+        assert_eq!(hsl_to_rgb(120, 100, 50), (0, 255, 0));
+        assert_eq!(hsl_to_rgb(240, 100, 25), (0, 0, 128));
+        assert_eq!(hsl_to_rgb(300, 100, 50), (255, 0, 255));
+        assert_eq!(hsl_to_rgb(360, 100, 50), (255, 0, 0));
+        assert_eq!(hsl_to_rgb(0, 0, 50), (128, 128, 128));
+        assert_eq!(hsl_to_rgb(200, 50, 100), (255, 255, 255));
+        assert_eq!(hsl_to_rgb(200, 50, 0), (0, 0, 0));
+    }
+
+    #[test]
+    fn gen_chars_of_sets_has_each_set_within_the_length() {
+        let mut rng = rand::thread_rng();
+        let sets: Vec<Vec<char>> = vec![vec!['a', 'b'], vec!['1'], vec!['!']];
+        for _ in 0..100 {
+            let chars: String = gen_chars_of_sets(&mut rng, &sets, 1, 5);
+            assert!((3..=5).contains(&chars.len()), "{}", chars);
+            assert!(chars.contains(['a', 'b']), "{}", chars);
+            assert!(chars.contains('1') && chars.contains('!'), "{}", chars);
+            // length never exceeds the maximum even if it is less than the count of the sets
+            assert_eq!(gen_chars_of_sets(&mut rng, &sets, 1, 2).len(), 2);
+        }
+        assert_eq!(gen_chars_of_sets(&mut rng, &[vec![], vec![]], 1, 5), "");
+    }
+
+    #[test]
+    fn gen_by_pattern_of_digits_letters_and_escape() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let text: String = gen_by_pattern(&mut rng, "EMP-999\\9A\\A");
+            let chars: Vec<char> = text.chars().collect();
+            assert_eq!(chars.len(), 10, "{}", text);
+            assert!(text.starts_with("EMP-"), "{}", text);
+            assert!(chars[4..7].iter().all(|c| c.is_ascii_digit()), "{}", text);
+            assert!(chars[8].is_ascii_uppercase(), "{}", text);
+            // escaped characters are as is
+            assert_eq!((chars[7], chars[9]), ('9', 'A'));
+        }
+    }
+
+    #[test]
+    fn ascii_label_of_names() {
+        assert_eq!(ascii_label("Blue Ridge"), "blueridge");
+        assert_eq!(ascii_label("O'Neil & Co. 2"), "oneilco2");
+        assert_eq!(ascii_label("株式会社"), "");
+    }
+
+    // fixed width with sign and zero padding
+    #[test]
+    fn format_latitude_and_longitude_with_sign() {
+        assert_eq!(format_latitude(35.681236), "+35.681236");
+        assert_eq!(format_latitude(-5.5), "-05.500000");
+        assert_eq!(format_longitude(139.767125), "+139.767125");
+        assert_eq!(format_longitude(-0.1276), "-000.127600");
+    }
+
+    #[test]
+    fn gen_point_in_box_across_the_180th_meridian() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let (latitude, longitude): (f64, f64) =
+                gen_point_in_box(&mut rng, -20.0, 170.0, 10.0, -170.0);
+            assert!((-20.0..=10.0).contains(&latitude), "{}", latitude);
+            assert!(longitude >= 170.0 || longitude <= -170.0, "{}", longitude);
+        }
+    }
+
+    // distance in km between two points by haversine formula
+    fn distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+        let (phi1, phi2): (f64, f64) = (from.0.to_radians(), to.0.to_radians());
+        let d_phi: f64 = phi2 - phi1;
+        let d_lambda: f64 = (to.1 - from.1).to_radians();
+        let a: f64 =
+            (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS * a.sqrt().asin()
+    }
+
+    #[test]
+    fn gen_point_in_circle_within_the_radius() {
+        let mut rng = rand::thread_rng();
+        // Tokyo station is 403 km from Osaka station
+        assert!((distance((35.681236, 139.767125), (34.702485, 135.495951)) - 403.0).abs() < 1.0);
+        for _ in 0..100 {
+            let (latitude, longitude): (f64, f64) =
+                gen_point_in_circle(&mut rng, 35.681236, 179.9, 10.0);
+            assert!(distance((35.681236, 179.9), (latitude, longitude)) <= 10.0 + 1e-6);
+            assert!((-180.0..180.0).contains(&longitude), "{}", longitude);
+        }
+    }
+
+    #[test]
+    fn split_csv_line_with_quoted_values() {
+        assert_eq!(split_csv_line("a,b,,c"), vec!["a", "b", "", "c"]);
+        assert_eq!(
+            split_csv_line("\"a,b\",\"say \"\"hi\"\"\",c\r"),
+            vec!["a,b", "say \"hi\"", "c"]
+        );
+        assert_eq!(split_csv_line(""), vec![""]);
+    }
+}
@@ -7,4 +7,5 @@ pub mod faker;
 
 // don't have submodule
 pub mod date_time_format;
+mod hash;
 mod helper;